@@ -6,9 +6,8 @@ pub use query::{check_royalties, query_royalties_info};
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_json_binary, Empty};
-pub use cw721_base::{
-    execute::Cw721Execute, msg::InstantiateMsg, query::Cw721Query, Cw721Contract,
-};
+use cw721::state::{DefaultOptionCollectionInfoExtension, TraitSource};
+pub use cw721_base::{execute::Cw721Execute, query::Cw721Query, Cw721Contract};
 
 use crate::error::ContractError;
 
@@ -45,11 +44,28 @@ pub struct Metadata {
     pub royalty_payment_address: Option<String>,
 }
 
+impl TraitSource for Metadata {
+    fn traits(&self) -> Vec<cw721::state::Trait> {
+        self.attributes
+            .iter()
+            .flatten()
+            .map(|attribute| cw721::state::Trait {
+                display_type: attribute.display_type.clone(),
+                trait_type: attribute.trait_type.clone(),
+                value: attribute.value.clone(),
+            })
+            .collect()
+    }
+}
+
 pub type Extension = Option<Metadata>;
 
 pub type MintExtension = Option<Extension>;
 
-pub type Cw2981Contract<'a> = Cw721Contract<'a, Extension, Empty, Empty>;
+pub type Cw2981Contract<'a> =
+    Cw721Contract<'a, Extension, Empty, Empty, DefaultOptionCollectionInfoExtension>;
+pub type InstantiateMsg =
+    cw721_base::msg::InstantiateMsg<cw721_base::msg::DefaultOptionCollectionInfoExtensionMsg>;
 pub type ExecuteMsg = cw721_base::msg::ExecuteMsg<Extension, Empty>;
 
 #[cfg(not(feature = "library"))]
@@ -59,7 +75,7 @@ pub mod entry {
     use super::*;
 
     use cosmwasm_std::entry_point;
-    use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+    use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult};
 
     #[entry_point]
     pub fn instantiate(
@@ -85,19 +101,22 @@ pub mod entry {
         info: MessageInfo,
         msg: ExecuteMsg,
     ) -> Result<Response, ContractError> {
-        if let ExecuteMsg::Mint {
-            extension:
-                Some(Metadata {
-                    royalty_percentage: Some(royalty_percentage),
-                    ..
-                }),
-            ..
-        } = &msg
-        {
-            // validate royalty_percentage to be between 0 and 100
-            // no need to check < 0 because royalty_percentage is u64
-            if *royalty_percentage > 100 {
-                return Err(ContractError::InvalidRoyaltyPercentage);
+        let extensions = match &msg {
+            ExecuteMsg::Mint { extension, .. } => vec![extension],
+            ExecuteMsg::BatchMint { tokens } => tokens.iter().map(|t| &t.extension).collect(),
+            _ => vec![],
+        };
+        for extension in extensions {
+            if let Some(Metadata {
+                royalty_percentage: Some(royalty_percentage),
+                ..
+            }) = extension
+            {
+                // validate royalty_percentage to be between 0 and 100
+                // no need to check < 0 because royalty_percentage is u64
+                if *royalty_percentage > 100 {
+                    return Err(ContractError::InvalidRoyaltyPercentage);
+                }
             }
         }
 
@@ -106,6 +125,11 @@ pub mod entry {
             .map_err(Into::into)
     }
 
+    #[entry_point]
+    pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+        Ok(Cw2981Contract::default().reply(deps, env, msg)?)
+    }
+
     #[entry_point]
     pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         match msg {
@@ -124,7 +148,7 @@ mod tests {
     use super::*;
     use crate::msg::{CheckRoyaltiesResponse, QueryMsg, RoyaltiesInfoResponse};
 
-    use cosmwasm_std::{from_json, Uint128};
+    use cosmwasm_std::{from_json, Decimal, Uint128};
 
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 
@@ -140,7 +164,14 @@ mod tests {
             name: "SpaceShips".to_string(),
             symbol: "SPACE".to_string(),
             minter: None,
+            creator: None,
             withdraw_address: None,
+            metadata_update_authority: None,
+            transfer_policy: None,
+            sequential_token_ids: None,
+            max_supply: None,
+            index_traits: None,
+            collection_info_extension: None,
         };
         entry::instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
 
@@ -152,7 +183,7 @@ mod tests {
             ..Metadata::default()
         });
         let exec_msg = ExecuteMsg::Mint {
-            token_id: token_id.to_string(),
+            token_id: Some(token_id.to_string()),
             owner: "john".to_string(),
             token_uri: token_uri.clone(),
             extension: extension.clone(),
@@ -167,6 +198,56 @@ mod tests {
         assert_eq!(res.extension, extension);
     }
 
+    #[test]
+    fn index_metadata_attributes() {
+        let mut deps = mock_dependencies();
+        let contract = Cw2981Contract::default();
+
+        let info = mock_info(CREATOR, &[]);
+        let init_msg = InstantiateMsg {
+            name: "SpaceShips".to_string(),
+            symbol: "SPACE".to_string(),
+            minter: None,
+            creator: None,
+            withdraw_address: None,
+            metadata_update_authority: None,
+            transfer_policy: None,
+            sequential_token_ids: None,
+            max_supply: None,
+            index_traits: Some(true),
+            collection_info_extension: None,
+        };
+        entry::instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
+
+        let token_id = "Enterprise";
+        let exec_msg = ExecuteMsg::Mint {
+            token_id: Some(token_id.to_string()),
+            owner: "john".to_string(),
+            token_uri: None,
+            extension: Some(Metadata {
+                attributes: Some(vec![Trait {
+                    display_type: None,
+                    trait_type: "class".to_string(),
+                    value: "Constitution".to_string(),
+                }]),
+                ..Metadata::default()
+            }),
+        };
+        entry::execute(deps.as_mut(), mock_env(), info, exec_msg).unwrap();
+
+        let res = contract
+            .query_tokens_by_trait(
+                deps.as_ref(),
+                mock_env(),
+                "class".to_string(),
+                "Constitution".to_string(),
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(res.tokens, vec![token_id.to_string()]);
+    }
+
     #[test]
     fn validate_royalty_information() {
         let mut deps = mock_dependencies();
@@ -177,13 +258,20 @@ mod tests {
             name: "SpaceShips".to_string(),
             symbol: "SPACE".to_string(),
             minter: None,
+            creator: None,
             withdraw_address: None,
+            metadata_update_authority: None,
+            transfer_policy: None,
+            sequential_token_ids: None,
+            max_supply: None,
+            index_traits: None,
+            collection_info_extension: None,
         };
         entry::instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
 
         let token_id = "Enterprise";
         let exec_msg = ExecuteMsg::Mint {
-            token_id: token_id.to_string(),
+            token_id: Some(token_id.to_string()),
             owner: "john".to_string(),
             token_uri: Some("https://starships.example.com/Starship/Enterprise.json".into()),
             extension: Some(Metadata {
@@ -208,13 +296,20 @@ mod tests {
             name: "SpaceShips".to_string(),
             symbol: "SPACE".to_string(),
             minter: None,
+            creator: None,
             withdraw_address: None,
+            metadata_update_authority: None,
+            transfer_policy: None,
+            sequential_token_ids: None,
+            max_supply: None,
+            index_traits: None,
+            collection_info_extension: None,
         };
         entry::instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
 
         let token_id = "Enterprise";
         let exec_msg = ExecuteMsg::Mint {
-            token_id: token_id.to_string(),
+            token_id: Some(token_id.to_string()),
             owner: "john".to_string(),
             token_uri: Some("https://starships.example.com/Starship/Enterprise.json".into()),
             extension: Some(Metadata {
@@ -247,7 +342,14 @@ mod tests {
             name: "SpaceShips".to_string(),
             symbol: "SPACE".to_string(),
             minter: None,
+            creator: None,
             withdraw_address: None,
+            metadata_update_authority: None,
+            transfer_policy: None,
+            sequential_token_ids: None,
+            max_supply: None,
+            index_traits: None,
+            collection_info_extension: None,
         };
         let env = mock_env();
         entry::instantiate(deps.as_mut(), env.clone(), info.clone(), init_msg).unwrap();
@@ -255,7 +357,7 @@ mod tests {
         let token_id = "Enterprise";
         let owner = "jeanluc";
         let exec_msg = ExecuteMsg::Mint {
-            token_id: token_id.to_string(),
+            token_id: Some(token_id.to_string()),
             owner: owner.into(),
             token_uri: Some("https://starships.example.com/Starship/Enterprise.json".into()),
             extension: Some(Metadata {
@@ -295,7 +397,7 @@ mod tests {
         let voyager_token_id = "Voyager";
         let owner = "janeway";
         let voyager_exec_msg = ExecuteMsg::Mint {
-            token_id: voyager_token_id.to_string(),
+            token_id: Some(voyager_token_id.to_string()),
             owner: owner.into(),
             token_uri: Some("https://starships.example.com/Starship/Voyager.json".into()),
             extension: Some(Metadata {
@@ -324,4 +426,109 @@ mod tests {
         .unwrap();
         assert_eq!(res, voyager_expected);
     }
+
+    #[test]
+    fn check_collection_royalty_fallback() {
+        let mut deps = mock_dependencies();
+
+        let info = mock_info(CREATOR, &[]);
+        let init_msg = InstantiateMsg {
+            name: "SpaceShips".to_string(),
+            symbol: "SPACE".to_string(),
+            minter: None,
+            creator: None,
+            withdraw_address: None,
+            metadata_update_authority: None,
+            transfer_policy: None,
+            sequential_token_ids: None,
+            max_supply: None,
+            index_traits: None,
+            collection_info_extension: None,
+        };
+        let env = mock_env();
+        entry::instantiate(deps.as_mut(), env.clone(), info.clone(), init_msg).unwrap();
+
+        for (token_id, royalty_payment_address, royalty_percentage) in [
+            ("Enterprise", Some("jeanluc"), Some(10)),
+            ("Defiant", None, None),
+            ("Voyager", Some("kathryn"), None),
+        ] {
+            let exec_msg = ExecuteMsg::Mint {
+                token_id: Some(token_id.to_string()),
+                owner: "jeanluc".into(),
+                token_uri: None,
+                extension: Some(Metadata {
+                    royalty_payment_address: royalty_payment_address.map(str::to_string),
+                    royalty_percentage,
+                    ..Metadata::default()
+                }),
+            };
+            entry::execute(deps.as_mut(), env.clone(), info.clone(), exec_msg).unwrap();
+        }
+        let exec_msg = ExecuteMsg::SetCollectionRoyalty {
+            royalty: Some(cw721_base::msg::RoyaltyInfoMsg {
+                payment_address: "starfleet".to_string(),
+                share: Decimal::percent(5),
+            }),
+        };
+        entry::execute(deps.as_mut(), env.clone(), info.clone(), exec_msg).unwrap();
+
+        // royalties in metadata take precedence
+        let res = query_royalties_info(
+            deps.as_ref(),
+            env.clone(),
+            "Enterprise".to_string(),
+            Uint128::new(100),
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            RoyaltiesInfoResponse {
+                address: "jeanluc".into(),
+                royalty_amount: Uint128::new(10),
+            }
+        );
+
+        // otherwise the collection royalty applies, also for a payment address without percentage
+        for token_id in ["Defiant", "Voyager"] {
+            let res = query_royalties_info(
+                deps.as_ref(),
+                env.clone(),
+                token_id.to_string(),
+                Uint128::new(100),
+            )
+            .unwrap();
+            assert_eq!(
+                res,
+                RoyaltiesInfoResponse {
+                    address: "starfleet".into(),
+                    royalty_amount: Uint128::new(5),
+                }
+            );
+        }
+
+        // token royalty set by the creator overrides royalties in metadata
+        let exec_msg = ExecuteMsg::SetTokenRoyalty {
+            token_id: "Enterprise".to_string(),
+            royalty: Some(cw721_base::msg::RoyaltyInfoMsg {
+                payment_address: "picard".to_string(),
+                share: Decimal::percent(20),
+            }),
+        };
+        entry::execute(deps.as_mut(), env.clone(), info, exec_msg).unwrap();
+        let res = query_royalties_info(
+            deps.as_ref(),
+            env,
+            "Enterprise".to_string(),
+            Uint128::new(100),
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            RoyaltiesInfoResponse {
+                address: "picard".into(),
+                royalty_amount: Uint128::new(20),
+            }
+        );
+    }
 }
@@ -2,12 +2,16 @@ use crate::Extension;
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Uint128};
 use cw721::msg::Cw721QueryMsg;
+pub use cw721_base::msg::{CheckRoyaltiesResponse, RoyaltiesInfoResponse};
 use cw721_base::{
     msg::{
-        AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, MinterResponse, NftInfoResponse,
-        NumTokensResponse, OperatorResponse, OperatorsResponse, OwnerOfResponse, TokensResponse,
+        AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, ApprovedOwnersResponse,
+        BalanceOfResponse, BurnedTokensResponse, CollectionInfoAndExtensionResponse, HooksResponse,
+        MinterResponse, NftInfoResponse, NumTokensResponse, OperatorResponse, OperatorsResponse,
+        OrderBy, OwnerOfResponse, SupplyResponse, TokenLockResponse, TokenLocksResponse,
+        TokensResponse, TokensWithInfoResponse, TraitCountsResponse,
     },
-    state::CollectionInfo,
+    state::{CollectionInfo, DefaultOptionCollectionInfoExtension, PauseStatus},
 };
 use cw_ownable::Ownership;
 
@@ -70,23 +74,54 @@ pub enum QueryMsg {
         /// unset or false will filter out expired items, you must set to true to see them
         include_expired: Option<bool>,
         start_after: Option<String>,
+        /// upper bound (exclusive), e.g. for loading the previous page
+        start_before: Option<String>,
+        limit: Option<u32>,
+        /// unset defaults to ascending
+        order: Option<OrderBy>,
+    },
+    /// List all token_ids the spender has been approved for, operator grants excluded
+    #[returns(TokensResponse)]
+    TokensApprovedFor {
+        spender: String,
+        /// unset or false will filter out expired approvals, you must set to true to see them
+        include_expired: Option<bool>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// List all owners that granted the operator access to all of their tokens
+    #[returns(ApprovedOwnersResponse)]
+    ApprovedOwnersForOperator {
+        operator: String,
+        /// unset or false will filter out expired items, you must set to true to see them
+        include_expired: Option<bool>,
+        start_after: Option<String>,
         limit: Option<u32>,
     },
     /// Total number of tokens issued
     #[returns(NumTokensResponse)]
     NumTokens {},
+    /// Number of tokens owned by the given address
+    #[returns(BalanceOfResponse)]
+    BalanceOf { owner: String },
 
     #[returns(CollectionInfo)]
     ContractInfo {},
 
     /// With MetaData Extension.
     /// Returns top-level metadata about the contract
-    #[returns(CollectionInfo)]
+    #[returns(CollectionInfoAndExtensionResponse<DefaultOptionCollectionInfoExtension>)]
     GetCollectionInfo {},
 
     #[returns(Ownership<Addr>)]
     Ownership {},
 
+    #[returns(Ownership<Addr>)]
+    GetMinterOwnership {},
+
+    #[returns(Ownership<Addr>)]
+    GetCreatorOwnership {},
+
     /// With MetaData Extension.
     /// Returns metadata about one particular token, based on *ERC721 Metadata JSON Schema*
     /// but directly from the contract
@@ -108,14 +143,39 @@ pub enum QueryMsg {
     Tokens {
         owner: String,
         start_after: Option<String>,
+        /// upper bound (exclusive), e.g. for loading the previous page
+        start_before: Option<String>,
         limit: Option<u32>,
+        /// unset defaults to ascending
+        order: Option<OrderBy>,
     },
     /// With Enumerable extension.
     /// Requires pagination. Lists all token_ids controlled by the contract.
     #[returns(TokensResponse)]
     AllTokens {
+        start_after: Option<String>,
+        /// upper bound (exclusive), e.g. for loading the previous page
+        start_before: Option<String>,
+        limit: Option<u32>,
+        /// unset defaults to ascending
+        order: Option<OrderBy>,
+    },
+    /// With Enumerable extension.
+    /// Like `Tokens`, but returns owner, token_uri and extension of each token as well.
+    #[returns(TokensWithInfoResponse<Extension>)]
+    TokensWithInfo {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        include_approvals: Option<bool>,
+    },
+    /// With Enumerable extension.
+    /// Like `AllTokens`, but returns owner, token_uri and extension of each token as well.
+    #[returns(TokensWithInfoResponse<Extension>)]
+    AllTokensWithInfo {
         start_after: Option<String>,
         limit: Option<u32>,
+        include_approvals: Option<bool>,
     },
 
     /// Return the minter
@@ -125,6 +185,57 @@ pub enum QueryMsg {
     #[returns(Option<String>)]
     GetWithdrawAddress {},
 
+    /// Returns which operations are currently paused
+    #[returns(PauseStatus)]
+    PauseStatus {},
+
+    /// Returns max supply, total minted and total burned tokens
+    #[returns(SupplyResponse)]
+    Supply {},
+
+    /// Requires pagination. Lists all registered hooks.
+    #[returns(HooksResponse)]
+    Hooks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the active lock of a token, or `None` if it is not locked
+    #[returns(Option<TokenLockResponse>)]
+    GetTokenLock { token_id: String },
+    /// Returns whether a token with the given id has been burned
+    #[returns(bool)]
+    IsBurned { token_id: String },
+    /// Requires pagination. Lists all burned tokens.
+    #[returns(BurnedTokensResponse)]
+    BurnedTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Requires pagination. Lists all active token locks.
+    #[returns(TokenLocksResponse)]
+    AllTokenLocks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Requires pagination and the trait index. Lists all token_ids having the given attribute.
+    #[returns(TokensResponse)]
+    TokensByTrait {
+        trait_type: String,
+        value: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Requires pagination and the trait index. Lists the number of tokens per value
+    /// of the given trait type, e.g. for rarity tables.
+    #[returns(TraitCountsResponse)]
+    TraitCounts {
+        trait_type: String,
+        /// value to start after
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
     // -- below queries, Extension and GetCollectionInfoExtension, are just dummies, since type annotations are required for
     // -- TMetadataExtension and TCollectionInfoExtension, Error:
     // -- "type annotations needed: cannot infer type for type parameter `TMetadataExtension` declared on the enum `Cw721QueryMsg`"
@@ -132,8 +243,8 @@ pub enum QueryMsg {
     Extension { msg: Extension },
 }
 
-impl From<QueryMsg> for Cw721QueryMsg<Extension> {
-    fn from(msg: QueryMsg) -> Cw721QueryMsg<Extension> {
+impl From<QueryMsg> for Cw721QueryMsg<Extension, DefaultOptionCollectionInfoExtension> {
+    fn from(msg: QueryMsg) -> Cw721QueryMsg<Extension, DefaultOptionCollectionInfoExtension> {
         match msg {
             QueryMsg::OwnerOf {
                 token_id,
@@ -143,7 +254,11 @@ impl From<QueryMsg> for Cw721QueryMsg<Extension> {
                 include_expired,
             },
             QueryMsg::NumTokens {} => Cw721QueryMsg::NumTokens {},
+            QueryMsg::BalanceOf { owner } => Cw721QueryMsg::BalanceOf { owner },
             QueryMsg::ContractInfo {} => Cw721QueryMsg::ContractInfo {},
+            QueryMsg::GetCollectionInfo {} => Cw721QueryMsg::GetCollectionInfo {},
+            QueryMsg::GetMinterOwnership {} => Cw721QueryMsg::GetMinterOwnership {},
+            QueryMsg::GetCreatorOwnership {} => Cw721QueryMsg::GetCreatorOwnership {},
             QueryMsg::NftInfo { token_id } => Cw721QueryMsg::NftInfo { token_id },
             QueryMsg::AllNftInfo {
                 token_id,
@@ -155,27 +270,116 @@ impl From<QueryMsg> for Cw721QueryMsg<Extension> {
             QueryMsg::Tokens {
                 owner,
                 start_after,
+                start_before,
                 limit,
+                order,
             } => Cw721QueryMsg::Tokens {
                 owner,
                 start_after,
+                start_before,
                 limit,
+                order,
+            },
+            QueryMsg::AllTokens {
+                start_after,
+                start_before,
+                limit,
+                order,
+            } => Cw721QueryMsg::AllTokens {
+                start_after,
+                start_before,
+                limit,
+                order,
+            },
+            QueryMsg::TokensWithInfo {
+                owner,
+                start_after,
+                limit,
+                include_approvals,
+            } => Cw721QueryMsg::TokensWithInfo {
+                owner,
+                start_after,
+                limit,
+                include_approvals,
+            },
+            QueryMsg::AllTokensWithInfo {
+                start_after,
+                limit,
+                include_approvals,
+            } => Cw721QueryMsg::AllTokensWithInfo {
+                start_after,
+                limit,
+                include_approvals,
             },
-            QueryMsg::AllTokens { start_after, limit } => {
-                Cw721QueryMsg::AllTokens { start_after, limit }
-            }
             #[allow(deprecated)]
             QueryMsg::Minter {} => Cw721QueryMsg::Minter {},
             QueryMsg::GetWithdrawAddress {} => Cw721QueryMsg::GetWithdrawAddress {},
+            QueryMsg::PauseStatus {} => Cw721QueryMsg::PauseStatus {},
+            QueryMsg::Supply {} => Cw721QueryMsg::Supply {},
+            QueryMsg::Hooks { start_after, limit } => Cw721QueryMsg::Hooks { start_after, limit },
+            QueryMsg::GetTokenLock { token_id } => Cw721QueryMsg::GetTokenLock { token_id },
+            QueryMsg::IsBurned { token_id } => Cw721QueryMsg::IsBurned { token_id },
+            QueryMsg::BurnedTokens { start_after, limit } => {
+                Cw721QueryMsg::BurnedTokens { start_after, limit }
+            }
+            QueryMsg::AllTokenLocks { start_after, limit } => {
+                Cw721QueryMsg::AllTokenLocks { start_after, limit }
+            }
+            QueryMsg::TokensByTrait {
+                trait_type,
+                value,
+                start_after,
+                limit,
+            } => Cw721QueryMsg::TokensByTrait {
+                trait_type,
+                value,
+                start_after,
+                limit,
+            },
+            QueryMsg::TraitCounts {
+                trait_type,
+                start_after,
+                limit,
+            } => Cw721QueryMsg::TraitCounts {
+                trait_type,
+                start_after,
+                limit,
+            },
             QueryMsg::AllOperators {
                 owner,
                 include_expired,
                 start_after,
+                start_before,
                 limit,
+                order,
             } => Cw721QueryMsg::AllOperators {
                 owner,
                 include_expired,
                 start_after,
+                start_before,
+                limit,
+                order,
+            },
+            QueryMsg::TokensApprovedFor {
+                spender,
+                include_expired,
+                start_after,
+                limit,
+            } => Cw721QueryMsg::TokensApprovedFor {
+                spender,
+                include_expired,
+                start_after,
+                limit,
+            },
+            QueryMsg::ApprovedOwnersForOperator {
+                operator,
+                include_expired,
+                start_after,
+                limit,
+            } => Cw721QueryMsg::ApprovedOwnersForOperator {
+                operator,
+                include_expired,
+                start_after,
                 limit,
             },
             QueryMsg::Approval {
@@ -198,18 +402,3 @@ impl From<QueryMsg> for Cw721QueryMsg<Extension> {
         }
     }
 }
-
-#[cw_serde]
-pub struct RoyaltiesInfoResponse {
-    pub address: String,
-    // Note that this must be the same denom as that passed in to RoyaltyInfo
-    // rounding up or down is at the discretion of the implementer
-    pub royalty_amount: Uint128,
-}
-
-/// Shows if the contract implements royalties
-/// if royalty_payments is true, marketplaces should pay them
-#[cw_serde]
-pub struct CheckRoyaltiesResponse {
-    pub royalty_payments: bool,
-}
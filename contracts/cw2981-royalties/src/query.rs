@@ -1,8 +1,11 @@
 use crate::msg::{CheckRoyaltiesResponse, RoyaltiesInfoResponse};
-use crate::Cw2981Contract;
+use crate::{Cw2981Contract, Metadata};
 use cosmwasm_std::{Decimal, Deps, Env, StdResult, Uint128};
 use cw721_base::query::Cw721Query;
 
+/// Royalty set via `SetTokenRoyalty` takes precedence, then royalties in metadata,
+/// otherwise the collection royalty set via `SetCollectionRoyalty` applies.
+/// Royalties in metadata only apply if `royalty_percentage` is set.
 /// NOTE: default behaviour here is to round down
 /// EIP2981 specifies that the rounding behaviour is at the discretion of the implementer
 pub fn query_royalties_info(
@@ -12,29 +15,30 @@ pub fn query_royalties_info(
     sale_price: Uint128,
 ) -> StdResult<RoyaltiesInfoResponse> {
     let contract = Cw2981Contract::default();
-    let token_info = contract.query_nft_info(deps, env, token_id)?;
+    let token_info = contract.query_nft_info(deps, env.clone(), token_id.clone())?;
 
-    let royalty_percentage = match token_info.extension {
-        Some(ref ext) => match ext.royalty_percentage {
-            Some(percentage) => Decimal::percent(percentage),
-            None => Decimal::percent(0),
-        },
-        None => Decimal::percent(0),
-    };
-    let royalty_from_sale_price = sale_price * royalty_percentage;
-
-    let royalty_address = match token_info.extension {
-        Some(ext) => match ext.royalty_payment_address {
-            Some(addr) => addr,
-            None => String::from(""),
-        },
-        None => String::from(""),
-    };
-
-    Ok(RoyaltiesInfoResponse {
-        address: royalty_address,
-        royalty_amount: royalty_from_sale_price,
-    })
+    if let Some(royalty) = contract
+        .config
+        .token_royalties
+        .may_load(deps.storage, &token_id)?
+    {
+        Ok(RoyaltiesInfoResponse {
+            address: royalty.payment_address.to_string(),
+            royalty_amount: sale_price * royalty.share,
+        })
+    } else if let Some(Metadata {
+        royalty_percentage: Some(royalty_percentage),
+        royalty_payment_address,
+        ..
+    }) = token_info.extension
+    {
+        Ok(RoyaltiesInfoResponse {
+            address: royalty_payment_address.unwrap_or_default(),
+            royalty_amount: sale_price * Decimal::percent(royalty_percentage),
+        })
+    } else {
+        contract.query_royalty_info(deps, env, token_id, sale_price)
+    }
 }
 
 /// As our default implementation here specifies royalties at token level
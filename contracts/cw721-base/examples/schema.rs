@@ -1,7 +1,10 @@
 use cosmwasm_schema::{export_schema_with_title, remove_schemas, schema_for};
 use cosmwasm_std::Empty;
 use cw721::state::DefaultOptionMetadataExtension;
-use cw721_base::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use cw721_base::msg::{
+    DefaultOptionCollectionInfoExtensionMsg, DefaultOptionMetadataExtensionMsg, ExecuteMsg,
+    InstantiateMsg, MigrateMsg, QueryMsg,
+};
 use std::env::current_dir;
 use std::fs::create_dir_all;
 
@@ -12,12 +15,18 @@ fn main() {
     remove_schemas(&out_dir).unwrap();
 
     // entry points - generate always with title for avoiding name suffixes like "..._empty_for_..." due to generics
-    export_schema_with_title(&schema_for!(InstantiateMsg), &out_dir, "InstantiateMsg");
     export_schema_with_title(
-        &schema_for!(ExecuteMsg::<DefaultOptionMetadataExtension, Empty>),
+        &schema_for!(InstantiateMsg<DefaultOptionCollectionInfoExtensionMsg>),
+        &out_dir,
+        "InstantiateMsg",
+    );
+    export_schema_with_title(
+        &schema_for!(
+            ExecuteMsg::<DefaultOptionMetadataExtension, DefaultOptionMetadataExtensionMsg>
+        ),
         &out_dir,
         "ExecuteMsg",
     );
-    export_schema_with_title(&schema_for!(QueryMsg<Empty>), &out_dir, "QueryMsg");
+    export_schema_with_title(&schema_for!(QueryMsg<Empty, Empty>), &out_dir, "QueryMsg");
     export_schema_with_title(&schema_for!(MigrateMsg), &out_dir, "MigrateMsg");
 }
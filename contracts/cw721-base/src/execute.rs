@@ -1,17 +1,37 @@
 use cosmwasm_std::CustomMsg;
 // expose to all others using contract, so others dont need to import cw721
 pub use cw721::execute::*;
+use cw721::msg::NftInfoUpdate;
+use cw721::state::TraitSource;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::Cw721Contract;
 
-impl<'a, TMetadataExtension, TCustomResponseMessage, TMetadataExtensionMsg>
-    Cw721Execute<TMetadataExtension, TCustomResponseMessage, TMetadataExtensionMsg>
-    for Cw721Contract<'a, TMetadataExtension, TCustomResponseMessage, TMetadataExtensionMsg>
+impl<
+        'a,
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
+    Cw721Execute<
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
+    for Cw721Contract<
+        'a,
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
 where
-    TMetadataExtension: Serialize + DeserializeOwned + Clone,
+    TMetadataExtension: Serialize + DeserializeOwned + Clone + TraitSource,
     TCustomResponseMessage: CustomMsg,
-    TMetadataExtensionMsg: CustomMsg,
+    TMetadataExtensionMsg: CustomMsg + NftInfoUpdate<TMetadataExtension>,
+    TCollectionInfoExtension: Serialize + DeserializeOwned + Clone,
 {
 }
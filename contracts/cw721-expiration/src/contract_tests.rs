@@ -7,12 +7,13 @@ use cosmwasm_std::{
 };
 
 use cw721::error::Cw721ContractError;
+use cw721::events::Cw721Event;
 use cw721::msg::{
     ApprovalResponse, Cw721ExecuteMsg, NftInfoResponse, OperatorResponse, OperatorsResponse,
     OwnerOfResponse, TokensResponse,
 };
 use cw721::receiver::Cw721ReceiveMsg;
-use cw721::state::{CollectionInfo, MINTER};
+use cw721::state::{CollectionInfo, DefaultOptionCollectionInfoExtension, MINTER};
 use cw721::{query::Cw721Query, Approval, Expiration};
 use cw_ownable::{Action, Ownership, OwnershipError};
 
@@ -29,15 +30,32 @@ const SYMBOL: &str = "MGK";
 fn setup_contract(
     deps: DepsMut<'_>,
     expiration_days: u16,
-) -> Cw721ExpirationContract<'static, DefaultOptionMetadataExtension, Empty, Empty> {
-    let contract =
-        Cw721ExpirationContract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+) -> Cw721ExpirationContract<
+    'static,
+    DefaultOptionMetadataExtension,
+    Empty,
+    Empty,
+    DefaultOptionCollectionInfoExtension,
+> {
+    let contract = Cw721ExpirationContract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
     let msg = InstantiateMsg {
         expiration_days,
         name: CONTRACT_NAME.to_string(),
         symbol: SYMBOL.to_string(),
         minter: Some(String::from(MINTER_ADDR)),
+        creator: None,
         withdraw_address: None,
+        metadata_update_authority: None,
+        transfer_policy: None,
+        sequential_token_ids: None,
+        max_supply: None,
+        index_traits: None,
+        collection_info_extension: None,
     };
     let info = mock_info("creator", &[]);
     let res = contract.instantiate(deps, mock_env(), info, msg).unwrap();
@@ -48,15 +66,26 @@ fn setup_contract(
 #[test]
 fn proper_instantiation() {
     let mut deps = mock_dependencies();
-    let contract =
-        Cw721ExpirationContract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+    let contract = Cw721ExpirationContract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
 
     let msg = InstantiateMsg {
         expiration_days: 1,
         name: CONTRACT_NAME.to_string(),
         symbol: SYMBOL.to_string(),
         minter: Some(String::from(MINTER_ADDR)),
+        creator: None,
         withdraw_address: Some(String::from(CREATOR_ADDR)),
+        metadata_update_authority: None,
+        transfer_policy: None,
+        sequential_token_ids: None,
+        max_supply: None,
+        index_traits: None,
+        collection_info_extension: None,
     };
     let info = mock_info("creator", &[]);
     let env = mock_env();
@@ -98,7 +127,15 @@ fn proper_instantiation() {
 
     // list the token_ids
     let tokens = contract
-        .query_all_tokens_include_expired_nft(deps.as_ref(), mock_env(), None, None, false)
+        .query_all_tokens_include_expired_nft(
+            deps.as_ref(),
+            mock_env(),
+            None,
+            None,
+            None,
+            None,
+            false,
+        )
         .unwrap();
     assert_eq!(0, tokens.tokens.len());
 }
@@ -106,15 +143,26 @@ fn proper_instantiation() {
 #[test]
 fn proper_instantiation_with_collection_info() {
     let mut deps = mock_dependencies();
-    let contract =
-        Cw721ExpirationContract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+    let contract = Cw721ExpirationContract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
 
     let msg = InstantiateMsg {
         expiration_days: 1,
         name: CONTRACT_NAME.to_string(),
         symbol: SYMBOL.to_string(),
         minter: Some(String::from(MINTER_ADDR)),
+        creator: None,
         withdraw_address: Some(String::from(CREATOR_ADDR)),
+        metadata_update_authority: None,
+        transfer_policy: None,
+        sequential_token_ids: None,
+        max_supply: None,
+        index_traits: None,
+        collection_info_extension: None,
     };
     let info = mock_info("creator", &[]);
     let env = mock_env();
@@ -156,7 +204,15 @@ fn proper_instantiation_with_collection_info() {
 
     // list the token_ids
     let tokens = contract
-        .query_all_tokens_include_expired_nft(deps.as_ref(), mock_env(), None, None, false)
+        .query_all_tokens_include_expired_nft(
+            deps.as_ref(),
+            mock_env(),
+            None,
+            None,
+            None,
+            None,
+            false,
+        )
         .unwrap();
     assert_eq!(0, tokens.tokens.len());
 }
@@ -170,7 +226,7 @@ fn test_mint() {
     let token_uri = "https://www.merriam-webster.com/dictionary/atomize".to_string();
 
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner: String::from("medusa"),
         token_uri: Some(token_uri.clone()),
         extension: None,
@@ -244,7 +300,7 @@ fn test_mint() {
 
     // Cannot mint same token_id again
     let mint_msg2 = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner: String::from("hercules"),
         token_uri: None,
         extension: None,
@@ -258,7 +314,15 @@ fn test_mint() {
 
     // list the token_ids
     let tokens = contract
-        .query_all_tokens_include_expired_nft(deps.as_ref(), mock_env(), None, None, false)
+        .query_all_tokens_include_expired_nft(
+            deps.as_ref(),
+            mock_env(),
+            None,
+            None,
+            None,
+            None,
+            false,
+        )
         .unwrap();
     assert_eq!(1, tokens.tokens.len());
     assert_eq!(vec![token_id], tokens.tokens);
@@ -273,7 +337,7 @@ fn test_update_minter() {
     let token_uri = "https://www.merriam-webster.com/dictionary/petrify".to_string();
 
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id,
+        token_id: Some(token_id),
         owner: String::from("medusa"),
         token_uri: Some(token_uri.clone()),
         extension: None,
@@ -338,7 +402,7 @@ fn test_update_minter() {
     assert_eq!(minter_ownership.owner, Some(random_info.sender.clone()));
 
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: "randoms_token".to_string(),
+        token_id: Some("randoms_token".to_string()),
         owner: String::from("medusa"),
         token_uri: Some(token_uri),
         extension: None,
@@ -359,6 +423,44 @@ fn test_update_minter() {
         .unwrap();
 }
 
+#[test]
+fn test_pause_minting() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut(), 1);
+
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::UpdatePauseStatus {
+                minting: Some(true),
+                transfers: None,
+                burns: None,
+            },
+        )
+        .unwrap();
+
+    // minting with timestamp can't bypass pause
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::Mint {
+                token_id: Some("1".to_string()),
+                owner: MINTER_ADDR.to_string(),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Cw721(Cw721ContractError::MintingPaused {})
+    );
+}
+
 #[test]
 fn test_burn() {
     let mut deps = mock_dependencies();
@@ -368,7 +470,7 @@ fn test_burn() {
     let token_uri = "https://www.merriam-webster.com/dictionary/petrify".to_string();
 
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner: MINTER_ADDR.to_string(),
         token_uri: Some(token_uri),
         extension: None,
@@ -419,7 +521,15 @@ fn test_burn() {
 
     // list the token_ids
     let tokens = contract
-        .query_all_tokens_include_expired_nft(deps.as_ref(), env.clone(), None, None, false)
+        .query_all_tokens_include_expired_nft(
+            deps.as_ref(),
+            env.clone(),
+            None,
+            None,
+            None,
+            None,
+            false,
+        )
         .unwrap();
     assert!(tokens.tokens.is_empty());
 
@@ -456,7 +566,7 @@ fn test_transfer_nft() {
 
     let owner = "owner";
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner: String::from(owner),
         token_uri: Some(token_uri),
         extension: None,
@@ -503,6 +613,16 @@ fn test_transfer_nft() {
     assert_eq!(
         res,
         Response::new()
+            .add_event(
+                Cw721Event::Transfer {
+                    sender: owner.to_string(),
+                    from: owner.to_string(),
+                    to: new_owner.to_string(),
+                    token_id: token_id.clone(),
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
             .add_attribute("action", "transfer_nft")
             .add_attribute("sender", owner)
             .add_attribute("recipient", new_owner)
@@ -536,7 +656,7 @@ fn test_send_nft() {
     let token_uri = "https://www.merriam-webster.com/dictionary/melt".to_string();
 
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner: String::from("venus"),
         token_uri: Some(token_uri),
         extension: None,
@@ -554,6 +674,7 @@ fn test_send_nft() {
         contract: target.clone(),
         token_id: token_id.clone(),
         msg: msg.clone(),
+        receive_msg_version: None,
     };
 
     let random = mock_info("random", &[]);
@@ -589,6 +710,16 @@ fn test_send_nft() {
         res,
         Response::new()
             .add_message(expected)
+            .add_event(
+                Cw721Event::Send {
+                    sender: "venus".to_string(),
+                    from: "venus".to_string(),
+                    to: "another_contract".to_string(),
+                    token_id: token_id.clone(),
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
             .add_attribute("action", "send_nft")
             .add_attribute("sender", "venus")
             .add_attribute("recipient", "another_contract")
@@ -622,7 +753,7 @@ fn test_approve_revoke() {
     let token_uri = "https://www.merriam-webster.com/dictionary/grow".to_string();
 
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner: String::from("demeter"),
         token_uri: Some(token_uri),
         extension: None,
@@ -668,6 +799,17 @@ fn test_approve_revoke() {
     assert_eq!(
         res,
         Response::new()
+            .add_event(
+                Cw721Event::Approve {
+                    sender: "demeter".to_string(),
+                    owner: "demeter".to_string(),
+                    spender: "random".to_string(),
+                    token_id: token_id.clone(),
+                    expires: Expiration::Never {},
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
             .add_attribute("action", "approve")
             .add_attribute("sender", "demeter")
             .add_attribute("spender", "random")
@@ -812,7 +954,7 @@ fn test_approve_all_revoke_all() {
     let token_uri2 = "https://www.merriam-webster.com/dictionary/grow2".to_string();
 
     let mint_msg1 = Cw721ExecuteMsg::Mint {
-        token_id: token_id1.clone(),
+        token_id: Some(token_id1.clone()),
         owner: String::from("demeter"),
         token_uri: Some(token_uri1),
         extension: None,
@@ -824,7 +966,7 @@ fn test_approve_all_revoke_all() {
         .unwrap();
 
     let mint_msg2 = Cw721ExecuteMsg::Mint {
-        token_id: token_id2.clone(),
+        token_id: Some(token_id2.clone()),
         owner: String::from("demeter"),
         token_uri: Some(token_uri2),
         extension: None,
@@ -836,7 +978,15 @@ fn test_approve_all_revoke_all() {
 
     // paginate the token_ids
     let tokens = contract
-        .query_all_tokens_include_expired_nft(deps.as_ref(), mock_env(), None, Some(1), false)
+        .query_all_tokens_include_expired_nft(
+            deps.as_ref(),
+            mock_env(),
+            None,
+            None,
+            Some(1),
+            None,
+            false,
+        )
         .unwrap();
     assert_eq!(1, tokens.tokens.len());
     assert_eq!(vec![token_id1.clone()], tokens.tokens);
@@ -845,7 +995,9 @@ fn test_approve_all_revoke_all() {
             deps.as_ref(),
             mock_env(),
             Some(token_id1.clone()),
+            None,
             Some(3),
+            None,
             false,
         )
         .unwrap();
@@ -864,6 +1016,15 @@ fn test_approve_all_revoke_all() {
     assert_eq!(
         res,
         Response::new()
+            .add_event(
+                Cw721Event::ApproveAll {
+                    owner: "demeter".to_string(),
+                    operator: "random".to_string(),
+                    expires: Expiration::Never {},
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
             .add_attribute("action", "approve_all")
             .add_attribute("sender", "demeter")
             .add_attribute("operator", "random")
@@ -891,6 +1052,7 @@ fn test_approve_all_revoke_all() {
         contract: String::from("another_contract"),
         token_id: token_id2,
         msg: to_json_binary(&msg).unwrap(),
+        receive_msg_version: None,
     };
     contract
         .execute(deps.as_mut(), mock_env(), random, send_msg)
@@ -950,6 +1112,8 @@ fn test_approve_all_revoke_all() {
             true,
             None,
             None,
+            None,
+            None,
         )
         .unwrap();
     assert_eq!(
@@ -982,7 +1146,9 @@ fn test_approve_all_revoke_all() {
             String::from("person"),
             true,
             None,
+            None,
             Some(1),
+            None,
         )
         .unwrap();
     assert_eq!(
@@ -1002,7 +1168,9 @@ fn test_approve_all_revoke_all() {
             String::from("person"),
             true,
             Some(String::from("buddy")),
+            None,
             Some(2),
+            None,
         )
         .unwrap();
     assert_eq!(
@@ -1045,6 +1213,8 @@ fn test_approve_all_revoke_all() {
             false,
             None,
             None,
+            None,
+            None,
         )
         .unwrap();
     assert_eq!(
@@ -1069,6 +1239,8 @@ fn test_approve_all_revoke_all() {
             false,
             None,
             None,
+            None,
+            None,
         )
         .unwrap();
     assert_eq!(0, res.operators.len());
@@ -1102,7 +1274,7 @@ fn test_tokens_by_owner() {
     let token_id3 = "sing".to_string();
 
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id1.clone(),
+        token_id: Some(token_id1.clone()),
         owner: demeter.clone(),
         token_uri: None,
         extension: None,
@@ -1112,7 +1284,7 @@ fn test_tokens_by_owner() {
         .unwrap();
 
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id2.clone(),
+        token_id: Some(token_id2.clone()),
         owner: ceres.clone(),
         token_uri: None,
         extension: None,
@@ -1122,7 +1294,7 @@ fn test_tokens_by_owner() {
         .unwrap();
 
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id3.clone(),
+        token_id: Some(token_id3.clone()),
         owner: demeter.clone(),
         token_uri: None,
         extension: None,
@@ -1134,12 +1306,28 @@ fn test_tokens_by_owner() {
     // get all tokens in order:
     let expected = vec![token_id1.clone(), token_id2.clone(), token_id3.clone()];
     let tokens = contract
-        .query_all_tokens_include_expired_nft(deps.as_ref(), mock_env(), None, None, false)
+        .query_all_tokens_include_expired_nft(
+            deps.as_ref(),
+            mock_env(),
+            None,
+            None,
+            None,
+            None,
+            false,
+        )
         .unwrap();
     assert_eq!(&expected, &tokens.tokens);
     // paginate
     let tokens = contract
-        .query_all_tokens_include_expired_nft(deps.as_ref(), mock_env(), None, Some(2), false)
+        .query_all_tokens_include_expired_nft(
+            deps.as_ref(),
+            mock_env(),
+            None,
+            None,
+            Some(2),
+            None,
+            false,
+        )
         .unwrap();
     assert_eq!(&expected[..2], &tokens.tokens[..]);
     let tokens = contract
@@ -1148,6 +1336,8 @@ fn test_tokens_by_owner() {
             mock_env(),
             Some(expected[1].clone()),
             None,
+            None,
+            None,
             false,
         )
         .unwrap();
@@ -1164,12 +1354,23 @@ fn test_tokens_by_owner() {
             demeter.clone(),
             None,
             None,
+            None,
+            None,
             false,
         )
         .unwrap();
     assert_eq!(&by_demeter, &tokens.tokens);
     let tokens = contract
-        .query_tokens_include_expired_nft(deps.as_ref(), mock_env(), ceres, None, None, false)
+        .query_tokens_include_expired_nft(
+            deps.as_ref(),
+            mock_env(),
+            ceres,
+            None,
+            None,
+            None,
+            None,
+            false,
+        )
         .unwrap();
     assert_eq!(&by_ceres, &tokens.tokens);
 
@@ -1180,7 +1381,9 @@ fn test_tokens_by_owner() {
             mock_env(),
             demeter.clone(),
             None,
+            None,
             Some(1),
+            None,
             false,
         )
         .unwrap();
@@ -1191,7 +1394,9 @@ fn test_tokens_by_owner() {
             mock_env(),
             demeter,
             Some(by_demeter[0].clone()),
+            None,
             Some(3),
+            None,
             false,
         )
         .unwrap();
@@ -1209,7 +1414,7 @@ fn test_nft_info() {
 
     let mut env = mock_env();
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner,
         token_uri: None,
         extension: None,
@@ -1251,7 +1456,7 @@ fn test_all_nft_info() {
 
     let mut env = mock_env();
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner,
         token_uri: None,
         extension: None,
@@ -1299,7 +1504,7 @@ fn test_owner_of() {
 
     let mut env = mock_env();
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner,
         token_uri: None,
         extension: None,
@@ -1347,7 +1552,7 @@ fn test_approval() {
 
     let mut env = mock_env();
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner: owner.clone(),
         token_uri: None,
         extension: None,
@@ -1403,7 +1608,7 @@ fn test_approvals() {
 
     let mut env = mock_env();
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner,
         token_uri: None,
         extension: None,
@@ -1451,7 +1656,7 @@ fn test_tokens() {
 
     let mut env = mock_env();
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner: owner.clone(),
         token_uri: None,
         extension: None,
@@ -1468,6 +1673,8 @@ fn test_tokens() {
             owner.clone(),
             None,
             None,
+            None,
+            None,
             false,
         )
         .unwrap();
@@ -1482,6 +1689,8 @@ fn test_tokens() {
             owner.clone(),
             None,
             None,
+            None,
+            None,
             false,
         )
         .unwrap();
@@ -1489,7 +1698,7 @@ fn test_tokens() {
 
     // assert invalid nft is returned
     let tokens = contract
-        .query_tokens_include_expired_nft(deps.as_ref(), env, owner, None, None, true)
+        .query_tokens_include_expired_nft(deps.as_ref(), env, owner, None, None, None, None, true)
         .unwrap();
     assert_eq!(
         tokens,
@@ -1510,7 +1719,7 @@ fn test_all_tokens() {
 
     let mut env = mock_env();
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner: owner.clone(),
         token_uri: None,
         extension: None,
@@ -1521,20 +1730,37 @@ fn test_all_tokens() {
 
     // assert valid nft is returned
     contract
-        .query_all_tokens_include_expired_nft(deps.as_ref(), env.clone(), None, None, false)
+        .query_all_tokens_include_expired_nft(
+            deps.as_ref(),
+            env.clone(),
+            None,
+            None,
+            None,
+            None,
+            false,
+        )
         .unwrap();
 
     // assert invalid nft is not returned
     let expiration = env.block.time.plus_days(1);
     env.block.time = expiration;
     let tokens = contract
-        .query_tokens_include_expired_nft(deps.as_ref(), env.clone(), owner, None, None, false)
+        .query_tokens_include_expired_nft(
+            deps.as_ref(),
+            env.clone(),
+            owner,
+            None,
+            None,
+            None,
+            None,
+            false,
+        )
         .unwrap();
     assert_eq!(tokens, TokensResponse { tokens: vec![] });
 
     // assert invalid nft is returned
     let tokens = contract
-        .query_all_tokens_include_expired_nft(deps.as_ref(), env, None, None, true)
+        .query_all_tokens_include_expired_nft(deps.as_ref(), env, None, None, None, None, true)
         .unwrap();
     assert_eq!(
         tokens,
@@ -1,7 +1,11 @@
 use cosmwasm_std::{Binary, CustomMsg, DepsMut, Env, MessageInfo, Response};
 use cw721::{
-    execute::Cw721Execute,
-    msg::{Cw721ExecuteMsg, Cw721InstantiateMsg},
+    execute::{check_not_paused, token_id_or_assign, Cw721Execute},
+    msg::{
+        Cw721ExecuteMsg, Cw721InstantiateMsg, IntoCollectionInfoExtension, MintMsg, NftInfoUpdate,
+    },
+    receiver::ReceiveMsgVersion,
+    state::TraitSource,
     Expiration,
 };
 use serde::de::DeserializeOwned;
@@ -12,21 +16,37 @@ use crate::{
     CONTRACT_VERSION,
 };
 
-impl<'a, TMetadataExtension, TCustomResponseMessage, TMetadataExtensionMsg>
-    Cw721ExpirationContract<'a, TMetadataExtension, TCustomResponseMessage, TMetadataExtensionMsg>
+impl<
+        'a,
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
+    Cw721ExpirationContract<
+        'a,
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
 where
-    TMetadataExtension: Serialize + DeserializeOwned + Clone,
+    TMetadataExtension: Serialize + DeserializeOwned + Clone + TraitSource,
     TCustomResponseMessage: CustomMsg,
-    TMetadataExtensionMsg: CustomMsg,
+    TMetadataExtensionMsg: CustomMsg + NftInfoUpdate<TMetadataExtension>,
+    TCollectionInfoExtension: Serialize + DeserializeOwned + Clone + Default,
 {
     // -- instantiate --
-    pub fn instantiate(
+    pub fn instantiate<TCollectionInfoExtensionMsg>(
         &self,
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
-        msg: InstantiateMsg,
-    ) -> Result<Response<TCustomResponseMessage>, ContractError> {
+        msg: InstantiateMsg<TCollectionInfoExtensionMsg>,
+    ) -> Result<Response<TCustomResponseMessage>, ContractError>
+    where
+        TCollectionInfoExtensionMsg: IntoCollectionInfoExtension<TCollectionInfoExtension>,
+    {
         if msg.expiration_days == 0 {
             return Err(ContractError::MinExpiration {});
         }
@@ -34,6 +54,7 @@ where
             TMetadataExtension,
             TCustomResponseMessage,
             TMetadataExtensionMsg,
+            TCollectionInfoExtension,
         >::default();
         contract
             .expiration_days
@@ -46,7 +67,14 @@ where
                 name: msg.name,
                 symbol: msg.symbol,
                 minter: msg.minter,
+                creator: msg.creator,
                 withdraw_address: msg.withdraw_address,
+                metadata_update_authority: msg.metadata_update_authority,
+                transfer_policy: msg.transfer_policy,
+                sequential_token_ids: msg.sequential_token_ids,
+                max_supply: msg.max_supply,
+                index_traits: msg.index_traits,
+                collection_info_extension: msg.collection_info_extension,
             },
             CONTRACT_NAME,
             CONTRACT_VERSION,
@@ -65,7 +93,10 @@ where
             TMetadataExtension,
             TCustomResponseMessage,
             TMetadataExtensionMsg,
+            TCollectionInfoExtension,
         >::default();
+        // pause is checked here too, since below some messages bypass base contract's `execute`
+        check_not_paused(deps.as_ref(), &msg)?;
         match msg {
             Cw721ExecuteMsg::Mint {
                 token_id,
@@ -75,6 +106,9 @@ where
             } => {
                 contract.mint_with_timestamp(deps, env, info, token_id, owner, token_uri, extension)
             }
+            Cw721ExecuteMsg::BatchMint { tokens } => {
+                contract.batch_mint_with_timestamp(deps, env, info, tokens)
+            }
             Cw721ExecuteMsg::Approve {
                 spender,
                 token_id,
@@ -91,10 +125,55 @@ where
                 contract: recipient,
                 token_id,
                 msg,
-            } => contract.send_nft_include_nft_expired(deps, env, info, recipient, token_id, msg),
+                receive_msg_version,
+            } => contract.send_nft_include_nft_expired(
+                deps,
+                env,
+                info,
+                recipient,
+                token_id,
+                msg,
+                receive_msg_version,
+            ),
+            Cw721ExecuteMsg::SafeTransferNft {
+                recipient,
+                token_id,
+                msg,
+                receive_msg_version,
+            } => contract.safe_transfer_nft_include_nft_expired(
+                deps,
+                env,
+                info,
+                recipient,
+                token_id,
+                msg,
+                receive_msg_version,
+            ),
+            Cw721ExecuteMsg::BatchTransferNft {
+                recipient,
+                token_ids,
+            } => contract
+                .batch_transfer_nft_include_nft_expired(deps, env, info, recipient, token_ids),
+            Cw721ExecuteMsg::BatchSendNft {
+                contract: recipient,
+                token_ids,
+                msg,
+                receive_msg_version,
+            } => contract.batch_send_nft_include_nft_expired(
+                deps,
+                env,
+                info,
+                recipient,
+                token_ids,
+                msg,
+                receive_msg_version,
+            ),
             Cw721ExecuteMsg::Burn { token_id } => {
                 contract.burn_nft_include_nft_expired(deps, env, info, token_id)
             }
+            Cw721ExecuteMsg::BatchBurn { token_ids } => {
+                contract.batch_burn_nft_include_nft_expired(deps, env, info, token_ids)
+            }
             _ => {
                 let response = contract.base_contract.execute(deps, env, info, msg)?;
                 Ok(response)
@@ -108,17 +187,41 @@ where
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
-        token_id: String,
+        token_id: Option<String>,
         owner: String,
         token_uri: Option<String>,
         extension: TMetadataExtension,
     ) -> Result<Response<TCustomResponseMessage>, ContractError> {
+        // assign token id here, since it is needed for storing the mint timestamp
+        let token_id = token_id_or_assign(deps.storage, token_id)?;
         let mint_timstamp = env.block.time;
         self.mint_timestamps
             .save(deps.storage, &token_id, &mint_timstamp)?;
         let res = self
             .base_contract
-            .mint(deps, info, token_id, owner, token_uri, extension)?
+            .mint(deps, info, Some(token_id), owner, token_uri, extension)?
+            .add_attribute("mint_timestamp", mint_timstamp.to_string());
+        Ok(res)
+    }
+
+    pub fn batch_mint_with_timestamp(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        mut tokens: Vec<MintMsg<TMetadataExtension>>,
+    ) -> Result<Response<TCustomResponseMessage>, ContractError> {
+        let mint_timstamp = env.block.time;
+        for token in &mut tokens {
+            // assign token ids here, since they are needed for storing the mint timestamps
+            let token_id = token_id_or_assign(deps.storage, token.token_id.take())?;
+            self.mint_timestamps
+                .save(deps.storage, &token_id, &mint_timstamp)?;
+            token.token_id = Some(token_id);
+        }
+        let res = self
+            .base_contract
+            .batch_mint(deps, info, tokens)?
             .add_attribute("mint_timestamp", mint_timstamp.to_string());
         Ok(res)
     }
@@ -166,6 +269,7 @@ where
             .transfer_nft(deps, env, info, recipient, token_id)?)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn send_nft_include_nft_expired(
         &self,
         deps: DepsMut,
@@ -174,11 +278,82 @@ where
         contract: String,
         token_id: String,
         msg: Binary,
+        receive_msg_version: Option<ReceiveMsgVersion>,
     ) -> Result<Response<TCustomResponseMessage>, ContractError> {
         self.assert_nft_expired(deps.as_ref(), &env, token_id.as_str())?;
+        Ok(self.base_contract.send_nft(
+            deps,
+            env,
+            info,
+            contract,
+            token_id,
+            msg,
+            receive_msg_version,
+        )?)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn safe_transfer_nft_include_nft_expired(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        recipient: String,
+        token_id: String,
+        msg: Option<Binary>,
+        receive_msg_version: Option<ReceiveMsgVersion>,
+    ) -> Result<Response<TCustomResponseMessage>, ContractError> {
+        self.assert_nft_expired(deps.as_ref(), &env, token_id.as_str())?;
+        Ok(self.base_contract.safe_transfer_nft(
+            deps,
+            env,
+            info,
+            recipient,
+            token_id,
+            msg,
+            receive_msg_version,
+        )?)
+    }
+
+    pub fn batch_transfer_nft_include_nft_expired(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        recipient: String,
+        token_ids: Vec<String>,
+    ) -> Result<Response<TCustomResponseMessage>, ContractError> {
+        for token_id in &token_ids {
+            self.assert_nft_expired(deps.as_ref(), &env, token_id)?;
+        }
         Ok(self
             .base_contract
-            .send_nft(deps, env, info, contract, token_id, msg)?)
+            .batch_transfer_nft(deps, env, info, recipient, token_ids)?)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn batch_send_nft_include_nft_expired(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        contract: String,
+        token_ids: Vec<String>,
+        msg: Binary,
+        receive_msg_version: Option<ReceiveMsgVersion>,
+    ) -> Result<Response<TCustomResponseMessage>, ContractError> {
+        for token_id in &token_ids {
+            self.assert_nft_expired(deps.as_ref(), &env, token_id)?;
+        }
+        Ok(self.base_contract.batch_send_nft(
+            deps,
+            env,
+            info,
+            contract,
+            token_ids,
+            msg,
+            receive_msg_version,
+        )?)
     }
 
     pub fn burn_nft_include_nft_expired(
@@ -191,4 +366,19 @@ where
         self.assert_nft_expired(deps.as_ref(), &env, token_id.as_str())?;
         Ok(self.base_contract.burn_nft(deps, env, info, token_id)?)
     }
+
+    pub fn batch_burn_nft_include_nft_expired(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        token_ids: Vec<String>,
+    ) -> Result<Response<TCustomResponseMessage>, ContractError> {
+        for token_id in &token_ids {
+            self.assert_nft_expired(deps.as_ref(), &env, token_id)?;
+        }
+        Ok(self
+            .base_contract
+            .batch_burn_nft(deps, env, info, token_ids)?)
+    }
 }
@@ -8,7 +8,7 @@ pub mod state;
 mod contract_tests;
 
 use cosmwasm_std::Empty;
-use cw721::state::DefaultOptionMetadataExtension;
+use cw721::state::{DefaultOptionCollectionInfoExtension, DefaultOptionMetadataExtension};
 
 // Version info for migration
 const CONTRACT_NAME: &str = "crates.io:cw721-expiration";
@@ -21,7 +21,7 @@ pub type NftInfo = cw721::state::NftInfo<DefaultOptionMetadataExtension>;
 pub mod entry {
     use crate::{
         error::ContractError,
-        msg::{InstantiateMsg, QueryMsg},
+        msg::{DefaultOptionCollectionInfoExtensionMsg, InstantiateMsg, QueryMsg},
         state::Cw721ExpirationContract,
     };
 
@@ -29,8 +29,10 @@ pub mod entry {
 
     #[cfg(not(feature = "library"))]
     use cosmwasm_std::entry_point;
-    use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
-    use cw721::{msg::Cw721ExecuteMsg, state::DefaultOptionMetadataExtension};
+    use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response};
+    use cw721::{
+        execute::Cw721Execute, msg::Cw721ExecuteMsg, state::DefaultOptionMetadataExtension,
+    };
 
     // This makes a conscious choice on the various generics used by the contract
     #[cfg_attr(not(feature = "library"), entry_point)]
@@ -38,10 +40,14 @@ pub mod entry {
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
-        msg: InstantiateMsg,
+        msg: InstantiateMsg<DefaultOptionCollectionInfoExtensionMsg>,
     ) -> Result<Response, ContractError> {
-        let contract =
-            Cw721ExpirationContract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+        let contract = Cw721ExpirationContract::<
+            DefaultOptionMetadataExtension,
+            Empty,
+            Empty,
+            DefaultOptionCollectionInfoExtension,
+        >::default();
         contract.instantiate(deps, env, info, msg)
     }
 
@@ -52,19 +58,38 @@ pub mod entry {
         info: MessageInfo,
         msg: Cw721ExecuteMsg<DefaultOptionMetadataExtension, Empty>,
     ) -> Result<Response, ContractError> {
-        let contract =
-            Cw721ExpirationContract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+        let contract = Cw721ExpirationContract::<
+            DefaultOptionMetadataExtension,
+            Empty,
+            Empty,
+            DefaultOptionCollectionInfoExtension,
+        >::default();
         contract.execute(deps, env, info, msg)
     }
 
+    #[entry_point]
+    pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+        let contract = Cw721ExpirationContract::<
+            DefaultOptionMetadataExtension,
+            Empty,
+            Empty,
+            DefaultOptionCollectionInfoExtension,
+        >::default();
+        Ok(contract.base_contract.reply(deps, env, msg)?)
+    }
+
     #[entry_point]
     pub fn query(
         deps: Deps,
         env: Env,
         msg: QueryMsg<DefaultOptionMetadataExtension>,
     ) -> Result<Binary, ContractError> {
-        let contract =
-            Cw721ExpirationContract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+        let contract = Cw721ExpirationContract::<
+            DefaultOptionMetadataExtension,
+            Empty,
+            Empty,
+            DefaultOptionCollectionInfoExtension,
+        >::default();
         contract.query(deps, env, msg)
     }
 
@@ -98,7 +123,14 @@ mod tests {
                 name: "collection_name".into(),
                 symbol: "collection_symbol".into(),
                 minter: Some("minter".into()),
+                creator: None,
                 withdraw_address: None,
+                metadata_update_authority: None,
+                transfer_policy: None,
+                sequential_token_ids: None,
+                max_supply: None,
+                index_traits: None,
+                collection_info_extension: None,
             },
         )
         .unwrap_err();
@@ -114,7 +146,14 @@ mod tests {
                 name: "".into(),
                 symbol: "".into(),
                 minter: Some("minter".into()),
+                creator: None,
                 withdraw_address: None,
+                metadata_update_authority: None,
+                transfer_policy: None,
+                sequential_token_ids: None,
+                max_supply: None,
+                index_traits: None,
+                collection_info_extension: None,
             },
         )
         .unwrap();
@@ -130,10 +169,15 @@ mod tests {
 
         assert_eq!(
             1,
-            Cw721ExpirationContract::<DefaultOptionMetadataExtension, Empty, Empty>::default()
-                .expiration_days
-                .load(deps.as_ref().storage)
-                .unwrap()
+            Cw721ExpirationContract::<
+                DefaultOptionMetadataExtension,
+                Empty,
+                Empty,
+                DefaultOptionCollectionInfoExtension,
+            >::default()
+            .expiration_days
+            .load(deps.as_ref().storage)
+            .unwrap()
         );
     }
 }
@@ -1,14 +1,17 @@
 use crate::DefaultOptionMetadataExtension;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
-use cw721::state::CollectionInfo;
+use cosmwasm_std::{Addr, Uint128};
+use cw721::state::{
+    DefaultOptionCollectionInfoExtension, MetadataUpdateAuthority, PauseStatus, SequentialTokenIds,
+    TransferPolicy,
+};
 use cw_ownable::Ownership;
 
 // expose to all others using contract, so others dont need to import cw721
 pub use cw721::msg::{Cw721ExecuteMsg as ExecuteMsg, Cw721MigrateMsg as MigrateMsg, *};
 
 #[cw_serde]
-pub struct InstantiateMsg {
+pub struct InstantiateMsg<TCollectionInfoExtension> {
     /// max 65535 days
     pub expiration_days: u16,
 
@@ -23,7 +26,23 @@ pub struct InstantiateMsg {
     /// or contract. You will likely replace this with custom logic in custom NFTs
     pub minter: Option<String>,
 
+    /// The creator owns the contract and can update collection info and metadata.
+    /// Defaults to the sender.
+    pub creator: Option<String>,
+
     pub withdraw_address: Option<String>,
+
+    pub metadata_update_authority: Option<MetadataUpdateAuthority>,
+
+    pub transfer_policy: Option<TransferPolicy>,
+
+    pub sequential_token_ids: Option<SequentialTokenIds>,
+
+    pub max_supply: Option<u64>,
+
+    pub index_traits: Option<bool>,
+
+    pub collection_info_extension: TCollectionInfoExtension,
 }
 
 #[cw_serde]
@@ -85,7 +104,11 @@ pub enum QueryMsg<TMetadataExtension> {
     Tokens {
         owner: String,
         start_after: Option<String>,
+        /// upper bound (exclusive), e.g. for loading the previous page
+        start_before: Option<String>,
         limit: Option<u32>,
+        /// unset defaults to ascending
+        order: Option<OrderBy>,
         /// unset or false will filter out expired nfts, you must set to true to see them
         include_expired_nft: Option<bool>,
     },
@@ -94,6 +117,66 @@ pub enum QueryMsg<TMetadataExtension> {
     /// Requires pagination. Lists all token_ids controlled by the contract.
     #[returns(cw721::msg::TokensResponse)]
     AllTokens {
+        start_after: Option<String>,
+        /// upper bound (exclusive), e.g. for loading the previous page
+        start_before: Option<String>,
+        limit: Option<u32>,
+        /// unset defaults to ascending
+        order: Option<OrderBy>,
+        /// unset or false will filter out expired nfts, you must set to true to see them
+        include_expired_nft: Option<bool>,
+    },
+
+    /// With Enumerable extension.
+    /// Like `Tokens`, but returns owner, token_uri and extension of each token as well.
+    #[returns(cw721::msg::TokensWithInfoResponse<DefaultOptionMetadataExtension>)]
+    TokensWithInfo {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        /// unset or false will omit approvals, set to true to include non-expired approvals
+        include_approvals: Option<bool>,
+        /// unset or false will filter out expired nfts, you must set to true to see them
+        include_expired_nft: Option<bool>,
+    },
+
+    /// With Enumerable extension.
+    /// Like `AllTokens`, but returns owner, token_uri and extension of each token as well.
+    #[returns(cw721::msg::TokensWithInfoResponse<DefaultOptionMetadataExtension>)]
+    AllTokensWithInfo {
+        start_after: Option<String>,
+        limit: Option<u32>,
+        /// unset or false will omit approvals, set to true to include non-expired approvals
+        include_approvals: Option<bool>,
+        /// unset or false will filter out expired nfts, you must set to true to see them
+        include_expired_nft: Option<bool>,
+    },
+
+    /// List all token_ids the spender has been approved for, operator grants excluded
+    #[returns(cw721::msg::TokensResponse)]
+    TokensApprovedFor {
+        spender: String,
+        /// unset or false will filter out expired approvals, you must set to true to see them
+        include_expired: Option<bool>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        /// unset or false will filter out expired nfts, you must set to true to see them
+        include_expired_nft: Option<bool>,
+    },
+    /// Returns the royalty of the token, falling back to the collection royalty.
+    #[returns(cw721::msg::RoyaltiesInfoResponse)]
+    RoyaltyInfo {
+        token_id: String,
+        /// the denom of this sale must also be the denom returned by RoyaltiesInfoResponse
+        sale_price: Uint128,
+        /// unset or false will filter out expired nfts, you must set to true to see them
+        include_expired_nft: Option<bool>,
+    },
+    /// Requires pagination and the trait index. Lists all token_ids having the given attribute.
+    #[returns(cw721::msg::TokensResponse)]
+    TokensByTrait {
+        trait_type: String,
+        value: String,
         start_after: Option<String>,
         limit: Option<u32>,
         /// unset or false will filter out expired nfts, you must set to true to see them
@@ -115,18 +198,34 @@ pub enum QueryMsg<TMetadataExtension> {
         /// unset or false will filter out expired items, you must set to true to see them
         include_expired: Option<bool>,
         start_after: Option<String>,
+        /// upper bound (exclusive), e.g. for loading the previous page
+        start_before: Option<String>,
+        limit: Option<u32>,
+        /// unset defaults to ascending
+        order: Option<OrderBy>,
+    },
+    /// List all owners that granted the operator access to all of their tokens
+    #[returns(cw721::msg::ApprovedOwnersResponse)]
+    ApprovedOwnersForOperator {
+        operator: String,
+        /// unset or false will filter out expired items, you must set to true to see them
+        include_expired: Option<bool>,
+        start_after: Option<String>,
         limit: Option<u32>,
     },
     /// Total number of tokens issued, including all expired NFTs
     #[returns(cw721::msg::NumTokensResponse)]
     NumTokens {},
+    /// Number of tokens owned by the given address, including all expired NFTs
+    #[returns(cw721::msg::BalanceOfResponse)]
+    BalanceOf { owner: String },
 
     #[returns(cw721::state::CollectionInfo)]
     ContractInfo {},
 
     /// With MetaData Extension.
     /// Returns top-level metadata about the contract
-    #[returns(CollectionInfo)]
+    #[returns(CollectionInfoAndExtensionResponse<DefaultOptionCollectionInfoExtension>)]
     GetCollectionInfo {},
 
     #[returns(Ownership<Addr>)]
@@ -135,6 +234,9 @@ pub enum QueryMsg<TMetadataExtension> {
     #[returns(Ownership<Addr>)]
     GetMinterOwnership {},
 
+    #[returns(Ownership<Addr>)]
+    GetCreatorOwnership {},
+
     /// Return the minter
     #[returns(MinterResponse)]
     Minter {},
@@ -145,4 +247,50 @@ pub enum QueryMsg<TMetadataExtension> {
 
     #[returns(Option<String>)]
     GetWithdrawAddress {},
+
+    /// Returns which operations are currently paused
+    #[returns(PauseStatus)]
+    PauseStatus {},
+
+    /// Returns max supply, total minted and total burned tokens
+    #[returns(SupplyResponse)]
+    Supply {},
+
+    /// Requires pagination. Lists all registered hooks.
+    #[returns(HooksResponse)]
+    Hooks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the active lock of a token, or `None` if it is not locked
+    #[returns(Option<TokenLockResponse>)]
+    GetTokenLock { token_id: String },
+    /// Returns whether a token with the given id has been burned
+    #[returns(bool)]
+    IsBurned { token_id: String },
+    /// Requires pagination. Lists all burned tokens.
+    #[returns(BurnedTokensResponse)]
+    BurnedTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Requires pagination. Lists all active token locks.
+    #[returns(TokenLocksResponse)]
+    AllTokenLocks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns whether any royalties are set, so marketplaces know whether to check `RoyaltyInfo`
+    #[returns(CheckRoyaltiesResponse)]
+    CheckRoyalties {},
+    /// Requires pagination and the trait index. Lists the number of tokens per value
+    /// of the given trait type, expired nfts included.
+    #[returns(TraitCountsResponse)]
+    TraitCounts {
+        trait_type: String,
+        /// value to start after
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
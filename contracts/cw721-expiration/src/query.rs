@@ -1,20 +1,34 @@
-use cosmwasm_std::{to_json_binary, Binary, CustomMsg, Deps, Env, StdResult};
+use cosmwasm_std::{to_json_binary, Binary, CustomMsg, Deps, Env, StdResult, Uint128};
 use cw721::msg::{
-    AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, NftInfoResponse, OwnerOfResponse,
-    TokensResponse,
+    AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, NftInfoResponse, OrderBy,
+    OwnerOfResponse, RoyaltiesInfoResponse, TokensResponse, TokensWithInfoResponse,
 };
 use cw721::query::Cw721Query;
+use cw721::state::RoyaltySource;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::{error::ContractError, msg::QueryMsg, state::Cw721ExpirationContract};
 
-impl<'a, TMetadataExtension, TCustomResponseMessage, TMetadataExtensionMsg>
-    Cw721ExpirationContract<'a, TMetadataExtension, TCustomResponseMessage, TMetadataExtensionMsg>
+impl<
+        'a,
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
+    Cw721ExpirationContract<
+        'a,
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
 where
     TMetadataExtension: Serialize + DeserializeOwned + Clone,
     TCustomResponseMessage: CustomMsg,
     TMetadataExtensionMsg: CustomMsg,
+    TCollectionInfoExtension: Serialize + DeserializeOwned + Clone + Default + RoyaltySource,
 {
     pub fn query(
         &self,
@@ -26,6 +40,7 @@ where
             TMetadataExtension,
             TCustomResponseMessage,
             TMetadataExtensionMsg,
+            TCollectionInfoExtension,
         >::default();
         match msg {
             // -------- msgs with `include_expired_nft` prop --------
@@ -97,7 +112,9 @@ where
             QueryMsg::Tokens {
                 owner,
                 start_after,
+                start_before,
                 limit,
+                order,
                 include_expired_nft: include_invalid,
             } => Ok(to_json_binary(
                 &contract.query_tokens_include_expired_nft(
@@ -105,19 +122,74 @@ where
                     env,
                     owner,
                     start_after,
+                    start_before,
                     limit,
+                    order,
                     include_invalid.unwrap_or(false),
                 )?,
             )?),
             QueryMsg::AllTokens {
                 start_after,
+                start_before,
                 limit,
+                order,
                 include_expired_nft: include_invalid,
             } => Ok(to_json_binary(
                 &contract.query_all_tokens_include_expired_nft(
                     deps,
                     env,
                     start_after,
+                    start_before,
+                    limit,
+                    order,
+                    include_invalid.unwrap_or(false),
+                )?,
+            )?),
+            QueryMsg::TokensWithInfo {
+                owner,
+                start_after,
+                limit,
+                include_approvals,
+                include_expired_nft: include_invalid,
+            } => Ok(to_json_binary(
+                &contract.query_tokens_with_info_include_expired_nft(
+                    deps,
+                    env,
+                    owner,
+                    start_after,
+                    limit,
+                    include_approvals.unwrap_or(false),
+                    include_invalid.unwrap_or(false),
+                )?,
+            )?),
+            QueryMsg::AllTokensWithInfo {
+                start_after,
+                limit,
+                include_approvals,
+                include_expired_nft: include_invalid,
+            } => Ok(to_json_binary(
+                &contract.query_all_tokens_with_info_include_expired_nft(
+                    deps,
+                    env,
+                    start_after,
+                    limit,
+                    include_approvals.unwrap_or(false),
+                    include_invalid.unwrap_or(false),
+                )?,
+            )?),
+            QueryMsg::TokensApprovedFor {
+                spender,
+                include_expired,
+                start_after,
+                limit,
+                include_expired_nft: include_invalid,
+            } => Ok(to_json_binary(
+                &contract.query_tokens_approved_for_include_expired_nft(
+                    deps,
+                    env,
+                    spender,
+                    include_expired.unwrap_or(false),
+                    start_after,
                     limit,
                     include_invalid.unwrap_or(false),
                 )?,
@@ -138,23 +210,47 @@ where
                 owner,
                 include_expired,
                 start_after,
+                start_before,
                 limit,
+                order,
             } => Ok(to_json_binary(&contract.base_contract.query_operators(
                 deps,
                 env,
                 owner,
                 include_expired.unwrap_or(false),
                 start_after,
+                start_before,
                 limit,
+                order,
             )?)?),
+            QueryMsg::ApprovedOwnersForOperator {
+                operator,
+                include_expired,
+                start_after,
+                limit,
+            } => Ok(to_json_binary(
+                &contract.base_contract.query_approved_owners_for_operator(
+                    deps,
+                    env,
+                    operator,
+                    include_expired.unwrap_or(false),
+                    start_after,
+                    limit,
+                )?,
+            )?),
             QueryMsg::NumTokens {} => Ok(to_json_binary(
                 &contract.base_contract.query_num_tokens(deps, env)?,
             )?),
+            QueryMsg::BalanceOf { owner } => Ok(to_json_binary(
+                &contract.base_contract.query_balance_of(deps, env, owner)?,
+            )?),
             QueryMsg::ContractInfo {} => Ok(to_json_binary(
                 &contract.base_contract.query_collection_info(deps, env)?,
             )?),
             QueryMsg::GetCollectionInfo {} => Ok(to_json_binary(
-                &contract.base_contract.query_collection_info(deps, env)?,
+                &contract
+                    .base_contract
+                    .query_collection_info_and_extension(deps, env)?,
             )?),
             QueryMsg::Ownership {} => Ok(to_json_binary(
                 &contract
@@ -166,6 +262,11 @@ where
                     .base_contract
                     .query_minter_ownership(deps.storage)?,
             )?),
+            QueryMsg::GetCreatorOwnership {} => Ok(to_json_binary(
+                &contract
+                    .base_contract
+                    .query_creator_ownership(deps.storage)?,
+            )?),
             QueryMsg::Minter {} => Ok(to_json_binary(
                 &contract.base_contract.query_minter(deps.storage)?,
             )?),
@@ -175,6 +276,75 @@ where
             QueryMsg::GetWithdrawAddress {} => Ok(to_json_binary(
                 &contract.base_contract.query_withdraw_address(deps)?,
             )?),
+            QueryMsg::PauseStatus {} => Ok(to_json_binary(
+                &contract.base_contract.query_pause_status(deps)?,
+            )?),
+            QueryMsg::Supply {} => Ok(to_json_binary(&contract.base_contract.query_supply(deps)?)?),
+            QueryMsg::Hooks { start_after, limit } => Ok(to_json_binary(
+                &contract
+                    .base_contract
+                    .query_hooks(deps, start_after, limit)?,
+            )?),
+            QueryMsg::GetTokenLock { token_id } => Ok(to_json_binary(
+                &contract
+                    .base_contract
+                    .query_token_lock(deps, env, token_id)?,
+            )?),
+            QueryMsg::IsBurned { token_id } => Ok(to_json_binary(
+                &contract.base_contract.query_is_burned(deps, token_id)?,
+            )?),
+            QueryMsg::BurnedTokens { start_after, limit } => Ok(to_json_binary(
+                &contract
+                    .base_contract
+                    .query_burned_tokens(deps, start_after, limit)?,
+            )?),
+            QueryMsg::AllTokenLocks { start_after, limit } => Ok(to_json_binary(
+                &contract
+                    .base_contract
+                    .query_all_token_locks(deps, env, start_after, limit)?,
+            )?),
+            QueryMsg::RoyaltyInfo {
+                token_id,
+                sale_price,
+                include_expired_nft: include_expired,
+            } => Ok(to_json_binary(
+                &contract.query_royalty_info_include_expired_nft(
+                    deps,
+                    env,
+                    token_id,
+                    sale_price,
+                    include_expired.unwrap_or(false),
+                )?,
+            )?),
+            QueryMsg::CheckRoyalties {} => Ok(to_json_binary(
+                &contract.base_contract.query_check_royalties(deps)?,
+            )?),
+            QueryMsg::TokensByTrait {
+                trait_type,
+                value,
+                start_after,
+                limit,
+                include_expired_nft: include_invalid,
+            } => Ok(to_json_binary(
+                &contract.query_tokens_by_trait_include_expired_nft(
+                    deps,
+                    env,
+                    trait_type,
+                    value,
+                    start_after,
+                    limit,
+                    include_invalid.unwrap_or(false),
+                )?,
+            )?),
+            QueryMsg::TraitCounts {
+                trait_type,
+                start_after,
+                limit,
+            } => Ok(to_json_binary(
+                &contract
+                    .base_contract
+                    .query_trait_counts(deps, trait_type, start_after, limit)?,
+            )?),
         }
     }
 
@@ -245,18 +415,27 @@ where
             .query_approvals(deps, env, token_id, include_expired_approval)?)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn query_tokens_include_expired_nft(
         &self,
         deps: Deps,
         env: Env,
         owner: String,
         start_after: Option<String>,
+        start_before: Option<String>,
         limit: Option<u32>,
+        order: Option<OrderBy>,
         include_expired_nft: bool,
     ) -> StdResult<TokensResponse> {
-        let tokens =
-            self.base_contract
-                .query_tokens(deps, env.clone(), owner, start_after, limit)?;
+        let tokens = self.base_contract.query_tokens(
+            deps,
+            env.clone(),
+            owner,
+            start_after,
+            start_before,
+            limit,
+            order,
+        )?;
         if include_expired_nft {
             return Ok(tokens);
         }
@@ -271,17 +450,25 @@ where
         Ok(TokensResponse { tokens: filtered })
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn query_all_tokens_include_expired_nft(
         &self,
         deps: Deps,
         env: Env,
         start_after: Option<String>,
+        start_before: Option<String>,
         limit: Option<u32>,
+        order: Option<OrderBy>,
         include_expired_nft: bool,
     ) -> Result<TokensResponse, ContractError> {
-        let tokens = self
-            .base_contract
-            .query_all_tokens(deps, env.clone(), start_after, limit)?;
+        let tokens = self.base_contract.query_all_tokens(
+            deps,
+            env.clone(),
+            start_after,
+            start_before,
+            limit,
+            order,
+        )?;
         if include_expired_nft {
             return Ok(tokens);
         }
@@ -296,6 +483,129 @@ where
         Ok(TokensResponse { tokens: filtered })
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn query_tokens_with_info_include_expired_nft(
+        &self,
+        deps: Deps,
+        env: Env,
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        include_approvals: bool,
+        include_expired_nft: bool,
+    ) -> StdResult<TokensWithInfoResponse<TMetadataExtension>> {
+        let mut tokens = self.base_contract.query_tokens_with_info(
+            deps,
+            env.clone(),
+            owner,
+            start_after,
+            limit,
+            include_approvals,
+        )?;
+        if !include_expired_nft {
+            tokens.tokens.retain(|token| {
+                self.is_nft_expired(deps, &env, &token.token_id)
+                    .unwrap_or(false)
+            });
+        }
+        Ok(tokens)
+    }
+
+    pub fn query_all_tokens_with_info_include_expired_nft(
+        &self,
+        deps: Deps,
+        env: Env,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        include_approvals: bool,
+        include_expired_nft: bool,
+    ) -> StdResult<TokensWithInfoResponse<TMetadataExtension>> {
+        let mut tokens = self.base_contract.query_all_tokens_with_info(
+            deps,
+            env.clone(),
+            start_after,
+            limit,
+            include_approvals,
+        )?;
+        if !include_expired_nft {
+            tokens.tokens.retain(|token| {
+                self.is_nft_expired(deps, &env, &token.token_id)
+                    .unwrap_or(false)
+            });
+        }
+        Ok(tokens)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn query_tokens_approved_for_include_expired_nft(
+        &self,
+        deps: Deps,
+        env: Env,
+        spender: String,
+        include_expired_approval: bool,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        include_expired_nft: bool,
+    ) -> StdResult<TokensResponse> {
+        let mut tokens = self.base_contract.query_tokens_approved_for(
+            deps,
+            env.clone(),
+            spender,
+            include_expired_approval,
+            start_after,
+            limit,
+        )?;
+        if !include_expired_nft {
+            tokens
+                .tokens
+                .retain(|token_id| self.is_nft_expired(deps, &env, token_id).unwrap_or(false));
+        }
+        Ok(tokens)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn query_tokens_by_trait_include_expired_nft(
+        &self,
+        deps: Deps,
+        env: Env,
+        trait_type: String,
+        value: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        include_expired_nft: bool,
+    ) -> StdResult<TokensResponse> {
+        let mut tokens = self.base_contract.query_tokens_by_trait(
+            deps,
+            env.clone(),
+            trait_type,
+            value,
+            start_after,
+            limit,
+        )?;
+        if !include_expired_nft {
+            tokens
+                .tokens
+                .retain(|token_id| self.is_nft_expired(deps, &env, token_id).unwrap_or(false));
+        }
+        Ok(tokens)
+    }
+
+    pub fn query_royalty_info_include_expired_nft(
+        &self,
+        deps: Deps,
+        env: Env,
+        token_id: String,
+        sale_price: Uint128,
+        include_expired_nft: bool,
+    ) -> Result<RoyaltiesInfoResponse, ContractError> {
+        if !include_expired_nft {
+            self.assert_nft_expired(deps, &env, token_id.as_str())?;
+        }
+        Ok(self
+            .base_contract
+            .query_royalty_info(deps, env, token_id, sale_price)?)
+    }
+
     pub fn query_all_nft_info_include_expired_nft(
         &self,
         deps: Deps,
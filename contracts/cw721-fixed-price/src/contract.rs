@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use crate::error::ContractError;
 use crate::msg::{BalanceOfResponse, ConfigResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{Config, BALANCE, CONFIG, MINTSTATUS, TOTALMINT};
+use crate::state::{Config, SequentialTokenIds, BALANCE, CONFIG, MINTSTATUS, TOTALMINT};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
@@ -12,7 +12,7 @@ use cosmwasm_std::{
 use cw2::set_contract_version;
 use cw721::helpers::Cw721Contract;
 use cw721::msg::{self, Cw721ExecuteMsg, Cw721InstantiateMsg};
-use cw721::state::DefaultOptionMetadataExtension;
+use cw721::state::{DefaultOptionCollectionInfoExtension, DefaultOptionMetadataExtension};
 use cw_utils::must_pay;
 use cw_utils::parse_reply_instantiate_data;
 
@@ -50,6 +50,7 @@ pub fn instantiate(
         token_uri: msg.token_uri.clone(),
         extension: msg.extension.clone(),
         unused_token_id: 0,
+        sequential_token_ids: true,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -57,11 +58,20 @@ pub fn instantiate(
     let sub_msg: Vec<SubMsg> = vec![SubMsg {
         msg: WasmMsg::Instantiate {
             code_id: msg.token_code_id,
-            msg: to_json_binary(&Cw721InstantiateMsg {
+            msg: to_json_binary(&Cw721InstantiateMsg::<
+                msg::DefaultOptionCollectionInfoExtensionMsg,
+            > {
                 name: msg.name.clone(),
                 symbol: msg.symbol,
                 minter: None,
+                creator: None,
                 withdraw_address: msg.withdraw_address,
+                metadata_update_authority: None,
+                transfer_policy: None,
+                sequential_token_ids: Some(SequentialTokenIds::default()),
+                max_supply: None,
+                index_traits: None,
+                collection_info_extension: None,
             })?,
             funds: vec![],
             admin: None,
@@ -234,7 +244,12 @@ pub fn execute_mint(
     TOTALMINT.save(deps.storage, &(&total_mint + 1))?;
 
     let mint_msg = Cw721ExecuteMsg::<DefaultOptionMetadataExtension, Empty>::Mint {
-        token_id: config.unused_token_id.to_string(),
+        // let the collection assign the id, so it never collides with tokens minted elsewhere
+        token_id: if config.sequential_token_ids {
+            None
+        } else {
+            Some(config.unused_token_id.to_string())
+        },
         owner: info.sender.to_string(),
         token_uri: config.token_uri.clone().into(),
         extension: config.extension.clone(),
@@ -242,11 +257,11 @@ pub fn execute_mint(
 
     match config.cw721_address.clone() {
         Some(cw721) => {
-            let callback = Cw721Contract::<DefaultOptionMetadataExtension, Empty>(
-                cw721.clone(),
-                PhantomData,
-                PhantomData,
-            )
+            let callback = Cw721Contract::<
+                DefaultOptionMetadataExtension,
+                Empty,
+                DefaultOptionCollectionInfoExtension,
+            >(cw721.clone(), PhantomData, PhantomData, PhantomData)
             .call(mint_msg)
             .map_err(|_| ContractError::Cw721CallFailed {})?;
             let send_funds_msg = BankMsg::Send {
@@ -258,11 +273,11 @@ pub fn execute_mint(
             };
             config.unused_token_id += 1;
             CONFIG.save(deps.storage, &config)?;
+            // the minted token_id is emitted by the collection (`cw721_mint` event)
             Ok(Response::new()
                 .add_message(callback)
                 .add_message(send_funds_msg)
                 .add_attribute("action", "mint_nft")
-                .add_attribute("token_id", config.unused_token_id.to_string())
                 .add_attribute("amount", amount.to_string())
                 .add_attribute("denom", denom))
         }
@@ -312,11 +327,20 @@ mod tests {
             vec![SubMsg {
                 msg: WasmMsg::Instantiate {
                     code_id: msg.token_code_id,
-                    msg: to_json_binary(&Cw721InstantiateMsg {
+                    msg: to_json_binary(&Cw721InstantiateMsg::<
+                        msg::DefaultOptionCollectionInfoExtensionMsg,
+                    > {
                         name: msg.name.clone(),
                         symbol: msg.symbol.clone(),
                         minter: None,
+                        creator: None,
                         withdraw_address: None,
+                        metadata_update_authority: None,
+                        transfer_policy: None,
+                        sequential_token_ids: Some(SequentialTokenIds::default()),
+                        max_supply: None,
+                        index_traits: None,
+                        collection_info_extension: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -410,7 +434,7 @@ mod tests {
         let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         let mint_msg = Cw721ExecuteMsg::<DefaultOptionMetadataExtension, Empty>::Mint {
-            token_id: String::from("0"),
+            token_id: None,
             owner: MOCK_CONTRACT_ADDR.to_string(),
             token_uri: Some(String::from("https://ipfs.io/ipfs/Q")),
             extension: None,
@@ -646,7 +670,7 @@ mod tests {
         let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         let mint_msg = Cw721ExecuteMsg::<DefaultOptionMetadataExtension, Empty>::Mint {
-            token_id: String::from("0"),
+            token_id: None,
             owner: String::from("unauthorized-token"),
             token_uri: Some(String::from("https://ipfs.io/ipfs/Q")),
             extension: None,
@@ -18,6 +18,9 @@ pub struct Config {
     pub token_uri: String,
     pub extension: DefaultOptionMetadataExtension,
     pub unused_token_id: u32,
+    /// Whether the collection assigns token ids itself, false for collections instantiated before.
+    #[serde(default)]
+    pub sequential_token_ids: bool,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
@@ -0,0 +1,14 @@
+use cw721::error::Cw721ContractError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] cosmwasm_std::StdError),
+
+    #[error(transparent)]
+    Cw721(#[from] Cw721ContractError),
+
+    #[error("Transfer policy must be one of the soulbound modes, not Transferable")]
+    TransferableNotAllowed {},
+}
@@ -1,11 +1,15 @@
 pub use crate::msg::{InstantiateMsg, QueryMsg};
 use cosmwasm_std::Empty;
-use cw721::state::DefaultOptionMetadataExtension;
+use cw721::msg::DefaultOptionMetadataExtensionMsg;
+use cw721::state::{
+    DefaultOptionCollectionInfoExtension, DefaultOptionMetadataExtension, TransferPolicy,
+};
 pub use cw721_base::{
     entry::{execute as _execute, query as _query},
     Cw721Contract,
 };
 
+pub mod error;
 pub mod msg;
 pub mod query;
 pub mod state;
@@ -14,19 +18,24 @@ pub mod state;
 const CONTRACT_NAME: &str = "crates.io:cw721-non-transferable";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
-pub type Cw721NonTransferableContract<'a> =
-    Cw721Contract<'a, DefaultOptionMetadataExtension, Empty, Empty>;
+pub type Cw721NonTransferableContract<'a> = Cw721Contract<
+    'a,
+    DefaultOptionMetadataExtension,
+    Empty,
+    DefaultOptionMetadataExtensionMsg,
+    DefaultOptionCollectionInfoExtension,
+>;
 
 #[cfg(not(feature = "library"))]
 pub mod entry {
     use super::*;
+    use crate::error::ContractError;
     use crate::query::admin;
     use crate::state::{Config, CONFIG};
     use cosmwasm_std::{
-        entry_point, to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response,
-        StdResult,
+        entry_point, to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply,
+        Response, StdResult,
     };
-    use cw721::error::Cw721ContractError;
     use cw721::execute::Cw721Execute;
     use cw721::msg::{Cw721ExecuteMsg, Cw721InstantiateMsg};
 
@@ -36,7 +45,13 @@ pub mod entry {
         env: Env,
         info: MessageInfo,
         msg: InstantiateMsg,
-    ) -> Result<Response, Cw721ContractError> {
+    ) -> Result<Response, ContractError> {
+        let transfer_policy = msg
+            .transfer_policy
+            .unwrap_or(TransferPolicy::NonTransferable);
+        if transfer_policy == TransferPolicy::Transferable {
+            return Err(ContractError::TransferableNotAllowed {});
+        }
         let admin_addr: Option<Addr> = msg
             .admin
             .as_deref()
@@ -51,7 +66,14 @@ pub mod entry {
             name: msg.name,
             symbol: msg.symbol,
             minter: msg.minter,
+            creator: msg.creator,
             withdraw_address: msg.withdraw_address,
+            metadata_update_authority: msg.metadata_update_authority,
+            transfer_policy: Some(transfer_policy),
+            sequential_token_ids: msg.sequential_token_ids,
+            max_supply: msg.max_supply,
+            index_traits: msg.index_traits,
+            collection_info_extension: msg.collection_info_extension,
         };
 
         Cw721NonTransferableContract::default().instantiate(
@@ -75,32 +97,16 @@ pub mod entry {
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
-        msg: Cw721ExecuteMsg<DefaultOptionMetadataExtension, Empty>,
-    ) -> Result<Response, Cw721ContractError> {
-        let config = CONFIG.load(deps.storage)?;
-        match config.admin {
-            Some(admin) => {
-                if admin == info.sender {
-                    _execute(deps, env, info, msg)
-                } else {
-                    Err(Cw721ContractError::Ownership(
-                        cw721_base::OwnershipError::NotOwner,
-                    ))
-                }
-            }
-            None => match msg {
-                Cw721ExecuteMsg::Mint {
-                    token_id,
-                    owner,
-                    token_uri,
-                    extension,
-                } => Cw721NonTransferableContract::default()
-                    .mint(deps, info, token_id, owner, token_uri, extension),
-                _ => Err(Cw721ContractError::Ownership(
-                    cw721_base::OwnershipError::NotOwner,
-                )),
-            },
-        }
+        msg: Cw721ExecuteMsg<DefaultOptionMetadataExtension, DefaultOptionMetadataExtensionMsg>,
+    ) -> Result<Response, ContractError> {
+        // transfers are restricted by the transfer policy set in `instantiate`,
+        // so approvals and burns work as in cw721-base
+        Ok(_execute(deps, env, info, msg)?)
+    }
+
+    #[entry_point]
+    pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+        Ok(Cw721NonTransferableContract::default().reply(deps, env, msg)?)
     }
 
     #[entry_point]
@@ -1,19 +1,35 @@
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
 // expose to all others using contract, so others dont need to import cw721
 pub use cw721::msg::{Cw721ExecuteMsg as ExecuteMsg, Cw721MigrateMsg as MigrateMsg, *};
-use cw721::state::DefaultOptionMetadataExtension;
+use cw721::state::{
+    DefaultOptionCollectionInfoExtension, DefaultOptionMetadataExtension, MetadataUpdateAuthority,
+    SequentialTokenIds, TransferPolicy,
+};
 
 #[cw_serde]
 pub struct InstantiateMsg {
+    /// Deprecated: not used for any permission checks, transfers are restricted by `transfer_policy`.
+    /// Kept for backwards compatibility, only returned by `QueryMsg::Admin`.
     pub admin: Option<String>,
     pub name: String,
     pub symbol: String,
     pub minter: Option<String>,
+    pub creator: Option<String>,
     pub withdraw_address: Option<String>,
+    pub metadata_update_authority: Option<MetadataUpdateAuthority>,
+    /// Soulbound mode, defaults to `TransferPolicy::NonTransferable`.
+    /// `TransferPolicy::Transferable` is rejected.
+    pub transfer_policy: Option<TransferPolicy>,
+    pub sequential_token_ids: Option<SequentialTokenIds>,
+    pub max_supply: Option<u64>,
+    pub index_traits: Option<bool>,
+    pub collection_info_extension: DefaultOptionCollectionInfoExtensionMsg,
 }
 
 #[cw_serde]
 pub enum QueryMsg {
+    /// Deprecated: the admin has no permissions, use `GetCreatorOwnership` instead.
     Admin {},
 
     // -- below copied from Cw721QueryMsg
@@ -34,10 +50,28 @@ pub enum QueryMsg {
         owner: String,
         include_expired: Option<bool>,
         start_after: Option<String>,
+        start_before: Option<String>,
+        limit: Option<u32>,
+        order: Option<OrderBy>,
+    },
+    TokensApprovedFor {
+        spender: String,
+        include_expired: Option<bool>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    ApprovedOwnersForOperator {
+        operator: String,
+        include_expired: Option<bool>,
+        start_after: Option<String>,
         limit: Option<u32>,
     },
     NumTokens {},
+    BalanceOf {
+        owner: String,
+    },
     ContractInfo {},
+    GetCollectionInfo {},
 
     NftInfo {
         token_id: String,
@@ -49,19 +83,78 @@ pub enum QueryMsg {
     Tokens {
         owner: String,
         start_after: Option<String>,
+        start_before: Option<String>,
         limit: Option<u32>,
+        order: Option<OrderBy>,
     },
     AllTokens {
         start_after: Option<String>,
+        start_before: Option<String>,
         limit: Option<u32>,
+        order: Option<OrderBy>,
+    },
+    TokensWithInfo {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        include_approvals: Option<bool>,
+    },
+    AllTokensWithInfo {
+        start_after: Option<String>,
+        limit: Option<u32>,
+        include_approvals: Option<bool>,
     },
     Minter {},
+    GetMinterOwnership {},
+    GetCreatorOwnership {},
 
     GetWithdrawAddress {},
+
+    PauseStatus {},
+    Supply {},
+    Hooks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    GetTokenLock {
+        token_id: String,
+    },
+    IsBurned {
+        token_id: String,
+    },
+    BurnedTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    AllTokenLocks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    RoyaltyInfo {
+        token_id: String,
+        sale_price: Uint128,
+    },
+    CheckRoyalties {},
+    TokensByTrait {
+        trait_type: String,
+        value: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    TraitCounts {
+        trait_type: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
-impl From<QueryMsg> for Cw721QueryMsg<DefaultOptionMetadataExtension> {
-    fn from(msg: QueryMsg) -> Cw721QueryMsg<DefaultOptionMetadataExtension> {
+impl From<QueryMsg>
+    for Cw721QueryMsg<DefaultOptionMetadataExtension, DefaultOptionCollectionInfoExtension>
+{
+    fn from(
+        msg: QueryMsg,
+    ) -> Cw721QueryMsg<DefaultOptionMetadataExtension, DefaultOptionCollectionInfoExtension> {
         match msg {
             QueryMsg::OwnerOf {
                 token_id,
@@ -71,7 +164,9 @@ impl From<QueryMsg> for Cw721QueryMsg<DefaultOptionMetadataExtension> {
                 include_expired,
             },
             QueryMsg::NumTokens {} => Cw721QueryMsg::NumTokens {},
+            QueryMsg::BalanceOf { owner } => Cw721QueryMsg::BalanceOf { owner },
             QueryMsg::ContractInfo {} => Cw721QueryMsg::ContractInfo {},
+            QueryMsg::GetCollectionInfo {} => Cw721QueryMsg::GetCollectionInfo {},
             QueryMsg::NftInfo { token_id } => Cw721QueryMsg::NftInfo { token_id },
             QueryMsg::AllNftInfo {
                 token_id,
@@ -83,21 +178,145 @@ impl From<QueryMsg> for Cw721QueryMsg<DefaultOptionMetadataExtension> {
             QueryMsg::Tokens {
                 owner,
                 start_after,
+                start_before,
                 limit,
+                order,
             } => Cw721QueryMsg::Tokens {
                 owner,
                 start_after,
+                start_before,
                 limit,
+                order,
+            },
+            QueryMsg::AllTokens {
+                start_after,
+                start_before,
+                limit,
+                order,
+            } => Cw721QueryMsg::AllTokens {
+                start_after,
+                start_before,
+                limit,
+                order,
+            },
+            QueryMsg::TokensWithInfo {
+                owner,
+                start_after,
+                limit,
+                include_approvals,
+            } => Cw721QueryMsg::TokensWithInfo {
+                owner,
+                start_after,
+                limit,
+                include_approvals,
+            },
+            QueryMsg::AllTokensWithInfo {
+                start_after,
+                limit,
+                include_approvals,
+            } => Cw721QueryMsg::AllTokensWithInfo {
+                start_after,
+                limit,
+                include_approvals,
             },
-            QueryMsg::AllTokens { start_after, limit } => {
-                Cw721QueryMsg::AllTokens { start_after, limit }
-            }
             QueryMsg::Minter {} => Cw721QueryMsg::Minter {},
+            QueryMsg::GetMinterOwnership {} => Cw721QueryMsg::GetMinterOwnership {},
+            QueryMsg::GetCreatorOwnership {} => Cw721QueryMsg::GetCreatorOwnership {},
             QueryMsg::GetWithdrawAddress {} => Cw721QueryMsg::GetWithdrawAddress {},
-            QueryMsg::AllOperators { .. } => unreachable!("AllOperators is not supported!"),
-            QueryMsg::Approval { .. } => unreachable!("Approval is not supported!"),
-            QueryMsg::Approvals { .. } => unreachable!("Approvals is not supported!"),
-            QueryMsg::Admin { .. } => unreachable!("Approvals is not supported!"),
+            QueryMsg::PauseStatus {} => Cw721QueryMsg::PauseStatus {},
+            QueryMsg::Supply {} => Cw721QueryMsg::Supply {},
+            QueryMsg::Hooks { start_after, limit } => Cw721QueryMsg::Hooks { start_after, limit },
+            QueryMsg::GetTokenLock { token_id } => Cw721QueryMsg::GetTokenLock { token_id },
+            QueryMsg::IsBurned { token_id } => Cw721QueryMsg::IsBurned { token_id },
+            QueryMsg::BurnedTokens { start_after, limit } => {
+                Cw721QueryMsg::BurnedTokens { start_after, limit }
+            }
+            QueryMsg::AllTokenLocks { start_after, limit } => {
+                Cw721QueryMsg::AllTokenLocks { start_after, limit }
+            }
+            QueryMsg::RoyaltyInfo {
+                token_id,
+                sale_price,
+            } => Cw721QueryMsg::RoyaltyInfo {
+                token_id,
+                sale_price,
+            },
+            QueryMsg::CheckRoyalties {} => Cw721QueryMsg::CheckRoyalties {},
+            QueryMsg::TokensByTrait {
+                trait_type,
+                value,
+                start_after,
+                limit,
+            } => Cw721QueryMsg::TokensByTrait {
+                trait_type,
+                value,
+                start_after,
+                limit,
+            },
+            QueryMsg::TraitCounts {
+                trait_type,
+                start_after,
+                limit,
+            } => Cw721QueryMsg::TraitCounts {
+                trait_type,
+                start_after,
+                limit,
+            },
+            QueryMsg::AllOperators {
+                owner,
+                include_expired,
+                start_after,
+                start_before,
+                limit,
+                order,
+            } => Cw721QueryMsg::AllOperators {
+                owner,
+                include_expired,
+                start_after,
+                start_before,
+                limit,
+                order,
+            },
+            QueryMsg::TokensApprovedFor {
+                spender,
+                include_expired,
+                start_after,
+                limit,
+            } => Cw721QueryMsg::TokensApprovedFor {
+                spender,
+                include_expired,
+                start_after,
+                limit,
+            },
+            QueryMsg::ApprovedOwnersForOperator {
+                operator,
+                include_expired,
+                start_after,
+                limit,
+            } => Cw721QueryMsg::ApprovedOwnersForOperator {
+                operator,
+                include_expired,
+                start_after,
+                limit,
+            },
+            QueryMsg::Approval {
+                token_id,
+                spender,
+                include_expired,
+            } => Cw721QueryMsg::Approval {
+                token_id,
+                spender,
+                include_expired,
+            },
+            QueryMsg::Approvals {
+                token_id,
+                include_expired,
+            } => Cw721QueryMsg::Approvals {
+                token_id,
+                include_expired,
+            },
+            // handled by the contract's query entry point
+            QueryMsg::Admin {} => unreachable!("Admin is not a cw721 query!"),
         }
     }
 }
@@ -7,6 +7,7 @@ pub use cw721::state::*;
 
 #[cw_serde]
 pub struct Config {
+    /// Deprecated: not used for any permission checks, only returned by `QueryMsg::Admin`.
     pub admin: Option<Addr>,
 }
 
@@ -1,6 +1,6 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{from_json, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{from_json, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InnerMsg, InstantiateMsg, QueryMsg};
@@ -19,7 +19,7 @@ pub fn instantiate(
 pub fn execute(
     _deps: DepsMut,
     _env: Env,
-    _info: MessageInfo,
+    info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
@@ -45,9 +45,31 @@ pub fn execute(
                 InnerMsg::Fail => Err(ContractError::Failed {}),
             }
         }
+        ExecuteMsg::ReceiveNftV2(receive_msg) => {
+            let inner: InnerMsg = from_json(&receive_msg.msg)?;
+            match inner {
+                InnerMsg::Succeed => Ok(Response::new().add_attributes([
+                    ("action", "receive_nft_v2"),
+                    ("token_id", receive_msg.token_id.as_str()),
+                    ("sender", receive_msg.sender.as_str()),
+                    ("owner", receive_msg.owner.as_str()),
+                    ("collection", receive_msg.collection.address.as_str()),
+                    ("funds", coins_to_string(&info.funds).as_str()),
+                ])),
+                InnerMsg::Fail => Err(ContractError::Failed {}),
+            }
+        }
     }
 }
 
+fn coins_to_string(coins: &[Coin]) -> String {
+    coins
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(_deps: Deps, _env: Env, _msg: QueryMsg) -> StdResult<Binary> {
     unimplemented!()
@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cw721::receiver::Cw721ReceiveMsg;
+use cw721::receiver::{Cw721ReceiveMsg, Cw721ReceiveMsgV2};
 
 #[cw_serde]
 pub struct InstantiateMsg {}
@@ -7,6 +7,7 @@ pub struct InstantiateMsg {}
 #[cw_serde]
 pub enum ExecuteMsg {
     ReceiveNft(Cw721ReceiveMsg),
+    ReceiveNftV2(Cw721ReceiveMsgV2),
 }
 
 #[cw_serde]
@@ -1,4 +1,5 @@
-use cosmwasm_std::{to_json_binary, Addr, Attribute, Binary};
+use cosmwasm_std::testing::MockApi;
+use cosmwasm_std::{coins, to_json_binary, Addr, Attribute, Binary};
 use cw_multi_test::{App, ContractWrapper, Executor};
 
 #[test]
@@ -23,6 +24,7 @@ fn test_cw721_base_receive_succeed() {
                 contract: receiver_contract.to_string(),
                 token_id: "test".to_string(),
                 msg: to_json_binary(&InnerMsg::Succeed).unwrap(),
+                receive_msg_version: None,
             },
             &[],
         )
@@ -58,6 +60,73 @@ fn test_cw721_base_receive_succeed() {
     );
 }
 
+#[test]
+fn test_cw721_base_receive_v2_forwards_funds() {
+    use cw721::receiver::ReceiveMsgVersion;
+    use cw721_base::msg::*;
+    use cw721_receiver_tester::msg::InnerMsg;
+
+    let admin = MockApi::default().addr_make("admin");
+    let mut app = App::new(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &admin, coins(100, "uark"))
+            .unwrap();
+    });
+
+    let Contracts {
+        nft_contract,
+        receiver_contract,
+    } = setup_contracts(&mut app, admin.clone());
+
+    // send token with funds to receiver contract
+    let response = app
+        .execute_contract(
+            admin.clone(),
+            nft_contract.clone(),
+            &ExecuteMsg::<(), ()>::SendNft {
+                contract: receiver_contract.to_string(),
+                token_id: "test".to_string(),
+                msg: to_json_binary(&InnerMsg::Succeed).unwrap(),
+                receive_msg_version: Some(ReceiveMsgVersion::V2),
+            },
+            &coins(100, "uark"),
+        )
+        .unwrap();
+
+    let receive_event = response
+        .events
+        .iter()
+        .filter(|e| e.ty == "wasm")
+        .find(|e| get_attribute(&e.attributes, "action") == Some("receive_nft_v2"))
+        .unwrap();
+    assert_eq!(
+        get_attribute(&receive_event.attributes, "sender"),
+        Some(admin.as_str())
+    );
+    assert_eq!(
+        get_attribute(&receive_event.attributes, "owner"),
+        Some(admin.as_str())
+    );
+    assert_eq!(
+        get_attribute(&receive_event.attributes, "collection"),
+        Some(nft_contract.as_str())
+    );
+    assert_eq!(
+        get_attribute(&receive_event.attributes, "funds"),
+        Some("100uark")
+    );
+
+    // funds ended up at the receiver
+    let balance = app
+        .wrap()
+        .query_balance(&receiver_contract, "uark")
+        .unwrap();
+    assert_eq!(balance.amount.u128(), 100);
+    let balance = app.wrap().query_balance(&nft_contract, "uark").unwrap();
+    assert_eq!(balance.amount.u128(), 0);
+}
+
 #[test]
 fn test_cw721_base_receive_fail() {
     use cw721_base::msg::*;
@@ -79,6 +148,7 @@ fn test_cw721_base_receive_fail() {
             contract: receiver_contract.to_string(),
             token_id: "test".to_string(),
             msg: to_json_binary(&InnerMsg::Fail).unwrap(),
+            receive_msg_version: None,
         },
         &[],
     );
@@ -92,12 +162,135 @@ fn test_cw721_base_receive_fail() {
             contract: receiver_contract.to_string(),
             token_id: "test".to_string(),
             msg: Binary::from(br#"{"invalid": "fields"}"#),
+            receive_msg_version: None,
         },
         &[],
     );
     assert!(result.is_err());
 }
 
+#[test]
+fn test_cw721_base_safe_transfer() {
+    use cw721_base::msg::*;
+    use cw721_receiver_tester::msg::InnerMsg;
+
+    let mut app = App::default();
+    let admin = app.api().addr_make("admin");
+
+    let Contracts {
+        nft_contract,
+        receiver_contract,
+    } = setup_contracts(&mut app, admin.clone());
+
+    // receiver failing reverts the transfer
+    let result = app.execute_contract(
+        admin.clone(),
+        nft_contract.clone(),
+        &ExecuteMsg::<(), ()>::SafeTransferNft {
+            recipient: receiver_contract.to_string(),
+            token_id: "test".to_string(),
+            msg: Some(to_json_binary(&InnerMsg::Fail).unwrap()),
+            receive_msg_version: None,
+        },
+        &[],
+    );
+    assert!(result.is_err());
+    let owner: OwnerOfResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &nft_contract,
+            &QueryMsg::<(), ()>::OwnerOf {
+                token_id: "test".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(owner.owner, admin.to_string());
+
+    // receiver acknowledging keeps the transfer
+    let response = app
+        .execute_contract(
+            admin.clone(),
+            nft_contract.clone(),
+            &ExecuteMsg::<(), ()>::SafeTransferNft {
+                recipient: receiver_contract.to_string(),
+                token_id: "test".to_string(),
+                msg: Some(to_json_binary(&InnerMsg::Succeed).unwrap()),
+                receive_msg_version: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let receive_event = response
+        .events
+        .iter()
+        .filter(|e| e.ty == "wasm")
+        .find(|e| get_attribute(&e.attributes, "action") == Some("receive_nft"))
+        .unwrap();
+    assert_eq!(
+        get_attribute(&receive_event.attributes, "token_id"),
+        Some("test")
+    );
+    let owner: OwnerOfResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &nft_contract,
+            &QueryMsg::<(), ()>::OwnerOf {
+                token_id: "test".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(owner.owner, receiver_contract.to_string());
+}
+
+#[test]
+fn test_cw721_base_safe_transfer_to_account() {
+    use cw721_base::msg::*;
+
+    let mut app = App::default();
+    let admin = app.api().addr_make("admin");
+    let recipient = app.api().addr_make("recipient");
+
+    let Contracts { nft_contract, .. } = setup_contracts(&mut app, admin.clone());
+
+    // accounts get a plain transfer
+    let response = app
+        .execute_contract(
+            admin.clone(),
+            nft_contract.clone(),
+            &ExecuteMsg::<(), ()>::SafeTransferNft {
+                recipient: recipient.to_string(),
+                token_id: "test".to_string(),
+                msg: None,
+                receive_msg_version: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let transfer_event = response
+        .events
+        .iter()
+        .filter(|e| e.ty == "wasm")
+        .find(|e| get_attribute(&e.attributes, "action") == Some("safe_transfer_nft"))
+        .unwrap();
+    assert_eq!(
+        get_attribute(&transfer_event.attributes, "receiver_notified"),
+        Some("false")
+    );
+    let owner: OwnerOfResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &nft_contract,
+            &QueryMsg::<(), ()>::OwnerOf {
+                token_id: "test".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(owner.owner, recipient.to_string());
+}
+
 struct Contracts {
     nft_contract: Addr,
     receiver_contract: Addr,
@@ -122,11 +315,18 @@ fn setup_contracts(app: &mut App, admin: Addr) -> Contracts {
         .instantiate_contract(
             nft_code_id,
             admin.clone(),
-            &base_msg::InstantiateMsg {
+            &base_msg::InstantiateMsg::<base_msg::DefaultOptionCollectionInfoExtensionMsg> {
                 name: "nft".to_string(),
                 symbol: "NFT".to_string(),
                 minter: Some(admin.to_string()),
+                creator: None,
                 withdraw_address: None,
+                metadata_update_authority: None,
+                transfer_policy: None,
+                sequential_token_ids: None,
+                max_supply: None,
+                index_traits: None,
+                collection_info_extension: None,
             },
             &[],
             "nft".to_string(),
@@ -150,7 +350,7 @@ fn setup_contracts(app: &mut App, admin: Addr) -> Contracts {
         admin.clone(),
         nft_contract.clone(),
         &base_msg::ExecuteMsg::<(), ()>::Mint {
-            token_id: "test".to_string(),
+            token_id: Some("test".to_string()),
             owner: admin.to_string(),
             token_uri: Some("https://example.com".to_string()),
             extension: (),
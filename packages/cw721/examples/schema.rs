@@ -6,13 +6,15 @@ use cosmwasm_schema::{export_schema, export_schema_with_title, remove_schemas, s
 use cosmwasm_std::Empty;
 use cw721::{
     msg::{
-        AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, CollectionInfoMsg,
-        Cw721ExecuteMsg, Cw721InstantiateMsg, Cw721MigrateMsg, Cw721QueryMsg, MinterResponse,
+        AllNftInfoResponse, ApprovalResponse, ApprovalsResponse,
+        CollectionInfoAndExtensionResponse, CollectionInfoMsg, Cw721ExecuteMsg,
+        Cw721InstantiateMsg, Cw721MigrateMsg, Cw721QueryMsg,
+        DefaultOptionCollectionInfoExtensionMsg, DefaultOptionMetadataExtensionMsg, MinterResponse,
         NftInfoResponse, NumTokensResponse, OperatorResponse, OperatorsResponse, OwnerOfResponse,
         TokensResponse,
     },
-    receiver::Cw721ReceiveMsg,
-    state::{CollectionInfo, DefaultOptionMetadataExtension},
+    receiver::{Cw721ReceiveMsg, Cw721ReceiveMsgV2},
+    state::{CollectionInfo, DefaultOptionCollectionInfoExtension, DefaultOptionMetadataExtension},
 };
 fn main() {
     let mut out_dir = current_dir().unwrap();
@@ -22,17 +24,19 @@ fn main() {
 
     // entry points - generate always with title for avoiding name suffixes like "..._empty_for_..." due to generics
     export_schema_with_title(
-        &schema_for!(Cw721InstantiateMsg),
+        &schema_for!(Cw721InstantiateMsg<DefaultOptionCollectionInfoExtensionMsg>),
         &out_dir,
         "Cw721InstantiateMsg",
     );
     export_schema_with_title(
-        &schema_for!(Cw721ExecuteMsg::<DefaultOptionMetadataExtension, Empty>),
+        &schema_for!(
+            Cw721ExecuteMsg::<DefaultOptionMetadataExtension, DefaultOptionMetadataExtensionMsg>
+        ),
         &out_dir,
         "Cw721ExecuteMsg",
     );
     export_schema_with_title(
-        &schema_for!(Cw721QueryMsg<Empty>),
+        &schema_for!(Cw721QueryMsg<Empty, Empty>),
         &out_dir,
         "Cw721QueryMsg",
     );
@@ -40,6 +44,11 @@ fn main() {
 
     // messages
     export_schema_with_title(&schema_for!(Cw721ReceiveMsg), &out_dir, "Cw721ReceiveMsg");
+    export_schema_with_title(
+        &schema_for!(Cw721ReceiveMsgV2),
+        &out_dir,
+        "Cw721ReceiveMsgV2",
+    );
     export_schema(&schema_for!(CollectionInfoMsg), &out_dir);
 
     // responses
@@ -58,6 +67,11 @@ fn main() {
     export_schema(&schema_for!(OperatorResponse), &out_dir);
     export_schema(&schema_for!(OperatorsResponse), &out_dir);
     export_schema_with_title(&schema_for!(CollectionInfo), &out_dir, "CollectionInfo");
+    export_schema_with_title(
+        &schema_for!(CollectionInfoAndExtensionResponse<DefaultOptionCollectionInfoExtension>),
+        &out_dir,
+        "CollectionInfoAndExtensionResponse",
+    );
     export_schema(&schema_for!(OwnerOfResponse), &out_dir);
     export_schema(&schema_for!(MinterResponse), &out_dir);
     export_schema(&schema_for!(NumTokensResponse), &out_dir);
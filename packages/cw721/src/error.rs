@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Decimal, StdError, Timestamp};
 use cw_ownable::OwnershipError;
 use thiserror::Error;
 
@@ -24,4 +24,76 @@ pub enum Cw721ContractError {
 
     #[error("No withdraw address set")]
     NoWithdrawAddress {},
+
+    #[error("Batch must contain at least one token")]
+    EmptyBatch {},
+
+    #[error("Duplicate token_id in batch: {token_id}")]
+    DuplicateTokenId { token_id: String },
+
+    #[error("Collection name must not be empty")]
+    CollectionNameEmpty {},
+
+    #[error("Collection symbol must not be empty")]
+    CollectionSymbolEmpty {},
+
+    #[error("Collection info is frozen")]
+    CollectionInfoFrozen {},
+
+    #[error("Token is locked: {token_id}")]
+    TokenLocked { token_id: String },
+
+    #[error("Token is not locked: {token_id}")]
+    TokenNotLocked { token_id: String },
+
+    #[error("Minting is paused")]
+    MintingPaused {},
+
+    #[error("Transfers are paused")]
+    TransfersPaused {},
+
+    #[error("Burns are paused")]
+    BurnsPaused {},
+
+    #[error("Tokens are non-transferable")]
+    NonTransferable {},
+
+    #[error("Tokens are not transferable before {timestamp}")]
+    TransferableAfter { timestamp: Timestamp },
+
+    #[error("Hook already registered: {addr}")]
+    HookAlreadyRegistered { addr: String },
+
+    #[error("Hook not registered: {addr}")]
+    HookNotRegistered { addr: String },
+
+    #[error("token_id is required, sequential token ids are not enabled")]
+    TokenIdRequired {},
+
+    #[error("Max supply of {max_supply} tokens reached")]
+    MaxSupplyReached { max_supply: u64 },
+
+    #[error("Max supply can only be lowered, current max supply: {max_supply}")]
+    MaxSupplyRaised { max_supply: u64 },
+
+    #[error("Max supply can't be lower than the {total_minted} already minted tokens")]
+    MaxSupplyBelowMinted { total_minted: u64 },
+
+    #[error("Only the owner can lock a token without expiration")]
+    LockExpirationRequired {},
+
+    #[error("Total minted can't be lower than the {num_tokens} existing tokens")]
+    TotalMintedBelowTokens { num_tokens: u64 },
+
+    #[error("token_id has been burned and can't be minted again: {token_id}")]
+    TokenBurned { token_id: String },
+
+    #[error("Royalty share must not exceed 100%: {share}")]
+    InvalidRoyaltyShare { share: Decimal },
+
+    #[error("Unknown reply id: {id}")]
+    UnknownReplyId { id: u64 },
+
+    #[error("Funds are only forwarded to contracts receiving ReceiveNftV2")]
+    FundsNotForwarded {},
 }
@@ -0,0 +1,412 @@
+use cosmwasm_std::{Coin, Decimal, Event, StdResult, Storage};
+use cw2::ContractVersion;
+use cw_utils::Expiration;
+
+/// Prefix of all event types, emitted by wasmd as e.g. `wasm-cw721_transfer`.
+pub const EVENT_TYPE_PREFIX: &str = "cw721_";
+
+/// Structured events emitted by all cw721 actions, so indexers can rely on a stable schema.
+/// Besides the attributes below, each event carries `contract_name` and `contract_version` (cw2).
+/// Optional values (e.g. `token_uri`) are always present, empty if not set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Cw721Event {
+    /// `wasm-cw721_mint`: minter, owner, token_id, token_uri
+    Mint {
+        minter: String,
+        owner: String,
+        token_id: String,
+        token_uri: Option<String>,
+    },
+    /// `wasm-cw721_transfer`: sender, from (previous owner), to, token_id
+    Transfer {
+        sender: String,
+        from: String,
+        to: String,
+        token_id: String,
+    },
+    /// `wasm-cw721_send`: sender, from (previous owner), to (receiving contract), token_id
+    Send {
+        sender: String,
+        from: String,
+        to: String,
+        token_id: String,
+    },
+    /// `wasm-cw721_burn`: sender, owner, token_id
+    Burn {
+        sender: String,
+        owner: String,
+        token_id: String,
+    },
+    /// `wasm-cw721_approve`: sender, owner, spender, token_id, expires
+    Approve {
+        sender: String,
+        owner: String,
+        spender: String,
+        token_id: String,
+        expires: Expiration,
+    },
+    /// `wasm-cw721_revoke`: sender, owner, spender, token_id
+    Revoke {
+        sender: String,
+        owner: String,
+        spender: String,
+        token_id: String,
+    },
+    /// `wasm-cw721_approve_all`: owner, operator, expires
+    ApproveAll {
+        owner: String,
+        operator: String,
+        expires: Expiration,
+    },
+    /// `wasm-cw721_revoke_all`: owner, operator
+    RevokeAll { owner: String, operator: String },
+    /// `wasm-cw721_update_metadata`: sender, token_id, token_uri
+    UpdateMetadata {
+        sender: String,
+        token_id: String,
+        token_uri: Option<String>,
+    },
+    /// `wasm-cw721_lock`: sender (locker), token_id, expires
+    Lock {
+        sender: String,
+        token_id: String,
+        expires: Expiration,
+    },
+    /// `wasm-cw721_unlock`: sender, token_id
+    Unlock { sender: String, token_id: String },
+    /// `wasm-cw721_prune_expired`: sender, pruned_approvals, pruned_operators
+    PruneExpired {
+        sender: String,
+        pruned_approvals: u64,
+        pruned_operators: u64,
+    },
+    /// `wasm-cw721_update_collection_info`: sender, name, symbol, frozen
+    UpdateCollectionInfo {
+        sender: String,
+        name: String,
+        symbol: String,
+        frozen: bool,
+    },
+    /// `wasm-cw721_update_pause_status`: sender, minting, transfers, burns
+    UpdatePauseStatus {
+        sender: String,
+        minting: bool,
+        transfers: bool,
+        burns: bool,
+    },
+    /// `wasm-cw721_update_max_supply`: sender, max_supply
+    UpdateMaxSupply { sender: String, max_supply: u64 },
+    /// `wasm-cw721_set_collection_royalty`: sender, payment_address, share
+    SetCollectionRoyalty {
+        sender: String,
+        payment_address: Option<String>,
+        share: Option<Decimal>,
+    },
+    /// `wasm-cw721_set_token_royalty`: sender, token_id, payment_address, share
+    SetTokenRoyalty {
+        sender: String,
+        token_id: String,
+        payment_address: Option<String>,
+        share: Option<Decimal>,
+    },
+    /// `wasm-cw721_add_hook`: sender, hook, revert_on_error
+    AddHook {
+        sender: String,
+        hook: String,
+        revert_on_error: bool,
+    },
+    /// `wasm-cw721_remove_hook`: sender, hook
+    RemoveHook { sender: String, hook: String },
+    /// `wasm-cw721_update_minter_ownership`: sender, owner, pending_owner, pending_expiry
+    UpdateMinterOwnership {
+        sender: String,
+        owner: Option<String>,
+        pending_owner: Option<String>,
+        pending_expiry: Option<Expiration>,
+    },
+    /// `wasm-cw721_update_creator_ownership`: sender, owner, pending_owner, pending_expiry
+    UpdateCreatorOwnership {
+        sender: String,
+        owner: Option<String>,
+        pending_owner: Option<String>,
+        pending_expiry: Option<Expiration>,
+    },
+    /// `wasm-cw721_set_withdraw_address`: sender, address
+    SetWithdrawAddress { sender: String, address: String },
+    /// `wasm-cw721_remove_withdraw_address`: sender, address
+    RemoveWithdrawAddress { sender: String, address: String },
+    /// `wasm-cw721_withdraw_funds`: address (recipient), amount, denom
+    WithdrawFunds { address: String, amount: Coin },
+    /// `wasm-cw721_migrate_indexes`: sender, migrated_operator_grants, migrated_token_approvals,
+    /// migrated_balances, pending
+    MigrateIndexes {
+        sender: String,
+        migrated_operator_grants: u32,
+        migrated_token_approvals: u32,
+        migrated_balances: u32,
+        pending: bool,
+    },
+}
+
+impl Cw721Event {
+    pub fn action(&self) -> &'static str {
+        match self {
+            Cw721Event::Mint { .. } => "mint",
+            Cw721Event::Transfer { .. } => "transfer",
+            Cw721Event::Send { .. } => "send",
+            Cw721Event::Burn { .. } => "burn",
+            Cw721Event::Approve { .. } => "approve",
+            Cw721Event::Revoke { .. } => "revoke",
+            Cw721Event::ApproveAll { .. } => "approve_all",
+            Cw721Event::RevokeAll { .. } => "revoke_all",
+            Cw721Event::UpdateMetadata { .. } => "update_metadata",
+            Cw721Event::Lock { .. } => "lock",
+            Cw721Event::Unlock { .. } => "unlock",
+            Cw721Event::PruneExpired { .. } => "prune_expired",
+            Cw721Event::UpdateCollectionInfo { .. } => "update_collection_info",
+            Cw721Event::UpdatePauseStatus { .. } => "update_pause_status",
+            Cw721Event::UpdateMaxSupply { .. } => "update_max_supply",
+            Cw721Event::SetCollectionRoyalty { .. } => "set_collection_royalty",
+            Cw721Event::SetTokenRoyalty { .. } => "set_token_royalty",
+            Cw721Event::AddHook { .. } => "add_hook",
+            Cw721Event::RemoveHook { .. } => "remove_hook",
+            Cw721Event::UpdateMinterOwnership { .. } => "update_minter_ownership",
+            Cw721Event::UpdateCreatorOwnership { .. } => "update_creator_ownership",
+            Cw721Event::SetWithdrawAddress { .. } => "set_withdraw_address",
+            Cw721Event::RemoveWithdrawAddress { .. } => "remove_withdraw_address",
+            Cw721Event::WithdrawFunds { .. } => "withdraw_funds",
+            Cw721Event::MigrateIndexes { .. } => "migrate_indexes",
+        }
+    }
+
+    /// Creates the event, reading contract name and version from storage.
+    pub fn into_event(self, storage: &dyn Storage) -> StdResult<Event> {
+        Ok(self.into_event_with_version(&contract_version(storage)?))
+    }
+
+    pub fn into_event_with_version(self, version: &ContractVersion) -> Event {
+        let event = Event::new(format!("{}{}", EVENT_TYPE_PREFIX, self.action()))
+            .add_attribute("contract_name", &version.contract)
+            .add_attribute("contract_version", &version.version);
+        match self {
+            Cw721Event::Mint {
+                minter,
+                owner,
+                token_id,
+                token_uri,
+            } => event
+                .add_attribute("minter", minter)
+                .add_attribute("owner", owner)
+                .add_attribute("token_id", token_id)
+                .add_attribute("token_uri", token_uri.unwrap_or_default()),
+            Cw721Event::Transfer {
+                sender,
+                from,
+                to,
+                token_id,
+            }
+            | Cw721Event::Send {
+                sender,
+                from,
+                to,
+                token_id,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute("from", from)
+                .add_attribute("to", to)
+                .add_attribute("token_id", token_id),
+            Cw721Event::Burn {
+                sender,
+                owner,
+                token_id,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute("owner", owner)
+                .add_attribute("token_id", token_id),
+            Cw721Event::Approve {
+                sender,
+                owner,
+                spender,
+                token_id,
+                expires,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute("owner", owner)
+                .add_attribute("spender", spender)
+                .add_attribute("token_id", token_id)
+                .add_attribute("expires", expires.to_string()),
+            Cw721Event::Revoke {
+                sender,
+                owner,
+                spender,
+                token_id,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute("owner", owner)
+                .add_attribute("spender", spender)
+                .add_attribute("token_id", token_id),
+            Cw721Event::ApproveAll {
+                owner,
+                operator,
+                expires,
+            } => event
+                .add_attribute("owner", owner)
+                .add_attribute("operator", operator)
+                .add_attribute("expires", expires.to_string()),
+            Cw721Event::RevokeAll { owner, operator } => event
+                .add_attribute("owner", owner)
+                .add_attribute("operator", operator),
+            Cw721Event::UpdateMetadata {
+                sender,
+                token_id,
+                token_uri,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute("token_id", token_id)
+                .add_attribute("token_uri", token_uri.unwrap_or_default()),
+            Cw721Event::Lock {
+                sender,
+                token_id,
+                expires,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute("token_id", token_id)
+                .add_attribute("expires", expires.to_string()),
+            Cw721Event::Unlock { sender, token_id } => event
+                .add_attribute("sender", sender)
+                .add_attribute("token_id", token_id),
+            Cw721Event::PruneExpired {
+                sender,
+                pruned_approvals,
+                pruned_operators,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute("pruned_approvals", pruned_approvals.to_string())
+                .add_attribute("pruned_operators", pruned_operators.to_string()),
+            Cw721Event::UpdateCollectionInfo {
+                sender,
+                name,
+                symbol,
+                frozen,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute("name", name)
+                .add_attribute("symbol", symbol)
+                .add_attribute("frozen", frozen.to_string()),
+            Cw721Event::UpdatePauseStatus {
+                sender,
+                minting,
+                transfers,
+                burns,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute("minting", minting.to_string())
+                .add_attribute("transfers", transfers.to_string())
+                .add_attribute("burns", burns.to_string()),
+            Cw721Event::UpdateMaxSupply { sender, max_supply } => event
+                .add_attribute("sender", sender)
+                .add_attribute("max_supply", max_supply.to_string()),
+            Cw721Event::SetCollectionRoyalty {
+                sender,
+                payment_address,
+                share,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute("payment_address", payment_address.unwrap_or_default())
+                .add_attribute(
+                    "share",
+                    share.map(|share| share.to_string()).unwrap_or_default(),
+                ),
+            Cw721Event::SetTokenRoyalty {
+                sender,
+                token_id,
+                payment_address,
+                share,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute("token_id", token_id)
+                .add_attribute("payment_address", payment_address.unwrap_or_default())
+                .add_attribute(
+                    "share",
+                    share.map(|share| share.to_string()).unwrap_or_default(),
+                ),
+            Cw721Event::AddHook {
+                sender,
+                hook,
+                revert_on_error,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute("hook", hook)
+                .add_attribute("revert_on_error", revert_on_error.to_string()),
+            Cw721Event::RemoveHook { sender, hook } => event
+                .add_attribute("sender", sender)
+                .add_attribute("hook", hook),
+            Cw721Event::UpdateMinterOwnership {
+                sender,
+                owner,
+                pending_owner,
+                pending_expiry,
+            }
+            | Cw721Event::UpdateCreatorOwnership {
+                sender,
+                owner,
+                pending_owner,
+                pending_expiry,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute("owner", owner.unwrap_or_default())
+                .add_attribute("pending_owner", pending_owner.unwrap_or_default())
+                .add_attribute(
+                    "pending_expiry",
+                    pending_expiry
+                        .map(|expiry| expiry.to_string())
+                        .unwrap_or_default(),
+                ),
+            Cw721Event::SetWithdrawAddress { sender, address }
+            | Cw721Event::RemoveWithdrawAddress { sender, address } => event
+                .add_attribute("sender", sender)
+                .add_attribute("address", address),
+            Cw721Event::WithdrawFunds { address, amount } => event
+                .add_attribute("address", address)
+                .add_attribute("amount", amount.amount.to_string())
+                .add_attribute("denom", amount.denom),
+            Cw721Event::MigrateIndexes {
+                sender,
+                migrated_operator_grants,
+                migrated_token_approvals,
+                migrated_balances,
+                pending,
+            } => event
+                .add_attribute("sender", sender)
+                .add_attribute(
+                    "migrated_operator_grants",
+                    migrated_operator_grants.to_string(),
+                )
+                .add_attribute(
+                    "migrated_token_approvals",
+                    migrated_token_approvals.to_string(),
+                )
+                .add_attribute("migrated_balances", migrated_balances.to_string())
+                .add_attribute("pending", pending.to_string()),
+        }
+    }
+}
+
+/// Creates events for the given actions, reading contract name and version only once.
+pub fn prepare_events(storage: &dyn Storage, events: Vec<Cw721Event>) -> StdResult<Vec<Event>> {
+    let version = contract_version(storage)?;
+    Ok(events
+        .into_iter()
+        .map(|event| event.into_event_with_version(&version))
+        .collect())
+}
+
+/// cw2 contract version, empty if not set (e.g. before instantiation).
+fn contract_version(storage: &dyn Storage) -> StdResult<ContractVersion> {
+    Ok(cw2::CONTRACT
+        .may_load(storage)?
+        .unwrap_or_else(|| ContractVersion {
+            contract: String::new(),
+            version: String::new(),
+        }))
+}
@@ -1,18 +1,32 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use cosmwasm_std::{
-    Addr, Api, BankMsg, Binary, Coin, CustomMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response,
-    StdResult, Storage,
+    to_json_binary, to_json_vec, Addr, Api, BankMsg, Binary, Coin, ContractResult, CosmosMsg,
+    CustomMsg, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Order, QuerierWrapper,
+    QueryRequest, Reply, Response, StdError, StdResult, Storage, SystemError, SystemResult,
+    WasmQuery,
 };
-use cw_ownable::{none_or, Action, Ownership, OwnershipError, OwnershipStore};
-use cw_storage_plus::Item;
+use cw_ownable::{none_or, Action, Ownership, OwnershipError};
+use cw_storage_plus::{Bound, Item, PrimaryKey};
 use cw_utils::Expiration;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::{
     error::Cw721ContractError,
-    msg::{Cw721ExecuteMsg, Cw721InstantiateMsg, Cw721MigrateMsg},
-    receiver::Cw721ReceiveMsg,
-    state::{CollectionInfo, Cw721Config, DefaultOptionMetadataExtension, NftInfo, MINTER},
+    events::{prepare_events, Cw721Event},
+    hooks::{prepare_hooks, Cw721HookMsg, HOOK_REPLY_ID},
+    msg::{
+        Cw721ExecuteMsg, Cw721InstantiateMsg, Cw721MigrateMsg, MintMsg, NftInfoUpdate, PruneCursor,
+        RoyaltyInfoMsg,
+    },
+    query::{DEFAULT_LIMIT, MAX_LIMIT},
+    receiver::{Cw721ReceiveCollection, Cw721ReceiveMsg, Cw721ReceiveMsgV2, ReceiveMsgVersion},
+    state::{
+        BurnedToken, CollectionInfo, Cw721Config, DefaultOptionMetadataExtension,
+        MetadataUpdateAuthority, NftInfo, PauseStatus, RoyaltyInfo, TokenLock, TraitSource,
+        TransferPolicy, CREATOR, MINTER,
+    },
     Approval,
 };
 
@@ -23,22 +37,28 @@ pub trait Cw721Execute<
     TCustomResponseMessage,
     // Message passed for updating metadata.
     TMetadataExtensionMsg,
+    // Extension defined in CollectionInfo.
+    TCollectionInfoExtension,
 > where
-    TMetadataExtension: Serialize + DeserializeOwned + Clone,
+    TMetadataExtension: Serialize + DeserializeOwned + Clone + TraitSource,
     TCustomResponseMessage: CustomMsg,
-    TMetadataExtensionMsg: CustomMsg,
+    TMetadataExtensionMsg: CustomMsg + NftInfoUpdate<TMetadataExtension>,
+    TCollectionInfoExtension: Serialize + DeserializeOwned + Clone,
 {
-    fn instantiate(
+    fn instantiate<TCollectionInfoExtensionMsg>(
         &self,
         deps: DepsMut,
         _env: Env,
         info: MessageInfo,
-        msg: Cw721InstantiateMsg,
+        msg: Cw721InstantiateMsg<TCollectionInfoExtensionMsg>,
         contract_name: &str,
         contract_version: &str,
-    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError>
+    where
+        TCollectionInfoExtensionMsg: IntoCollectionInfoExtension<TCollectionInfoExtension>,
+    {
         cw2::set_contract_version(deps.storage, contract_name, contract_version)?;
-        let config = Cw721Config::<Empty, Empty, Empty>::default();
+        let config = Cw721Config::<Empty, Empty, Empty, TCollectionInfoExtension>::default();
         let collection_info = CollectionInfo {
             name: msg.name,
             symbol: msg.symbol,
@@ -46,18 +66,53 @@ pub trait Cw721Execute<
         config
             .collection_info
             .save(deps.storage, &collection_info)?;
+        let collection_info_extension = msg
+            .collection_info_extension
+            .into_collection_info_extension(deps.api)?;
+        config
+            .collection_info_extension
+            .save(deps.storage, &collection_info_extension)?;
+        if let Some(authority) = msg.metadata_update_authority {
+            config
+                .metadata_update_authority
+                .save(deps.storage, &authority)?;
+        }
+        if let Some(policy) = msg.transfer_policy {
+            config.transfer_policy.save(deps.storage, &policy)?;
+        }
+        if let Some(max_supply) = msg.max_supply {
+            config.max_supply.save(deps.storage, &max_supply)?;
+        }
+        if msg.index_traits.unwrap_or_default() {
+            config.index_traits.save(deps.storage, &true)?;
+        }
+        if let Some(sequential_token_ids) = msg.sequential_token_ids {
+            config
+                .sequential_token_ids
+                .save(deps.storage, &sequential_token_ids)?;
+        }
 
+        // use info.sender if None is passed
         let minter = match msg.minter {
             Some(owner) => deps.api.addr_validate(&owner)?,
-            None => info.sender,
+            None => info.sender.clone(),
         };
         self.initialize_minter(deps.storage, deps.api, Some(minter.as_ref()))?;
 
+        // use info.sender if None is passed
+        let creator = match msg.creator {
+            Some(owner) => deps.api.addr_validate(&owner)?,
+            None => info.sender,
+        };
+        self.initialize_creator(deps.storage, deps.api, Some(creator.as_ref()))?;
+
         if let Some(withdraw_address) = msg.withdraw_address {
-            self.set_withdraw_address(deps, &minter, withdraw_address)?;
+            self.set_withdraw_address(deps, &creator, withdraw_address)?;
         }
 
-        Ok(Response::default().add_attribute("minter", minter))
+        Ok(Response::default()
+            .add_attribute("minter", minter)
+            .add_attribute("creator", creator))
     }
 
     fn execute(
@@ -67,6 +122,8 @@ pub trait Cw721Execute<
         info: MessageInfo,
         msg: Cw721ExecuteMsg<TMetadataExtension, TMetadataExtensionMsg>,
     ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        // enforced here, so neither owner nor minter can bypass it
+        check_not_paused(deps.as_ref(), &msg)?;
         match msg {
             Cw721ExecuteMsg::Mint {
                 token_id,
@@ -74,6 +131,7 @@ pub trait Cw721Execute<
                 token_uri,
                 extension,
             } => self.mint(deps, info, token_id, owner, token_uri, extension),
+            Cw721ExecuteMsg::BatchMint { tokens } => self.batch_mint(deps, info, tokens),
             Cw721ExecuteMsg::Approve {
                 spender,
                 token_id,
@@ -86,6 +144,12 @@ pub trait Cw721Execute<
                 self.approve_all(deps, env, info, operator, expires)
             }
             Cw721ExecuteMsg::RevokeAll { operator } => self.revoke_all(deps, env, info, operator),
+            Cw721ExecuteMsg::PruneExpired {
+                token_ids,
+                owners,
+                start_after,
+                limit,
+            } => self.prune_expired(deps, env, info, token_ids, owners, start_after, limit),
             Cw721ExecuteMsg::TransferNft {
                 recipient,
                 token_id,
@@ -94,11 +158,86 @@ pub trait Cw721Execute<
                 contract,
                 token_id,
                 msg,
-            } => self.send_nft(deps, env, info, contract, token_id, msg),
+                receive_msg_version,
+            } => self.send_nft(
+                deps,
+                env,
+                info,
+                contract,
+                token_id,
+                msg,
+                receive_msg_version,
+            ),
+            Cw721ExecuteMsg::SafeTransferNft {
+                recipient,
+                token_id,
+                msg,
+                receive_msg_version,
+            } => self.safe_transfer_nft(
+                deps,
+                env,
+                info,
+                recipient,
+                token_id,
+                msg,
+                receive_msg_version,
+            ),
+            Cw721ExecuteMsg::BatchTransferNft {
+                recipient,
+                token_ids,
+            } => self.batch_transfer_nft(deps, env, info, recipient, token_ids),
+            Cw721ExecuteMsg::BatchSendNft {
+                contract,
+                token_ids,
+                msg,
+                receive_msg_version,
+            } => self.batch_send_nft(
+                deps,
+                env,
+                info,
+                contract,
+                token_ids,
+                msg,
+                receive_msg_version,
+            ),
+            Cw721ExecuteMsg::LockNft { token_id, expires } => {
+                self.lock_nft(deps, env, info, token_id, expires)
+            }
+            Cw721ExecuteMsg::UnlockNft { token_id } => self.unlock_nft(deps, env, info, token_id),
             Cw721ExecuteMsg::Burn { token_id } => self.burn_nft(deps, env, info, token_id),
+            Cw721ExecuteMsg::BatchBurn { token_ids } => {
+                self.batch_burn_nft(deps, env, info, token_ids)
+            }
             Cw721ExecuteMsg::UpdateOwnership(action) => {
                 self.update_minter_ownership(deps, env, info, action)
             }
+            Cw721ExecuteMsg::UpdateCreatorOwnership(action) => {
+                self.update_creator_ownership(deps, env, info, action)
+            }
+            Cw721ExecuteMsg::UpdateCollectionInfo {
+                name,
+                symbol,
+                freeze,
+            } => self.update_collection_info(deps, env, info, name, symbol, freeze),
+            Cw721ExecuteMsg::UpdatePauseStatus {
+                minting,
+                transfers,
+                burns,
+            } => self.update_pause_status(deps, env, info, minting, transfers, burns),
+            Cw721ExecuteMsg::UpdateMaxSupply { max_supply } => {
+                self.update_max_supply(deps, env, info, max_supply)
+            }
+            Cw721ExecuteMsg::SetCollectionRoyalty { royalty } => {
+                self.set_collection_royalty(deps, env, info, royalty)
+            }
+            Cw721ExecuteMsg::SetTokenRoyalty { token_id, royalty } => {
+                self.set_token_royalty(deps, env, info, token_id, royalty)
+            }
+            Cw721ExecuteMsg::AddHook {
+                addr,
+                revert_on_error,
+            } => self.add_hook(deps, env, info, addr, revert_on_error),
+            Cw721ExecuteMsg::RemoveHook { addr } => self.remove_hook(deps, env, info, addr),
             Cw721ExecuteMsg::Extension { msg } => {
                 self.update_metadata_extension(deps, env, info, msg)
             }
@@ -109,6 +248,7 @@ pub trait Cw721Execute<
                 self.remove_withdraw_address(deps.storage, &info.sender)
             }
             Cw721ExecuteMsg::WithdrawFunds { amount } => self.withdraw_funds(deps.storage, &amount),
+            Cw721ExecuteMsg::MigrateIndexes { limit } => self.migrate_indexes(deps, info, limit),
         }
     }
 
@@ -125,72 +265,340 @@ pub trait Cw721Execute<
         let response =
             migrate_legacy_minter_and_creator(deps.storage, deps.api, &env, &msg, response)?;
         let response = migrate_legacy_collection_info(deps.storage, &env, &msg, response)?;
+        let response = migrate_operator_grants(deps.storage, response)?;
+        let response = migrate_token_approvals::<TMetadataExtension>(deps.storage, response)?;
+        let response = migrate_balances::<TMetadataExtension>(deps.storage, response)?;
+        let response = migrate_total_minted(deps.storage, &msg, response)?;
         // ... then migrate
         let response = migrate_version(deps.storage, contract_name, contract_version, response)?;
         // ... and update creator and minter AFTER legacy migration
+        let response = migrate_creator(deps.storage, deps.api, &env, &msg, response)?;
         let response = migrate_minter(deps.storage, deps.api, &env, &msg, response)?;
         Ok(response)
     }
 
+    fn reply(
+        &self,
+        _deps: DepsMut,
+        _env: Env,
+        msg: Reply,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        match msg.id {
+            // hooks registered with `revert_on_error: false` must not revert the action
+            HOOK_REPLY_ID => Ok(Response::new()
+                .add_attribute("action", "hook_failed")
+                .add_attribute("error", msg.result.into_result().err().unwrap_or_default())),
+            id => Err(Cw721ContractError::UnknownReplyId { id }),
+        }
+    }
+
     // ------- ERC721-based functions -------
     fn transfer_nft(
         &self,
-        deps: DepsMut,
+        mut deps: DepsMut,
         env: Env,
         info: MessageInfo,
         recipient: String,
         token_id: String,
     ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
-        _transfer_nft::<TMetadataExtension>(deps, &env, &info, &recipient, &token_id)?;
+        let hooks = prepare_hooks(
+            deps.storage,
+            vec![Cw721HookMsg::Transfer {
+                token_id: token_id.clone(),
+                sender: info.sender.to_string(),
+                recipient: recipient.clone(),
+            }],
+        )?;
+        let previous_owner =
+            _transfer_nft::<TMetadataExtension>(deps.branch(), &env, &info, &recipient, &token_id)?;
+        let event = Cw721Event::Transfer {
+            sender: info.sender.to_string(),
+            from: previous_owner.to_string(),
+            to: recipient.clone(),
+            token_id: token_id.clone(),
+        }
+        .into_event(deps.storage)?;
 
         Ok(Response::new()
+            .add_submessages(hooks)
+            .add_event(event)
             .add_attribute("action", "transfer_nft")
             .add_attribute("sender", info.sender)
             .add_attribute("recipient", recipient)
             .add_attribute("token_id", token_id))
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn send_nft(
         &self,
-        deps: DepsMut,
+        mut deps: DepsMut,
         env: Env,
         info: MessageInfo,
         contract: String,
         token_id: String,
         msg: Binary,
+        receive_msg_version: Option<ReceiveMsgVersion>,
     ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        let hooks = prepare_hooks(
+            deps.storage,
+            vec![Cw721HookMsg::Send {
+                token_id: token_id.clone(),
+                sender: info.sender.to_string(),
+                contract: contract.clone(),
+            }],
+        )?;
         // Transfer token
-        _transfer_nft::<TMetadataExtension>(deps, &env, &info, &contract, &token_id)?;
-
-        let send = Cw721ReceiveMsg {
+        let previous_owner =
+            _transfer_nft::<TMetadataExtension>(deps.branch(), &env, &info, &contract, &token_id)?;
+        let event = Cw721Event::Send {
             sender: info.sender.to_string(),
+            from: previous_owner.to_string(),
+            to: contract.clone(),
             token_id: token_id.clone(),
+        }
+        .into_event(deps.storage)?;
+
+        let send = receive_nft_msg(
+            deps.storage,
+            &env,
+            receive_msg_version.unwrap_or_default(),
+            &contract,
+            &info.sender,
+            &previous_owner,
+            &token_id,
             msg,
-        };
+            info.funds.clone(),
+        )?;
 
         // Send message
         Ok(Response::new()
-            .add_message(send.into_cosmos_msg(contract.clone())?)
+            .add_message(send)
+            .add_submessages(hooks)
+            .add_event(event)
             .add_attribute("action", "send_nft")
             .add_attribute("sender", info.sender)
             .add_attribute("recipient", contract)
             .add_attribute("token_id", token_id))
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn safe_transfer_nft(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        recipient: String,
+        token_id: String,
+        msg: Option<Binary>,
+        receive_msg_version: Option<ReceiveMsgVersion>,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        // accounts get a plain transfer
+        let is_contract = is_contract(&deps.querier, &recipient)?;
+        let receive_msg_version = receive_msg_version.unwrap_or_default();
+        // only `ReceiveNftV2` forwards funds, others would be kept by this contract
+        if !info.funds.is_empty() && !(is_contract && receive_msg_version == ReceiveMsgVersion::V2)
+        {
+            return Err(Cw721ContractError::FundsNotForwarded {});
+        }
+        let hook = if is_contract {
+            Cw721HookMsg::Send {
+                token_id: token_id.clone(),
+                sender: info.sender.to_string(),
+                contract: recipient.clone(),
+            }
+        } else {
+            Cw721HookMsg::Transfer {
+                token_id: token_id.clone(),
+                sender: info.sender.to_string(),
+                recipient: recipient.clone(),
+            }
+        };
+        let hooks = prepare_hooks(deps.storage, vec![hook])?;
+        let previous_owner =
+            _transfer_nft::<TMetadataExtension>(deps.branch(), &env, &info, &recipient, &token_id)?;
+
+        let mut response = Response::new();
+        let event = if is_contract {
+            // receiver errors revert the transfer, since the message is not a submessage
+            let receive = receive_nft_msg(
+                deps.storage,
+                &env,
+                receive_msg_version,
+                &recipient,
+                &info.sender,
+                &previous_owner,
+                &token_id,
+                msg.unwrap_or_default(),
+                info.funds.clone(),
+            )?;
+            response = response.add_message(receive);
+            Cw721Event::Send {
+                sender: info.sender.to_string(),
+                from: previous_owner.to_string(),
+                to: recipient.clone(),
+                token_id: token_id.clone(),
+            }
+        } else {
+            Cw721Event::Transfer {
+                sender: info.sender.to_string(),
+                from: previous_owner.to_string(),
+                to: recipient.clone(),
+                token_id: token_id.clone(),
+            }
+        }
+        .into_event(deps.storage)?;
+
+        Ok(response
+            .add_submessages(hooks)
+            .add_event(event)
+            .add_attribute("action", "safe_transfer_nft")
+            .add_attribute("sender", info.sender)
+            .add_attribute("recipient", recipient)
+            .add_attribute("token_id", token_id)
+            .add_attribute("receiver_notified", is_contract.to_string()))
+    }
+
+    fn batch_transfer_nft(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        recipient: String,
+        token_ids: Vec<String>,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        if token_ids.is_empty() {
+            return Err(Cw721ContractError::EmptyBatch {});
+        }
+        let mut events = Vec::with_capacity(token_ids.len());
+        let mut hook_msgs = Vec::with_capacity(token_ids.len());
+        for token_id in &token_ids {
+            let previous_owner = _transfer_nft::<TMetadataExtension>(
+                deps.branch(),
+                &env,
+                &info,
+                &recipient,
+                token_id,
+            )?;
+            events.push(Cw721Event::Transfer {
+                sender: info.sender.to_string(),
+                from: previous_owner.to_string(),
+                to: recipient.clone(),
+                token_id: token_id.clone(),
+            });
+            hook_msgs.push(Cw721HookMsg::Transfer {
+                token_id: token_id.clone(),
+                sender: info.sender.to_string(),
+                recipient: recipient.clone(),
+            });
+        }
+        let hooks = prepare_hooks(deps.storage, hook_msgs)?;
+        let events = prepare_events(deps.storage, events)?;
+
+        Ok(Response::new()
+            .add_submessages(hooks)
+            .add_attribute("action", "batch_transfer_nft")
+            .add_attribute("sender", info.sender)
+            .add_attribute("recipient", recipient)
+            .add_attribute("count", token_ids.len().to_string())
+            .add_events(events))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn batch_send_nft(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        contract: String,
+        token_ids: Vec<String>,
+        msg: Binary,
+        receive_msg_version: Option<ReceiveMsgVersion>,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        if token_ids.is_empty() {
+            return Err(Cw721ContractError::EmptyBatch {});
+        }
+        let receive_msg_version = receive_msg_version.unwrap_or_default();
+        let mut response = Response::new();
+        let mut events = Vec::with_capacity(token_ids.len());
+        let mut hook_msgs = Vec::with_capacity(token_ids.len());
+        for (i, token_id) in token_ids.iter().enumerate() {
+            // Transfer token
+            let previous_owner = _transfer_nft::<TMetadataExtension>(
+                deps.branch(),
+                &env,
+                &info,
+                &contract,
+                token_id,
+            )?;
+            events.push(Cw721Event::Send {
+                sender: info.sender.to_string(),
+                from: previous_owner.to_string(),
+                to: contract.clone(),
+                token_id: token_id.clone(),
+            });
+
+            // one receive message per token, so existing receivers work as is
+            // funds can only be forwarded once, so they go with the first token
+            let funds = if i == 0 { info.funds.clone() } else { vec![] };
+            let send = receive_nft_msg(
+                deps.storage,
+                &env,
+                receive_msg_version.clone(),
+                &contract,
+                &info.sender,
+                &previous_owner,
+                token_id,
+                msg.clone(),
+                funds,
+            )?;
+            response = response.add_message(send);
+            hook_msgs.push(Cw721HookMsg::Send {
+                token_id: token_id.clone(),
+                sender: info.sender.to_string(),
+                contract: contract.clone(),
+            });
+        }
+        let hooks = prepare_hooks(deps.storage, hook_msgs)?;
+        let events = prepare_events(deps.storage, events)?;
+
+        Ok(response
+            .add_submessages(hooks)
+            .add_attribute("action", "batch_send_nft")
+            .add_attribute("sender", info.sender)
+            .add_attribute("recipient", contract)
+            .add_attribute("count", token_ids.len().to_string())
+            .add_events(events))
+    }
+
     fn approve(
         &self,
-        deps: DepsMut,
+        mut deps: DepsMut,
         env: Env,
         info: MessageInfo,
         spender: String,
         token_id: String,
         expires: Option<Expiration>,
     ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
-        _update_approvals::<TMetadataExtension>(
-            deps, &env, &info, &spender, &token_id, true, expires,
+        let token = _update_approvals::<TMetadataExtension>(
+            deps.branch(),
+            &env,
+            &info,
+            &spender,
+            &token_id,
+            true,
+            expires,
         )?;
+        let event = Cw721Event::Approve {
+            sender: info.sender.to_string(),
+            owner: token.owner.to_string(),
+            spender: spender.clone(),
+            token_id: token_id.clone(),
+            expires: expires.unwrap_or_default(),
+        }
+        .into_event(deps.storage)?;
 
         Ok(Response::new()
+            .add_event(event)
             .add_attribute("action", "approve")
             .add_attribute("sender", info.sender)
             .add_attribute("spender", spender)
@@ -199,17 +607,31 @@ pub trait Cw721Execute<
 
     fn revoke(
         &self,
-        deps: DepsMut,
+        mut deps: DepsMut,
         env: Env,
         info: MessageInfo,
         spender: String,
         token_id: String,
     ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
-        _update_approvals::<TMetadataExtension>(
-            deps, &env, &info, &spender, &token_id, false, None,
+        let token = _update_approvals::<TMetadataExtension>(
+            deps.branch(),
+            &env,
+            &info,
+            &spender,
+            &token_id,
+            false,
+            None,
         )?;
+        let event = Cw721Event::Revoke {
+            sender: info.sender.to_string(),
+            owner: token.owner.to_string(),
+            spender: spender.clone(),
+            token_id: token_id.clone(),
+        }
+        .into_event(deps.storage)?;
 
         Ok(Response::new()
+            .add_event(event)
             .add_attribute("action", "revoke")
             .add_attribute("sender", info.sender)
             .add_attribute("spender", spender)
@@ -236,14 +658,25 @@ pub trait Cw721Execute<
             TMetadataExtension,
             TCustomResponseMessage,
             TMetadataExtensionMsg,
+            TCollectionInfoExtension,
         >::default();
         config
             .operators
             // stores info.sender as key (=granter, NFT owner) and operator as value (operator only(!) has control over NFTs of granter)
             // check is done in `check_can_send()`
             .save(deps.storage, (&info.sender, &operator_addr), &expires)?;
+        config
+            .operator_grants
+            .save(deps.storage, (&operator_addr, &info.sender), &expires)?;
+        let event = Cw721Event::ApproveAll {
+            owner: info.sender.to_string(),
+            operator: operator.clone(),
+            expires,
+        }
+        .into_event(deps.storage)?;
 
         Ok(Response::new()
+            .add_event(event)
             .add_attribute("action", "approve_all")
             .add_attribute("sender", info.sender)
             .add_attribute("operator", operator))
@@ -261,41 +694,451 @@ pub trait Cw721Execute<
             TMetadataExtension,
             TCustomResponseMessage,
             TMetadataExtensionMsg,
+            TCollectionInfoExtension,
         >::default();
         config
             .operators
             .remove(deps.storage, (&info.sender, &operator_addr));
+        config
+            .operator_grants
+            .remove(deps.storage, (&operator_addr, &info.sender));
+        let event = Cw721Event::RevokeAll {
+            owner: info.sender.to_string(),
+            operator: operator.clone(),
+        }
+        .into_event(deps.storage)?;
 
         Ok(Response::new()
+            .add_event(event)
             .add_attribute("action", "revoke_all")
             .add_attribute("sender", info.sender)
             .add_attribute("operator", operator))
     }
 
-    fn burn_nft(
+    #[allow(clippy::too_many_arguments)]
+    fn prune_expired(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        token_ids: Option<Vec<String>>,
+        owners: Option<Vec<String>>,
+        start_after: Option<PruneCursor>,
+        limit: Option<u32>,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        // at least one entry, otherwise a missing cursor would wrongly signal completion
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT) as usize;
+        let config = Cw721Config::<
+            TMetadataExtension,
+            TCustomResponseMessage,
+            TMetadataExtensionMsg,
+            TCollectionInfoExtension,
+        >::default();
+        // where to continue, in case not all entries are pruned
+        let mut next_start_after = None;
+        // expired approvals, as (spender, token_id)
+        let mut expired_approvals: Vec<(Addr, String)> = vec![];
+        // expired operators, as (granter, operator)
+        let mut expired_operators: Vec<(Addr, Addr)> = vec![];
+
+        if token_ids.is_none() && owners.is_none() {
+            // scan all approvals, then all operators, reading at most `limit` entries
+            let mut scanned = 0;
+            if !matches!(start_after, Some(PruneCursor::Operator { .. })) {
+                let start = match &start_after {
+                    Some(PruneCursor::Approval { spender, token_id }) => {
+                        let spender = deps.api.addr_validate(spender)?;
+                        Some(Bound::ExclusiveRaw(
+                            (&spender, token_id.as_str()).joined_key(),
+                        ))
+                    }
+                    _ => None,
+                };
+                let entries = config
+                    .token_approvals
+                    .range(deps.storage, start, None, Order::Ascending)
+                    .take(limit)
+                    .collect::<StdResult<Vec<_>>>()?;
+                scanned = entries.len();
+                if scanned == limit {
+                    next_start_after =
+                        entries
+                            .last()
+                            .map(|((spender, token_id), _)| PruneCursor::Approval {
+                                spender: spender.to_string(),
+                                token_id: token_id.clone(),
+                            });
+                }
+                expired_approvals = entries
+                    .into_iter()
+                    .filter(|(_, expires)| expires.is_expired(&env.block))
+                    .map(|(key, _)| key)
+                    .collect();
+            }
+            // approvals used up the whole batch otherwise
+            if next_start_after.is_none() {
+                let start = match &start_after {
+                    Some(PruneCursor::Operator { owner, operator }) => {
+                        let owner = deps.api.addr_validate(owner)?;
+                        let operator = deps.api.addr_validate(operator)?;
+                        Some(Bound::ExclusiveRaw((&owner, &operator).joined_key()))
+                    }
+                    _ => None,
+                };
+                let limit = limit - scanned;
+                let entries = config
+                    .operators
+                    .range(deps.storage, start, None, Order::Ascending)
+                    .take(limit)
+                    .collect::<StdResult<Vec<_>>>()?;
+                if entries.len() == limit {
+                    next_start_after =
+                        entries
+                            .last()
+                            .map(|((owner, operator), _)| PruneCursor::Operator {
+                                owner: owner.to_string(),
+                                operator: operator.to_string(),
+                            });
+                }
+                expired_operators = entries
+                    .into_iter()
+                    .filter(|(_, expires)| expires.is_expired(&env.block))
+                    .map(|(key, _)| key)
+                    .collect();
+            }
+        } else {
+            // collect expired entries of the given tokens, then owners, until `limit` is reached
+            let (start_token_id, start_operator) = match &start_after {
+                Some(PruneCursor::Approval { token_id, .. }) => (Some(token_id.clone()), None),
+                Some(PruneCursor::Operator { owner, operator }) => (
+                    None,
+                    Some((
+                        deps.api.addr_validate(owner)?,
+                        deps.api.addr_validate(operator)?,
+                    )),
+                ),
+                None => (None, None),
+            };
+            // tokens are done, once the scan continues with operators
+            let token_ids = match start_operator {
+                Some(_) => BTreeSet::new(),
+                None => token_ids.unwrap_or_default().into_iter().collect(),
+            };
+            'tokens: for token_id in token_ids {
+                // pruned approvals are gone, so the scan continues at the token of the cursor
+                if matches!(&start_token_id, Some(start) if &token_id < start) {
+                    continue;
+                }
+                // skip tokens burned in the meantime
+                if let Some(token) = config.nft_info.may_load(deps.storage, &token_id)? {
+                    for approval in token.approvals {
+                        if !approval.is_expired(&env.block) {
+                            continue;
+                        }
+                        if expired_approvals.len() == limit {
+                            next_start_after =
+                                expired_approvals.last().map(|(spender, token_id)| {
+                                    PruneCursor::Approval {
+                                        spender: spender.to_string(),
+                                        token_id: token_id.clone(),
+                                    }
+                                });
+                            break 'tokens;
+                        }
+                        expired_approvals.push((approval.spender, token_id.clone()));
+                    }
+                }
+            }
+            if next_start_after.is_none() {
+                let owners = owners
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect::<BTreeSet<_>>();
+                'owners: for owner in owners {
+                    let owner = deps.api.addr_validate(&owner)?;
+                    let start = match &start_operator {
+                        Some((start_owner, _)) if &owner < start_owner => continue,
+                        Some((start_owner, operator)) if &owner == start_owner => {
+                            Some(Bound::ExclusiveRaw(operator.as_bytes().to_vec()))
+                        }
+                        _ => None,
+                    };
+                    for item in config.operators.prefix(&owner).range(
+                        deps.storage,
+                        start,
+                        None,
+                        Order::Ascending,
+                    ) {
+                        let (operator, expires) = item?;
+                        if !expires.is_expired(&env.block) {
+                            continue;
+                        }
+                        if expired_approvals.len() + expired_operators.len() == limit {
+                            next_start_after = match expired_operators.last() {
+                                Some((owner, operator)) => Some(PruneCursor::Operator {
+                                    owner: owner.to_string(),
+                                    operator: operator.to_string(),
+                                }),
+                                // approvals used up the whole batch
+                                None => expired_approvals.last().map(|(spender, token_id)| {
+                                    PruneCursor::Approval {
+                                        spender: spender.to_string(),
+                                        token_id: token_id.clone(),
+                                    }
+                                }),
+                            };
+                            break 'owners;
+                        }
+                        expired_operators.push((owner.clone(), operator));
+                    }
+                }
+            }
+        }
+
+        for (spender, token_id) in &expired_approvals {
+            let mut token = config.nft_info.load(deps.storage, token_id)?;
+            token.approvals.retain(|apr| &apr.spender != spender);
+            config.nft_info.save(deps.storage, token_id, &token)?;
+            config
+                .token_approvals
+                .remove(deps.storage, (spender, token_id));
+        }
+
+        for (granter, operator) in &expired_operators {
+            config.operators.remove(deps.storage, (granter, operator));
+            config
+                .operator_grants
+                .remove(deps.storage, (operator, granter));
+        }
+
+        let event = Cw721Event::PruneExpired {
+            sender: info.sender.to_string(),
+            pruned_approvals: expired_approvals.len() as u64,
+            pruned_operators: expired_operators.len() as u64,
+        }
+        .into_event(deps.storage)?;
+
+        let mut res = Response::new()
+            .add_event(event)
+            .add_attribute("action", "prune_expired")
+            .add_attribute("sender", info.sender)
+            .add_attribute("pruned_approvals", expired_approvals.len().to_string())
+            .add_attribute("pruned_operators", expired_operators.len().to_string());
+        if let Some(next_start_after) = next_start_after {
+            res = res.set_data(to_json_binary(&next_start_after)?);
+        }
+        Ok(res)
+    }
+
+    fn lock_nft(
         &self,
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
         token_id: String,
+        expires: Option<Expiration>,
     ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
         let config = Cw721Config::<
             TMetadataExtension,
             TCustomResponseMessage,
             TMetadataExtensionMsg,
+            TCollectionInfoExtension,
         >::default();
         let token = config.nft_info.load(deps.storage, &token_id)?;
         check_can_send(deps.as_ref(), &env, &info, &token)?;
+        check_not_locked(deps.as_ref(), &env, &token_id)?;
+
+        // reject expired data as invalid
+        let expires = expires.unwrap_or_default();
+        if expires.is_expired(&env.block) {
+            return Err(Cw721ContractError::Expired {});
+        }
+        // others can't keep the token from its owner forever
+        if matches!(expires, Expiration::Never {}) && token.owner != info.sender {
+            return Err(Cw721ContractError::LockExpirationRequired {});
+        }
+        let lock = TokenLock {
+            locker: info.sender.clone(),
+            expires,
+        };
+        config.token_locks.save(deps.storage, &token_id, &lock)?;
+        let event = Cw721Event::Lock {
+            sender: info.sender.to_string(),
+            token_id: token_id.clone(),
+            expires,
+        }
+        .into_event(deps.storage)?;
+
+        Ok(Response::new()
+            .add_event(event)
+            .add_attribute("action", "lock_nft")
+            .add_attribute("sender", info.sender)
+            .add_attribute("token_id", token_id)
+            .add_attribute("expires", expires.to_string()))
+    }
+
+    fn unlock_nft(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        token_id: String,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        let config = Cw721Config::<
+            TMetadataExtension,
+            TCustomResponseMessage,
+            TMetadataExtensionMsg,
+            TCollectionInfoExtension,
+        >::default();
+        match config.token_locks.may_load(deps.storage, &token_id)? {
+            Some(lock) if !lock.is_expired(&env.block) => {
+                // revoking the locker's approval doesn't release the lock, only expiry does
+                if lock.locker != info.sender {
+                    return Err(Cw721ContractError::Ownership(OwnershipError::NotOwner));
+                }
+            }
+            _ => return Err(Cw721ContractError::TokenNotLocked { token_id }),
+        }
+        config.token_locks.remove(deps.storage, &token_id);
+        let event = Cw721Event::Unlock {
+            sender: info.sender.to_string(),
+            token_id: token_id.clone(),
+        }
+        .into_event(deps.storage)?;
+
+        Ok(Response::new()
+            .add_event(event)
+            .add_attribute("action", "unlock_nft")
+            .add_attribute("sender", info.sender)
+            .add_attribute("token_id", token_id))
+    }
+
+    fn burn_nft(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        token_id: String,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        let config = Cw721Config::<
+            TMetadataExtension,
+            TCustomResponseMessage,
+            TMetadataExtensionMsg,
+            TCollectionInfoExtension,
+        >::default();
+        let token = config.nft_info.load(deps.storage, &token_id)?;
+        check_can_burn(deps.as_ref(), &env, &info, &token)?;
+        check_not_locked(deps.as_ref(), &env, &token_id)?;
 
         config.nft_info.remove(deps.storage, &token_id)?;
+        config.clear_token_approvals(deps.storage, &token_id, &token.approvals);
+        config.decrement_balance(deps.storage, &token.owner, &token_id)?;
+        config.unindex_token_traits(deps.storage, &token_id, &token.extension)?;
+        config.token_royalties.remove(deps.storage, &token_id);
+        config.token_locks.remove(deps.storage, &token_id);
+        config.burned_tokens.save(
+            deps.storage,
+            &token_id,
+            &BurnedToken {
+                burner: info.sender.clone(),
+                height: env.block.height,
+                time: env.block.time,
+            },
+        )?;
         config.decrement_tokens(deps.storage)?;
+        let hooks = prepare_hooks(
+            deps.storage,
+            vec![Cw721HookMsg::Burn {
+                token_id: token_id.clone(),
+                sender: info.sender.to_string(),
+            }],
+        )?;
+        let event = Cw721Event::Burn {
+            sender: info.sender.to_string(),
+            owner: token.owner.to_string(),
+            token_id: token_id.clone(),
+        }
+        .into_event(deps.storage)?;
 
         Ok(Response::new()
+            .add_submessages(hooks)
+            .add_event(event)
             .add_attribute("action", "burn")
             .add_attribute("sender", info.sender)
             .add_attribute("token_id", token_id))
     }
 
+    fn batch_burn_nft(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        token_ids: Vec<String>,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        if token_ids.is_empty() {
+            return Err(Cw721ContractError::EmptyBatch {});
+        }
+        let config = Cw721Config::<
+            TMetadataExtension,
+            TCustomResponseMessage,
+            TMetadataExtensionMsg,
+            TCollectionInfoExtension,
+        >::default();
+        // check permissions for all tokens before removing any of them
+        for (i, token_id) in token_ids.iter().enumerate() {
+            if token_ids[..i].contains(token_id) {
+                return Err(Cw721ContractError::DuplicateTokenId {
+                    token_id: token_id.clone(),
+                });
+            }
+            let token = config.nft_info.load(deps.storage, token_id)?;
+            check_can_burn(deps.as_ref(), &env, &info, &token)?;
+            check_not_locked(deps.as_ref(), &env, token_id)?;
+        }
+
+        let mut events = Vec::with_capacity(token_ids.len());
+        for token_id in &token_ids {
+            let token = config.nft_info.load(deps.storage, token_id)?;
+            events.push(Cw721Event::Burn {
+                sender: info.sender.to_string(),
+                owner: token.owner.to_string(),
+                token_id: token_id.clone(),
+            });
+            config.nft_info.remove(deps.storage, token_id)?;
+            config.clear_token_approvals(deps.storage, token_id, &token.approvals);
+            config.decrement_balance(deps.storage, &token.owner, token_id)?;
+            config.unindex_token_traits(deps.storage, token_id, &token.extension)?;
+            config.token_royalties.remove(deps.storage, token_id);
+            config.token_locks.remove(deps.storage, token_id);
+            config.burned_tokens.save(
+                deps.storage,
+                token_id,
+                &BurnedToken {
+                    burner: info.sender.clone(),
+                    height: env.block.height,
+                    time: env.block.time,
+                },
+            )?;
+        }
+        config.decrement_tokens_by(deps.storage, token_ids.len() as u64)?;
+        let hook_msgs = token_ids
+            .iter()
+            .map(|token_id| Cw721HookMsg::Burn {
+                token_id: token_id.clone(),
+                sender: info.sender.to_string(),
+            })
+            .collect();
+        let hooks = prepare_hooks(deps.storage, hook_msgs)?;
+        let events = prepare_events(deps.storage, events)?;
+
+        Ok(Response::new()
+            .add_submessages(hooks)
+            .add_attribute("action", "batch_burn")
+            .add_attribute("sender", info.sender)
+            .add_attributes(token_ids.into_iter().map(|token_id| ("token_id", token_id)))
+            .add_events(events))
+    }
+
     // ------- opionated cw721 functions -------
     fn initialize_minter(
         &self,
@@ -306,41 +1149,109 @@ pub trait Cw721Execute<
         MINTER.initialize_owner(storage, api, minter)
     }
 
+    fn initialize_creator(
+        &self,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        creator: Option<&str>,
+    ) -> StdResult<Ownership<Addr>> {
+        CREATOR.initialize_owner(storage, api, creator)
+    }
+
     fn mint(
         &self,
-        deps: DepsMut,
+        mut deps: DepsMut,
         info: MessageInfo,
-        token_id: String,
+        token_id: Option<String>,
         owner: String,
         token_uri: Option<String>,
         extension: TMetadataExtension,
     ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
         MINTER.assert_owner(deps.storage, &info.sender)?;
+        check_max_supply(deps.as_ref(), 1)?;
+
+        let config = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default();
+        let token_id = token_id_or_assign(deps.storage, token_id)?;
 
         // create the token
-        let token = NftInfo {
-            owner: deps.api.addr_validate(&owner)?,
-            approvals: vec![],
-            token_uri,
-            extension,
+        let event = Cw721Event::Mint {
+            minter: info.sender.to_string(),
+            owner: owner.clone(),
+            token_id: token_id.clone(),
+            token_uri: token_uri.clone(),
         };
-        let config = Cw721Config::<TMetadataExtension, Empty, Empty>::default();
-        config
-            .nft_info
-            .update(deps.storage, &token_id, |old| match old {
-                Some(_) => Err(Cw721ContractError::Claimed {}),
-                None => Ok(token),
-            })?;
-
+        _create_nft(deps.branch(), &token_id, &owner, token_uri, extension)?;
         config.increment_tokens(deps.storage)?;
+        let hooks = prepare_hooks(
+            deps.storage,
+            vec![Cw721HookMsg::Mint {
+                token_id: token_id.clone(),
+                owner: owner.clone(),
+            }],
+        )?;
+        let event = event.into_event(deps.storage)?;
 
         Ok(Response::new()
+            .add_submessages(hooks)
+            .add_event(event)
+            .set_data(to_json_binary(&token_id)?)
             .add_attribute("action", "mint")
             .add_attribute("minter", info.sender)
             .add_attribute("owner", owner)
             .add_attribute("token_id", token_id))
     }
 
+    fn batch_mint(
+        &self,
+        mut deps: DepsMut,
+        info: MessageInfo,
+        tokens: Vec<MintMsg<TMetadataExtension>>,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        MINTER.assert_owner(deps.storage, &info.sender)?;
+        if tokens.is_empty() {
+            return Err(Cw721ContractError::EmptyBatch {});
+        }
+        check_max_supply(deps.as_ref(), tokens.len() as u64)?;
+
+        let amount = tokens.len() as u64;
+        let mut token_ids = Vec::with_capacity(tokens.len());
+        let mut events = Vec::with_capacity(tokens.len());
+        let mut hook_msgs = Vec::with_capacity(tokens.len());
+        for token in tokens {
+            let token_id = token_id_or_assign(deps.storage, token.token_id)?;
+            _create_nft(
+                deps.branch(),
+                &token_id,
+                &token.owner,
+                token.token_uri.clone(),
+                token.extension,
+            )?;
+            hook_msgs.push(Cw721HookMsg::Mint {
+                token_id: token_id.clone(),
+                owner: token.owner.clone(),
+            });
+            events.push(Cw721Event::Mint {
+                minter: info.sender.to_string(),
+                owner: token.owner,
+                token_id: token_id.clone(),
+                token_uri: token.token_uri,
+            });
+            token_ids.push(token_id);
+        }
+        let config = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default();
+        config.increment_tokens_by(deps.storage, amount)?;
+        let hooks = prepare_hooks(deps.storage, hook_msgs)?;
+        let events = prepare_events(deps.storage, events)?;
+
+        Ok(Response::new()
+            .add_submessages(hooks)
+            .set_data(to_json_binary(&token_ids)?)
+            .add_attribute("action", "batch_mint")
+            .add_attribute("minter", info.sender)
+            .add_attribute("count", amount.to_string())
+            .add_events(events))
+    }
+
     fn update_minter_ownership(
         &self,
         deps: DepsMut,
@@ -350,21 +1261,370 @@ pub trait Cw721Execute<
     ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
         let ownership =
             MINTER.update_ownership(deps.api, deps.storage, &env.block, &info.sender, action)?;
+        let event = Cw721Event::UpdateMinterOwnership {
+            sender: info.sender.to_string(),
+            owner: ownership.owner.as_ref().map(Addr::to_string),
+            pending_owner: ownership.pending_owner.as_ref().map(Addr::to_string),
+            pending_expiry: ownership.pending_expiry,
+        }
+        .into_event(deps.storage)?;
         Ok(Response::new()
+            .add_event(event)
             .add_attribute("update_minter_ownership", info.sender)
             .add_attributes(ownership.into_attributes()))
     }
 
-    /// Allows creator to update onchain metadata. For now this is a no-op.
+    fn update_creator_ownership(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        action: Action,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        let ownership =
+            CREATOR.update_ownership(deps.api, deps.storage, &env.block, &info.sender, action)?;
+        let event = Cw721Event::UpdateCreatorOwnership {
+            sender: info.sender.to_string(),
+            owner: ownership.owner.as_ref().map(Addr::to_string),
+            pending_owner: ownership.pending_owner.as_ref().map(Addr::to_string),
+            pending_expiry: ownership.pending_expiry,
+        }
+        .into_event(deps.storage)?;
+        Ok(Response::new()
+            .add_event(event)
+            .add_attribute("update_creator_ownership", info.sender)
+            .add_attributes(ownership.into_attributes()))
+    }
+
+    /// Allows the configured `MetadataUpdateAuthority` to update `token_uri` and onchain metadata.
     fn update_metadata_extension(
         &self,
         deps: DepsMut,
         _env: Env,
         info: MessageInfo,
-        _msg: TMetadataExtensionMsg,
+        msg: TMetadataExtensionMsg,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        let token_id = match msg.token_id() {
+            Some(token_id) => token_id.to_string(),
+            // nothing to update (e.g. `Empty`)
+            None => {
+                CREATOR.assert_owner(deps.storage, &info.sender)?;
+                return Ok(Response::new().add_attribute("action", "update_metadata_extension"));
+            }
+        };
+        let config = Cw721Config::<
+            TMetadataExtension,
+            TCustomResponseMessage,
+            TMetadataExtensionMsg,
+            TCollectionInfoExtension,
+        >::default();
+        let nft_info = config.nft_info.load(deps.storage, &token_id)?;
+        check_can_update_metadata(deps.as_ref(), &info, &nft_info)?;
+
+        config.unindex_token_traits(deps.storage, &token_id, &nft_info.extension)?;
+        let nft_info = msg.apply(nft_info);
+        config.nft_info.save(deps.storage, &token_id, &nft_info)?;
+        config.index_token_traits(deps.storage, &token_id, &nft_info.extension)?;
+        let event = Cw721Event::UpdateMetadata {
+            sender: info.sender.to_string(),
+            token_id: token_id.clone(),
+            token_uri: nft_info.token_uri.clone(),
+        }
+        .into_event(deps.storage)?;
+
+        Ok(Response::new()
+            .add_event(event)
+            .add_attribute("action", "update_metadata_extension")
+            .add_attribute("sender", info.sender)
+            .add_attribute("token_id", token_id)
+            .add_attribute("token_uri", nft_info.token_uri.unwrap_or_default()))
+    }
+
+    fn update_collection_info(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        name: Option<String>,
+        symbol: Option<String>,
+        freeze: Option<bool>,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        CREATOR.assert_owner(deps.storage, &info.sender)?;
+        let config = Cw721Config::<
+            TMetadataExtension,
+            TCustomResponseMessage,
+            TMetadataExtensionMsg,
+            TCollectionInfoExtension,
+        >::default();
+        if config
+            .collection_info_frozen
+            .may_load(deps.storage)?
+            .unwrap_or(false)
+        {
+            return Err(Cw721ContractError::CollectionInfoFrozen {});
+        }
+
+        let mut collection_info = config.collection_info.load(deps.storage)?;
+        if let Some(name) = name {
+            collection_info.name = name;
+        }
+        if let Some(symbol) = symbol {
+            collection_info.symbol = symbol;
+        }
+        if collection_info.name.trim().is_empty() {
+            return Err(Cw721ContractError::CollectionNameEmpty {});
+        }
+        if collection_info.symbol.trim().is_empty() {
+            return Err(Cw721ContractError::CollectionSymbolEmpty {});
+        }
+        config
+            .collection_info
+            .save(deps.storage, &collection_info)?;
+
+        let frozen = freeze.unwrap_or(false);
+        if frozen {
+            config.collection_info_frozen.save(deps.storage, &true)?;
+        }
+        let event = Cw721Event::UpdateCollectionInfo {
+            sender: info.sender.to_string(),
+            name: collection_info.name.clone(),
+            symbol: collection_info.symbol.clone(),
+            frozen,
+        }
+        .into_event(deps.storage)?;
+
+        Ok(Response::new()
+            .add_event(event)
+            .add_attribute("action", "update_collection_info")
+            .add_attribute("sender", info.sender)
+            .add_attribute("name", collection_info.name)
+            .add_attribute("symbol", collection_info.symbol)
+            .add_attribute("frozen", frozen.to_string()))
+    }
+
+    fn update_pause_status(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        minting: Option<bool>,
+        transfers: Option<bool>,
+        burns: Option<bool>,
     ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
-        cw_ownable::assert_owner(deps.storage, &info.sender)?;
-        Ok(Response::new().add_attribute("action", "update_metadata_extension"))
+        CREATOR.assert_owner(deps.storage, &info.sender)?;
+        let config = Cw721Config::<
+            TMetadataExtension,
+            TCustomResponseMessage,
+            TMetadataExtensionMsg,
+            TCollectionInfoExtension,
+        >::default();
+        let current = config.pause_status(deps.storage)?;
+        let status = PauseStatus {
+            minting: minting.unwrap_or(current.minting),
+            transfers: transfers.unwrap_or(current.transfers),
+            burns: burns.unwrap_or(current.burns),
+        };
+        config.pause_status.save(deps.storage, &status)?;
+        let event = Cw721Event::UpdatePauseStatus {
+            sender: info.sender.to_string(),
+            minting: status.minting,
+            transfers: status.transfers,
+            burns: status.burns,
+        }
+        .into_event(deps.storage)?;
+
+        Ok(Response::new()
+            .add_event(event)
+            .add_attribute("action", "update_pause_status")
+            .add_attribute("sender", info.sender))
+    }
+
+    fn update_max_supply(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        max_supply: u64,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        CREATOR.assert_owner(deps.storage, &info.sender)?;
+        let config = Cw721Config::<
+            TMetadataExtension,
+            TCustomResponseMessage,
+            TMetadataExtensionMsg,
+            TCollectionInfoExtension,
+        >::default();
+        if let Some(current) = config.max_supply.may_load(deps.storage)? {
+            if max_supply > current {
+                return Err(Cw721ContractError::MaxSupplyRaised {
+                    max_supply: current,
+                });
+            }
+        }
+        let total_minted = config.total_minted(deps.storage)?;
+        if max_supply < total_minted {
+            return Err(Cw721ContractError::MaxSupplyBelowMinted { total_minted });
+        }
+        config.max_supply.save(deps.storage, &max_supply)?;
+        let event = Cw721Event::UpdateMaxSupply {
+            sender: info.sender.to_string(),
+            max_supply,
+        }
+        .into_event(deps.storage)?;
+
+        Ok(Response::new()
+            .add_event(event)
+            .add_attribute("action", "update_max_supply")
+            .add_attribute("sender", info.sender)
+            .add_attribute("max_supply", max_supply.to_string()))
+    }
+
+    fn set_collection_royalty(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        royalty: Option<RoyaltyInfoMsg>,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        CREATOR.assert_owner(deps.storage, &info.sender)?;
+        let config = Cw721Config::<
+            TMetadataExtension,
+            TCustomResponseMessage,
+            TMetadataExtensionMsg,
+            TCollectionInfoExtension,
+        >::default();
+        let royalty = royalty
+            .map(|royalty| validate_royalty(deps.api, royalty))
+            .transpose()?;
+        match &royalty {
+            Some(royalty) => config.collection_royalty.save(deps.storage, royalty)?,
+            None => config.collection_royalty.remove(deps.storage),
+        }
+        let event = Cw721Event::SetCollectionRoyalty {
+            sender: info.sender.to_string(),
+            payment_address: royalty
+                .as_ref()
+                .map(|royalty| royalty.payment_address.to_string()),
+            share: royalty.as_ref().map(|royalty| royalty.share),
+        }
+        .into_event(deps.storage)?;
+
+        Ok(Response::new()
+            .add_event(event)
+            .add_attribute("action", "set_collection_royalty")
+            .add_attribute("sender", info.sender)
+            .add_attributes(royalty_attributes(royalty)))
+    }
+
+    fn set_token_royalty(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        token_id: String,
+        royalty: Option<RoyaltyInfoMsg>,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        CREATOR.assert_owner(deps.storage, &info.sender)?;
+        let config = Cw721Config::<
+            TMetadataExtension,
+            TCustomResponseMessage,
+            TMetadataExtensionMsg,
+            TCollectionInfoExtension,
+        >::default();
+        // fails if token does not exist
+        config.nft_info.load(deps.storage, &token_id)?;
+        let royalty = royalty
+            .map(|royalty| validate_royalty(deps.api, royalty))
+            .transpose()?;
+        match &royalty {
+            Some(royalty) => config
+                .token_royalties
+                .save(deps.storage, &token_id, royalty)?,
+            None => config.token_royalties.remove(deps.storage, &token_id),
+        }
+        let event = Cw721Event::SetTokenRoyalty {
+            sender: info.sender.to_string(),
+            token_id: token_id.clone(),
+            payment_address: royalty
+                .as_ref()
+                .map(|royalty| royalty.payment_address.to_string()),
+            share: royalty.as_ref().map(|royalty| royalty.share),
+        }
+        .into_event(deps.storage)?;
+
+        Ok(Response::new()
+            .add_event(event)
+            .add_attribute("action", "set_token_royalty")
+            .add_attribute("sender", info.sender)
+            .add_attribute("token_id", token_id)
+            .add_attributes(royalty_attributes(royalty)))
+    }
+
+    fn add_hook(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        addr: String,
+        revert_on_error: Option<bool>,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        CREATOR.assert_owner(deps.storage, &info.sender)?;
+        let hook = deps.api.addr_validate(&addr)?;
+        let config = Cw721Config::<
+            TMetadataExtension,
+            TCustomResponseMessage,
+            TMetadataExtensionMsg,
+            TCollectionInfoExtension,
+        >::default();
+        if config.hooks.has(deps.storage, &hook) {
+            return Err(Cw721ContractError::HookAlreadyRegistered { addr });
+        }
+        let revert_on_error = revert_on_error.unwrap_or(true);
+        config.hooks.save(deps.storage, &hook, &revert_on_error)?;
+        let event = Cw721Event::AddHook {
+            sender: info.sender.to_string(),
+            hook: hook.to_string(),
+            revert_on_error,
+        }
+        .into_event(deps.storage)?;
+
+        Ok(Response::new()
+            .add_event(event)
+            .add_attribute("action", "add_hook")
+            .add_attribute("sender", info.sender)
+            .add_attribute("hook", hook)
+            .add_attribute("revert_on_error", revert_on_error.to_string()))
+    }
+
+    fn remove_hook(
+        &self,
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        addr: String,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        CREATOR.assert_owner(deps.storage, &info.sender)?;
+        let hook = deps.api.addr_validate(&addr)?;
+        let config = Cw721Config::<
+            TMetadataExtension,
+            TCustomResponseMessage,
+            TMetadataExtensionMsg,
+            TCollectionInfoExtension,
+        >::default();
+        if !config.hooks.has(deps.storage, &hook) {
+            return Err(Cw721ContractError::HookNotRegistered { addr });
+        }
+        config.hooks.remove(deps.storage, &hook);
+        let event = Cw721Event::RemoveHook {
+            sender: info.sender.to_string(),
+            hook: hook.to_string(),
+        }
+        .into_event(deps.storage)?;
+
+        Ok(Response::new()
+            .add_event(event)
+            .add_attribute("action", "remove_hook")
+            .add_attribute("sender", info.sender)
+            .add_attribute("hook", hook))
     }
 
     fn set_withdraw_address(
@@ -373,15 +1633,22 @@ pub trait Cw721Execute<
         sender: &Addr,
         address: String,
     ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
-        cw_ownable::assert_owner(deps.storage, sender)?;
+        CREATOR.assert_owner(deps.storage, sender)?;
         deps.api.addr_validate(&address)?;
         let config = Cw721Config::<
             TMetadataExtension,
             TCustomResponseMessage,
             TMetadataExtensionMsg,
+            TCollectionInfoExtension,
         >::default();
         config.withdraw_address.save(deps.storage, &address)?;
+        let event = Cw721Event::SetWithdrawAddress {
+            sender: sender.to_string(),
+            address: address.clone(),
+        }
+        .into_event(deps.storage)?;
         Ok(Response::new()
+            .add_event(event)
             .add_attribute("action", "set_withdraw_address")
             .add_attribute("address", address))
     }
@@ -391,17 +1658,24 @@ pub trait Cw721Execute<
         storage: &mut dyn Storage,
         sender: &Addr,
     ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
-        cw_ownable::assert_owner(storage, sender)?;
+        CREATOR.assert_owner(storage, sender)?;
         let config = Cw721Config::<
             TMetadataExtension,
             TCustomResponseMessage,
             TMetadataExtensionMsg,
+            TCollectionInfoExtension,
         >::default();
         let address = config.withdraw_address.may_load(storage)?;
         match address {
             Some(address) => {
                 config.withdraw_address.remove(storage);
+                let event = Cw721Event::RemoveWithdrawAddress {
+                    sender: sender.to_string(),
+                    address: address.clone(),
+                }
+                .into_event(storage)?;
                 Ok(Response::new()
+                    .add_event(event)
                     .add_attribute("action", "remove_withdraw_address")
                     .add_attribute("address", address))
             }
@@ -418,17 +1692,24 @@ pub trait Cw721Execute<
             TMetadataExtension,
             TCustomResponseMessage,
             TMetadataExtensionMsg,
+            TCollectionInfoExtension,
         >::default()
         .withdraw_address
         .may_load(storage)?;
         match withdraw_address {
             Some(address) => {
+                let event = Cw721Event::WithdrawFunds {
+                    address: address.clone(),
+                    amount: amount.clone(),
+                }
+                .into_event(storage)?;
                 let msg = BankMsg::Send {
                     to_address: address,
                     amount: vec![amount.clone()],
                 };
                 Ok(Response::new()
                     .add_message(msg)
+                    .add_event(event)
                     .add_attribute("action", "withdraw_funds")
                     .add_attribute("amount", amount.amount.to_string())
                     .add_attribute("denom", amount.denom.to_string()))
@@ -436,28 +1717,114 @@ pub trait Cw721Execute<
             None => Err(Cw721ContractError::NoWithdrawAddress {}),
         }
     }
+
+    /// Continues index backfills left pending by `migrate`. Only the creator can call this.
+    fn migrate_indexes(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        limit: Option<u32>,
+    ) -> Result<Response<TCustomResponseMessage>, Cw721ContractError> {
+        CREATOR.assert_owner(deps.storage, &info.sender)?;
+        let limit = limit.unwrap_or(MAX_LIMIT).min(MAX_LIMIT);
+        // backfills share the limit and run one after another
+        let operator_grants = backfill_operator_grants(deps.storage, limit)?;
+        let token_approvals =
+            backfill_token_approvals::<TMetadataExtension>(deps.storage, limit - operator_grants)?;
+        let balances = backfill_balances::<TMetadataExtension>(
+            deps.storage,
+            limit - operator_grants - token_approvals,
+        )?;
+        let config = Cw721Config::<Empty, Empty, Empty, Empty>::default();
+        let pending = config
+            .operator_grants_backfill
+            .may_load(deps.storage)?
+            .is_some()
+            || config
+                .token_approvals_backfill
+                .may_load(deps.storage)?
+                .is_some()
+            || config.balances_backfill.may_load(deps.storage)?.is_some();
+        let event = Cw721Event::MigrateIndexes {
+            sender: info.sender.to_string(),
+            migrated_operator_grants: operator_grants,
+            migrated_token_approvals: token_approvals,
+            migrated_balances: balances,
+            pending,
+        }
+        .into_event(deps.storage)?;
+        Ok(Response::new()
+            .add_event(event)
+            .add_attribute("action", "migrate_indexes")
+            .add_attribute("migrated_operator_grants", operator_grants.to_string())
+            .add_attribute("migrated_token_approvals", token_approvals.to_string())
+            .add_attribute("migrated_balances", balances.to_string())
+            .add_attribute("pending", pending.to_string()))
+    }
 }
 
 // ------- helper cw721 functions -------
+/// Stores a new NFT, fails in case `token_id` is already claimed.
+fn _create_nft<TMetadataExtension>(
+    deps: DepsMut,
+    token_id: &str,
+    owner: &str,
+    token_uri: Option<String>,
+    extension: TMetadataExtension,
+) -> Result<NftInfo<TMetadataExtension>, Cw721ContractError>
+where
+    TMetadataExtension: Serialize + DeserializeOwned + Clone + TraitSource,
+{
+    let token = NftInfo {
+        owner: deps.api.addr_validate(owner)?,
+        approvals: vec![],
+        token_uri,
+        extension,
+    };
+    let config = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default();
+    if config.burned_tokens.has(deps.storage, token_id) {
+        return Err(Cw721ContractError::TokenBurned {
+            token_id: token_id.to_string(),
+        });
+    }
+    let token = config
+        .nft_info
+        .update(deps.storage, token_id, |old| match old {
+            Some(_) => Err(Cw721ContractError::Claimed {}),
+            None => Ok(token),
+        })?;
+    config.increment_balance(deps.storage, &token.owner, token_id)?;
+    config.index_token_traits(deps.storage, token_id, &token.extension)?;
+    Ok(token)
+}
+
+/// Returns the previous owner.
 fn _transfer_nft<TMetadataExtension>(
     deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
     recipient: &str,
     token_id: &str,
-) -> Result<NftInfo<TMetadataExtension>, Cw721ContractError>
+) -> Result<Addr, Cw721ContractError>
 where
     TMetadataExtension: Serialize + DeserializeOwned + Clone,
 {
-    let config = Cw721Config::<TMetadataExtension, Empty, Empty>::default();
+    let config = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default();
     let mut token = config.nft_info.load(deps.storage, token_id)?;
     // ensure we have permissions
-    check_can_send(deps.as_ref(), env, info, &token)?;
+    check_can_transfer(deps.as_ref(), env, info, &token)?;
+    check_not_locked(deps.as_ref(), env, token_id)?;
     // set owner and remove existing approvals
-    token.owner = deps.api.addr_validate(recipient)?;
+    let recipient = deps.api.addr_validate(recipient)?;
+    if token.owner != recipient {
+        config.decrement_balance(deps.storage, &token.owner, token_id)?;
+        config.increment_balance(deps.storage, &recipient, token_id)?;
+    }
+    let previous_owner = std::mem::replace(&mut token.owner, recipient);
+    config.clear_token_approvals(deps.storage, token_id, &token.approvals);
     token.approvals = vec![];
     config.nft_info.save(deps.storage, token_id, &token)?;
-    Ok(token)
+    Ok(previous_owner)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -474,7 +1841,7 @@ fn _update_approvals<TMetadataExtension>(
 where
     TMetadataExtension: Serialize + DeserializeOwned + Clone,
 {
-    let config = Cw721Config::<TMetadataExtension, Empty, Empty>::default();
+    let config = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default();
     let mut token = config.nft_info.load(deps.storage, token_id)?;
     // ensure we have permissions
     check_can_approve(deps.as_ref(), env, info, &token)?;
@@ -482,14 +1849,21 @@ where
     // update the approval list (remove any for the same spender before adding)
     let spender_addr = deps.api.addr_validate(spender)?;
     token.approvals.retain(|apr| apr.spender != spender_addr);
+    config
+        .token_approvals
+        .remove(deps.storage, (&spender_addr, token_id));
 
     // only difference between approve and revoke
     if add {
+        check_not_locked(deps.as_ref(), env, token_id)?;
         // reject expired data as invalid
         let expires = expires.unwrap_or_default();
         if expires.is_expired(&env.block) {
             return Err(Cw721ContractError::Expired {});
         }
+        config
+            .token_approvals
+            .save(deps.storage, (&spender_addr, token_id), &expires)?;
         let approval = Approval {
             spender: spender_addr,
             expires,
@@ -517,7 +1891,7 @@ where
         return Ok(());
     }
     // operator can approve
-    let config = Cw721Config::<TMetadataExtension, Empty, Empty>::default();
+    let config = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default();
     let op = config
         .operators
         .may_load(deps.storage, (&token.owner, &info.sender))?;
@@ -533,6 +1907,28 @@ where
     }
 }
 
+/// returns true if the sender is the configured `MetadataUpdateAuthority` for the token
+pub fn check_can_update_metadata<TMetadataExtension>(
+    deps: Deps,
+    info: &MessageInfo,
+    token: &NftInfo<TMetadataExtension>,
+) -> Result<(), Cw721ContractError>
+where
+    TMetadataExtension: Serialize + DeserializeOwned + Clone,
+{
+    let config = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default();
+    match config.metadata_update_authority(deps.storage)? {
+        MetadataUpdateAuthority::Creator => CREATOR.assert_owner(deps.storage, &info.sender)?,
+        MetadataUpdateAuthority::Minter => MINTER.assert_owner(deps.storage, &info.sender)?,
+        MetadataUpdateAuthority::TokenOwner => {
+            if token.owner != info.sender {
+                return Err(Cw721ContractError::Ownership(OwnershipError::NotOwner));
+            }
+        }
+    }
+    Ok(())
+}
+
 /// returns true iff the sender can transfer ownership of the token
 pub fn check_can_send<TMetadataExtension>(
     deps: Deps,
@@ -540,36 +1936,236 @@ pub fn check_can_send<TMetadataExtension>(
     info: &MessageInfo,
     token: &NftInfo<TMetadataExtension>,
 ) -> Result<(), Cw721ContractError> {
+    if can_send(deps, env, &info.sender, token)? {
+        Ok(())
+    } else {
+        Err(Cw721ContractError::Ownership(OwnershipError::NotOwner))
+    }
+}
+
+/// returns true iff `spender` is the owner, an approved spender or an operator of the token
+pub fn can_send<TMetadataExtension>(
+    deps: Deps,
+    env: &Env,
+    spender: &Addr,
+    token: &NftInfo<TMetadataExtension>,
+) -> StdResult<bool> {
     // owner can send
-    if token.owner == info.sender {
-        return Ok(());
+    if &token.owner == spender {
+        return Ok(true);
     }
 
     // any non-expired token approval can send
     if token
         .approvals
         .iter()
-        .any(|apr| apr.spender == info.sender && !apr.is_expired(&env.block))
+        .any(|apr| &apr.spender == spender && !apr.is_expired(&env.block))
     {
-        return Ok(());
+        return Ok(true);
     }
 
     // operator can send
-    let config = Cw721Config::<Empty, Empty, Empty>::default();
+    let config = Cw721Config::<Empty, Empty, Empty, Empty>::default();
     let op = config
         .operators
-        // has token owner approved/gave grant to sender for full control over owner's NFTs?
-        .may_load(deps.storage, (&token.owner, &info.sender))?;
+        // has token owner approved/gave grant to spender for full control over owner's NFTs?
+        .may_load(deps.storage, (&token.owner, spender))?;
+    Ok(op.is_some_and(|ex| !ex.is_expired(&env.block)))
+}
 
-    match op {
-        Some(ex) => {
-            if ex.is_expired(&env.block) {
-                Err(Cw721ContractError::Ownership(OwnershipError::NotOwner))
-            } else {
-                Ok(())
+/// returns true iff the sender can transfer the token under the configured `TransferPolicy`
+pub fn check_can_transfer<TMetadataExtension>(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+    token: &NftInfo<TMetadataExtension>,
+) -> Result<(), Cw721ContractError> {
+    let config = Cw721Config::<Empty, Empty, Empty, Empty>::default();
+    match config.transfer_policy(deps.storage)? {
+        TransferPolicy::Transferable => check_can_send(deps, env, info, token),
+        TransferPolicy::NonTransferable | TransferPolicy::OwnerBurnOnly => {
+            Err(Cw721ContractError::NonTransferable {})
+        }
+        TransferPolicy::MinterOnly => Ok(MINTER.assert_owner(deps.storage, &info.sender)?),
+        TransferPolicy::TransferableAfter { timestamp } => {
+            if env.block.time < timestamp {
+                return Err(Cw721ContractError::TransferableAfter { timestamp });
             }
+            check_can_send(deps, env, info, token)
         }
-        None => Err(Cw721ContractError::Ownership(OwnershipError::NotOwner)),
+    }
+}
+
+/// returns true iff the sender can burn the token under the configured `TransferPolicy`
+pub fn check_can_burn<TMetadataExtension>(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+    token: &NftInfo<TMetadataExtension>,
+) -> Result<(), Cw721ContractError> {
+    let config = Cw721Config::<Empty, Empty, Empty, Empty>::default();
+    match config.transfer_policy(deps.storage)? {
+        TransferPolicy::OwnerBurnOnly => {
+            if token.owner != info.sender {
+                return Err(Cw721ContractError::Ownership(OwnershipError::NotOwner));
+            }
+            Ok(())
+        }
+        _ => check_can_send(deps, env, info, token),
+    }
+}
+
+/// returns an error if the operation triggered by `msg` is currently paused
+pub fn check_not_paused<TMetadataExtension, TMetadataExtensionMsg>(
+    deps: Deps,
+    msg: &Cw721ExecuteMsg<TMetadataExtension, TMetadataExtensionMsg>,
+) -> Result<(), Cw721ContractError> {
+    let status = Cw721Config::<Empty, Empty, Empty, Empty>::default().pause_status(deps.storage)?;
+    match msg {
+        Cw721ExecuteMsg::Mint { .. } | Cw721ExecuteMsg::BatchMint { .. } if status.minting => {
+            Err(Cw721ContractError::MintingPaused {})
+        }
+        Cw721ExecuteMsg::TransferNft { .. }
+        | Cw721ExecuteMsg::SendNft { .. }
+        | Cw721ExecuteMsg::SafeTransferNft { .. }
+        | Cw721ExecuteMsg::BatchTransferNft { .. }
+        | Cw721ExecuteMsg::BatchSendNft { .. }
+            if status.transfers =>
+        {
+            Err(Cw721ContractError::TransfersPaused {})
+        }
+        Cw721ExecuteMsg::Burn { .. } | Cw721ExecuteMsg::BatchBurn { .. } if status.burns => {
+            Err(Cw721ContractError::BurnsPaused {})
+        }
+        _ => Ok(()),
+    }
+}
+
+/// validates the payment address and that the share does not exceed 100%
+pub fn validate_royalty(
+    api: &dyn Api,
+    royalty: RoyaltyInfoMsg,
+) -> Result<RoyaltyInfo, Cw721ContractError> {
+    if royalty.share > Decimal::one() {
+        return Err(Cw721ContractError::InvalidRoyaltyShare {
+            share: royalty.share,
+        });
+    }
+    Ok(RoyaltyInfo {
+        payment_address: api.addr_validate(&royalty.payment_address)?,
+        share: royalty.share,
+    })
+}
+
+/// returns true iff `addr` is a contract, i.e. the querier knows its contract info.
+/// Queried raw, so missing contracts are detected by `SystemError::NoSuchContract`
+/// (or a failed query, e.g. in cw-multi-test) instead of by error message.
+/// Any other system error is returned, so an account is never assumed by mistake.
+pub fn is_contract(querier: &QuerierWrapper, addr: &str) -> StdResult<bool> {
+    let request: QueryRequest<Empty> = WasmQuery::ContractInfo {
+        contract_addr: addr.to_string(),
+    }
+    .into();
+    match querier.raw_query(&to_json_vec(&request)?) {
+        SystemResult::Ok(ContractResult::Ok(_)) => Ok(true),
+        SystemResult::Ok(ContractResult::Err(_))
+        | SystemResult::Err(SystemError::NoSuchContract { .. }) => Ok(false),
+        SystemResult::Err(err) => Err(StdError::generic_err(format!(
+            "Querier system error: {err}"
+        ))),
+    }
+}
+
+/// `ReceiveNft` or `ReceiveNftV2` message notifying `contract` about a token it received.
+/// Only `V2` forwards `funds`.
+#[allow(clippy::too_many_arguments)]
+fn receive_nft_msg<TCustomResponseMessage>(
+    storage: &dyn Storage,
+    env: &Env,
+    version: ReceiveMsgVersion,
+    contract: &str,
+    sender: &Addr,
+    previous_owner: &Addr,
+    token_id: &str,
+    msg: Binary,
+    funds: Vec<Coin>,
+) -> StdResult<CosmosMsg<TCustomResponseMessage>>
+where
+    TCustomResponseMessage: CustomMsg,
+{
+    match version {
+        ReceiveMsgVersion::V1 => Cw721ReceiveMsg {
+            sender: sender.to_string(),
+            token_id: token_id.to_string(),
+            msg,
+        }
+        .into_cosmos_msg(contract),
+        ReceiveMsgVersion::V2 => {
+            let collection_info = Cw721Config::<Empty, Empty, Empty, Empty>::default()
+                .collection_info
+                .load(storage)?;
+            Cw721ReceiveMsgV2 {
+                sender: sender.to_string(),
+                owner: previous_owner.to_string(),
+                token_id: token_id.to_string(),
+                collection: Cw721ReceiveCollection {
+                    address: env.contract.address.to_string(),
+                    name: collection_info.name,
+                    symbol: collection_info.symbol,
+                },
+                funds,
+                msg,
+            }
+            .into_cosmos_msg(contract)
+        }
+    }
+}
+
+/// `payment_address` and `share` attributes, empty if the royalty is removed
+fn royalty_attributes(royalty: Option<RoyaltyInfo>) -> Vec<(&'static str, String)> {
+    let (payment_address, share) = royalty
+        .map(|royalty| {
+            (
+                royalty.payment_address.to_string(),
+                royalty.share.to_string(),
+            )
+        })
+        .unwrap_or_default();
+    vec![("payment_address", payment_address), ("share", share)]
+}
+
+/// returns the given token id, or assigns the next one in case sequential token ids are enabled
+pub fn token_id_or_assign(
+    storage: &mut dyn Storage,
+    token_id: Option<String>,
+) -> Result<String, Cw721ContractError> {
+    match token_id {
+        Some(token_id) => Ok(token_id),
+        None => Cw721Config::<Empty, Empty, Empty, Empty>::default()
+            .assign_token_id(storage)?
+            .ok_or(Cw721ContractError::TokenIdRequired {}),
+    }
+}
+
+/// returns an error if minting `amount` more tokens would exceed the max supply
+pub fn check_max_supply(deps: Deps, amount: u64) -> Result<(), Cw721ContractError> {
+    let config = Cw721Config::<Empty, Empty, Empty, Empty>::default();
+    match config.max_supply.may_load(deps.storage)? {
+        Some(max_supply) if config.total_minted(deps.storage)? + amount > max_supply => {
+            Err(Cw721ContractError::MaxSupplyReached { max_supply })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// returns an error if the token has an active (non-expired) lock
+pub fn check_not_locked(deps: Deps, env: &Env, token_id: &str) -> Result<(), Cw721ContractError> {
+    let config = Cw721Config::<Empty, Empty, Empty, Empty>::default();
+    match config.token_locks.may_load(deps.storage, token_id)? {
+        Some(lock) if !lock.is_expired(&env.block) => Err(Cw721ContractError::TokenLocked {
+            token_id: token_id.to_string(),
+        }),
+        _ => Ok(()),
     }
 }
 
@@ -589,6 +2185,233 @@ pub fn migrate_version(
     Ok(response)
 }
 
+/// Starts backfilling the `operator_grants` reverse index from `operators`, in case it is not present yet.
+/// Up to `MAX_LIMIT` grants are migrated right away, the rest via `MigrateIndexes`.
+pub fn migrate_operator_grants(
+    storage: &mut dyn Storage,
+    response: Response,
+) -> StdResult<Response> {
+    let config = Cw721Config::<Empty, Empty, Empty, Empty>::default();
+    if config.operator_grants_backfill.may_load(storage)?.is_none() {
+        if config
+            .operator_grants
+            .keys(storage, None, None, Order::Ascending)
+            .next()
+            .is_some()
+        {
+            return Ok(response);
+        }
+        config.operator_grants_backfill.save(storage, &None)?;
+    }
+    let count = backfill_operator_grants(storage, MAX_LIMIT)?;
+    Ok(response.add_attribute("migrated_operator_grants", count.to_string()))
+}
+
+/// Migrates up to `limit` entries of a pending `operator_grants` backfill and
+/// returns their number. The backfill is removed once all operators are migrated.
+pub fn backfill_operator_grants(storage: &mut dyn Storage, limit: u32) -> StdResult<u32> {
+    let config = Cw721Config::<Empty, Empty, Empty, Empty>::default();
+    let start_after = match config.operator_grants_backfill.may_load(storage)? {
+        Some(start_after) if limit > 0 => start_after,
+        _ => return Ok(0),
+    };
+    let start = start_after
+        .as_ref()
+        .map(|(granter, operator)| Bound::ExclusiveRaw((granter, operator).joined_key()));
+    let operators = config
+        .operators
+        .range(storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    for ((granter, operator), expires) in &operators {
+        config
+            .operator_grants
+            .save(storage, (operator, granter), expires)?;
+    }
+    match operators.last() {
+        Some((last, _)) if operators.len() == limit as usize => config
+            .operator_grants_backfill
+            .save(storage, &Some(last.clone()))?,
+        _ => config.operator_grants_backfill.remove(storage),
+    }
+    Ok(operators.len() as u32)
+}
+
+/// Starts backfilling the `token_approvals` index from `NftInfo.approvals`, in case it is not present yet.
+/// Approvals of up to `MAX_LIMIT` tokens are migrated right away, the rest via `MigrateIndexes`.
+pub fn migrate_token_approvals<TMetadataExtension>(
+    storage: &mut dyn Storage,
+    response: Response,
+) -> StdResult<Response>
+where
+    TMetadataExtension: Serialize + DeserializeOwned + Clone,
+{
+    let config = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default();
+    if config.token_approvals_backfill.may_load(storage)?.is_none() {
+        if config
+            .token_approvals
+            .keys(storage, None, None, Order::Ascending)
+            .next()
+            .is_some()
+        {
+            return Ok(response);
+        }
+        config.token_approvals_backfill.save(storage, &None)?;
+    }
+    let count = backfill_token_approvals::<TMetadataExtension>(storage, MAX_LIMIT)?;
+    Ok(response.add_attribute("migrated_token_approvals", count.to_string()))
+}
+
+/// Migrates the approvals of up to `limit` tokens of a pending `token_approvals` backfill and
+/// returns the number of tokens. The backfill is removed once all tokens are migrated.
+pub fn backfill_token_approvals<TMetadataExtension>(
+    storage: &mut dyn Storage,
+    limit: u32,
+) -> StdResult<u32>
+where
+    TMetadataExtension: Serialize + DeserializeOwned + Clone,
+{
+    let config = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default();
+    let start_after = match config.token_approvals_backfill.may_load(storage)? {
+        Some(start_after) if limit > 0 => start_after,
+        _ => return Ok(0),
+    };
+    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+    let tokens = config
+        .nft_info
+        .range(storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| item.map(|(token_id, token)| (token_id, token.approvals)))
+        .collect::<StdResult<Vec<_>>>()?;
+    for (token_id, approvals) in &tokens {
+        for approval in approvals {
+            config.token_approvals.save(
+                storage,
+                (&approval.spender, token_id),
+                &approval.expires,
+            )?;
+        }
+    }
+    match tokens.last() {
+        Some((last, _)) if tokens.len() == limit as usize => config
+            .token_approvals_backfill
+            .save(storage, &Some(last.clone()))?,
+        _ => config.token_approvals_backfill.remove(storage),
+    }
+    Ok(tokens.len() as u32)
+}
+
+/// Starts backfilling `balances` by counting the tokens of each owner, in case they are not present yet.
+/// Up to `MAX_LIMIT` tokens are counted right away, the rest via `MigrateIndexes`.
+/// Until then, balances only change for tokens already counted, see `Cw721Config::balance_counted`.
+pub fn migrate_balances<TMetadataExtension>(
+    storage: &mut dyn Storage,
+    response: Response,
+) -> StdResult<Response>
+where
+    TMetadataExtension: Serialize + DeserializeOwned + Clone,
+{
+    let config = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default();
+    if config.balances_backfill.may_load(storage)?.is_none() {
+        if config
+            .balances
+            .keys(storage, None, None, Order::Ascending)
+            .next()
+            .is_some()
+        {
+            return Ok(response);
+        }
+        config.balances_backfill.save(storage, &None)?;
+    }
+    let count = backfill_balances::<TMetadataExtension>(storage, MAX_LIMIT)?;
+    Ok(response.add_attribute("migrated_balances", count.to_string()))
+}
+
+/// Counts up to `limit` tokens of a pending `balances` backfill and returns their number.
+/// The backfill is removed once all tokens are counted.
+pub fn backfill_balances<TMetadataExtension>(
+    storage: &mut dyn Storage,
+    limit: u32,
+) -> StdResult<u32>
+where
+    TMetadataExtension: Serialize + DeserializeOwned + Clone,
+{
+    let config = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default();
+    let start_after = match config.balances_backfill.may_load(storage)? {
+        Some(start_after) if limit > 0 => start_after,
+        _ => return Ok(0),
+    };
+    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+    let tokens = config
+        .nft_info
+        .range(storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| item.map(|(token_id, token)| (token_id, token.owner)))
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut balances: BTreeMap<&Addr, u64> = BTreeMap::new();
+    for (_, owner) in &tokens {
+        *balances.entry(owner).or_default() += 1;
+    }
+    for (owner, count) in balances {
+        let balance = config.balance(storage, owner)? + count;
+        config.balances.save(storage, owner, &balance)?;
+    }
+    match tokens.last() {
+        Some((last, _)) if tokens.len() == limit as usize => config
+            .balances_backfill
+            .save(storage, &Some(last.clone()))?,
+        _ => config.balances_backfill.remove(storage),
+    }
+    Ok(tokens.len() as u32)
+}
+
+/// Backfills `total_minted` in case it is not present yet, or sets it if given in the migrate message.
+pub fn migrate_total_minted(
+    storage: &mut dyn Storage,
+    msg: &Cw721MigrateMsg,
+    response: Response,
+) -> Result<Response, Cw721ContractError> {
+    let config = Cw721Config::<Empty, Empty, Empty, Empty>::default();
+    let num_tokens = config.token_count(storage)?;
+    let total_minted = match msg {
+        Cw721MigrateMsg::WithUpdate {
+            total_minted: Some(total_minted),
+            ..
+        } => {
+            if *total_minted < num_tokens {
+                return Err(Cw721ContractError::TotalMintedBelowTokens { num_tokens });
+            }
+            *total_minted
+        }
+        Cw721MigrateMsg::WithUpdate { .. } => {
+            if config.total_minted.may_load(storage)?.is_some() {
+                return Ok(response);
+            }
+            num_tokens
+        }
+    };
+    config.total_minted.save(storage, &total_minted)?;
+    Ok(response.add_attribute("total_minted", total_minted.to_string()))
+}
+
+pub fn migrate_creator(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    _env: &Env,
+    msg: &Cw721MigrateMsg,
+    response: Response,
+) -> StdResult<Response> {
+    match msg {
+        Cw721MigrateMsg::WithUpdate { creator, .. } => {
+            if let Some(creator) = creator {
+                CREATOR.initialize_owner(storage, api, Some(creator.as_str()))?;
+                return Ok(response.add_attribute("creator", creator));
+            }
+        }
+    }
+    Ok(response)
+}
+
 pub fn migrate_minter(
     storage: &mut dyn Storage,
     api: &dyn Api,
@@ -600,7 +2423,7 @@ pub fn migrate_minter(
         Cw721MigrateMsg::WithUpdate { minter, .. } => {
             if let Some(minter) = minter {
                 MINTER.initialize_owner(storage, api, Some(minter.as_str()))?;
-                return Ok(response.add_attribute("creator", minter));
+                return Ok(response.add_attribute("minter", minter));
             }
         }
     }
@@ -613,7 +2436,7 @@ pub fn migrate_minter(
 /// - minter can mint new tokens
 ///
 /// Before v0.19.0 there were confusing naming conventions:
-/// - v0.17.0: minter was replaced by cw_ownable, as a result minter is owner
+/// - v0.17.0/v0.18.0: minter was replaced by cw_ownable, as a result minter is owner
 /// - v0.16.0 and below: minter was stored in dedicated `minter` store (so NOT using cw_ownable at all)
 pub fn migrate_legacy_minter_and_creator(
     storage: &mut dyn Storage,
@@ -628,11 +2451,9 @@ pub fn migrate_legacy_minter_and_creator(
         return Ok(response);
     }
     // in v0.17/18 cw_ownable::OWNERSHIP was used for minter, now it is used for creator
-    let ownership_previously_used_as_minter = OwnershipStore::new("collection_minter")
-        .item
-        .may_load(storage)?;
+    let ownership_previously_used_as_minter = CREATOR.item.may_load(storage)?;
     let creator_and_minter = match ownership_previously_used_as_minter {
-        // v0.18 migration
+        // v0.17/18 migration
         Some(ownership) => {
             // owner is used for both: creator and minter
             // since it is already set for creator, we only need to migrate minter
@@ -640,11 +2461,12 @@ pub fn migrate_legacy_minter_and_creator(
             MINTER.initialize_owner(storage, api, owner.as_deref())?;
             owner
         }
-        // v0.17 and older migration
+        // v0.16 and older migration
         None => {
             let legacy_minter_store: Item<Addr> = Item::new("minter");
             let legacy_minter = legacy_minter_store.load(storage)?;
             MINTER.initialize_owner(storage, api, Some(legacy_minter.as_str()))?;
+            CREATOR.initialize_owner(storage, api, Some(legacy_minter.as_str()))?;
             Some(legacy_minter.to_string())
         }
     };
@@ -658,7 +2480,7 @@ pub fn migrate_legacy_collection_info(
     _msg: &Cw721MigrateMsg,
     response: Response,
 ) -> Result<Response, Cw721ContractError> {
-    let contract = Cw721Config::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+    let contract = Cw721Config::<DefaultOptionMetadataExtension, Empty, Empty, Empty>::default();
     match contract.collection_info.may_load(storage)? {
         Some(_) => Ok(response),
         None => {
@@ -1,8 +1,9 @@
 use std::marker::PhantomData;
 
 use crate::msg::{
-    AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, NftInfoResponse, NumTokensResponse,
-    OperatorsResponse, OwnerOfResponse, TokensResponse,
+    AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, ApprovedOwnersResponse,
+    CollectionInfoAndExtensionResponse, NftInfoResponse, NumTokensResponse, OperatorsResponse,
+    OwnerApproval, OwnerOfResponse, TokensResponse,
 };
 use crate::msg::{Cw721ExecuteMsg, Cw721QueryMsg};
 use crate::state::CollectionInfo;
@@ -15,17 +16,23 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 #[cw_serde]
-pub struct Cw721Contract<TMetadataExtension, TMetadataExtensionMsg: CustomMsg>(
+pub struct Cw721Contract<
+    TMetadataExtension,
+    TMetadataExtensionMsg: CustomMsg,
+    TCollectionInfoExtension,
+>(
     pub Addr,
     pub PhantomData<TMetadataExtension>,
     pub PhantomData<TMetadataExtensionMsg>,
+    pub PhantomData<TCollectionInfoExtension>,
 );
 
 #[allow(dead_code)]
-impl<TMetadataExtension, TMetadataExtensionMsg: CustomMsg>
-    Cw721Contract<TMetadataExtension, TMetadataExtensionMsg>
+impl<TMetadataExtension, TMetadataExtensionMsg: CustomMsg, TCollectionInfoExtension>
+    Cw721Contract<TMetadataExtension, TMetadataExtensionMsg, TCollectionInfoExtension>
 where
     TMetadataExtension: Serialize + DeserializeOwned + Clone,
+    TCollectionInfoExtension: Serialize + DeserializeOwned + Clone,
 {
     pub fn addr(&self) -> Addr {
         self.0.clone()
@@ -47,7 +54,7 @@ where
     pub fn query<T: DeserializeOwned>(
         &self,
         querier: &QuerierWrapper,
-        req: Cw721QueryMsg<TMetadataExtension>,
+        req: Cw721QueryMsg<TMetadataExtension, TCollectionInfoExtension>,
     ) -> StdResult<T> {
         let query = WasmQuery::Smart {
             contract_addr: self.addr().into(),
@@ -114,12 +121,32 @@ where
             owner: owner.into(),
             include_expired: Some(include_expired),
             start_after,
+            start_before: None,
             limit,
+            order: None,
         };
         let res: OperatorsResponse = self.query(querier, req)?;
         Ok(res.operators)
     }
 
+    pub fn approved_owners_for_operator<T: Into<String>>(
+        &self,
+        querier: &QuerierWrapper,
+        operator: T,
+        include_expired: bool,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<OwnerApproval>> {
+        let req = Cw721QueryMsg::ApprovedOwnersForOperator {
+            operator: operator.into(),
+            include_expired: Some(include_expired),
+            start_after,
+            limit,
+        };
+        let res: ApprovedOwnersResponse = self.query(querier, req)?;
+        Ok(res.owners)
+    }
+
     pub fn num_tokens(&self, querier: &QuerierWrapper) -> StdResult<u64> {
         let req = Cw721QueryMsg::NumTokens {};
         let res: NumTokensResponse = self.query(querier, req)?;
@@ -132,6 +159,15 @@ where
         self.query(querier, req)
     }
 
+    /// With collection info extension
+    pub fn collection_info_and_extension<U: DeserializeOwned>(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<CollectionInfoAndExtensionResponse<U>> {
+        let req = Cw721QueryMsg::GetCollectionInfo {};
+        self.query(querier, req)
+    }
+
     /// With metadata extension
     pub fn nft_info<T: Into<String>, U: DeserializeOwned>(
         &self,
@@ -169,7 +205,9 @@ where
         let req = Cw721QueryMsg::Tokens {
             owner: owner.into(),
             start_after,
+            start_before: None,
             limit,
+            order: None,
         };
         self.query(querier, req)
     }
@@ -181,7 +219,12 @@ where
         start_after: Option<String>,
         limit: Option<u32>,
     ) -> StdResult<TokensResponse> {
-        let req = Cw721QueryMsg::AllTokens { start_after, limit };
+        let req = Cw721QueryMsg::AllTokens {
+            start_after,
+            start_before: None,
+            limit,
+            order: None,
+        };
         self.query(querier, req)
     }
 
@@ -0,0 +1,93 @@
+use schemars::JsonSchema;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, CosmosMsg, Empty, Order, StdResult, Storage, SubMsg, WasmMsg,
+};
+
+use crate::state::Cw721Config;
+
+/// Reply id of hooks registered with `revert_on_error: false`.
+pub const HOOK_REPLY_ID: u64 = 721;
+
+/// Cw721HookMsg is sent to all registered hooks and should be de/serialized under `Cw721Hook()` variant in a ExecuteMsg
+#[cw_serde]
+pub enum Cw721HookMsg {
+    Mint {
+        token_id: String,
+        owner: String,
+    },
+    Transfer {
+        token_id: String,
+        sender: String,
+        recipient: String,
+    },
+    Send {
+        token_id: String,
+        sender: String,
+        contract: String,
+    },
+    Burn {
+        token_id: String,
+        sender: String,
+    },
+}
+
+impl Cw721HookMsg {
+    /// serializes the message
+    pub fn into_json_binary(self) -> StdResult<Binary> {
+        let msg = HookExecuteMsg::Cw721Hook(self);
+        to_json_binary(&msg)
+    }
+
+    /// creates a cosmos_msg sending this struct to the named contract
+    pub fn into_cosmos_msg<TAddress: Into<String>, TCustomResponseMessage>(
+        self,
+        contract_addr: TAddress,
+    ) -> StdResult<CosmosMsg<TCustomResponseMessage>>
+    where
+        TCustomResponseMessage: Clone + std::fmt::Debug + PartialEq + JsonSchema,
+    {
+        let msg = self.into_json_binary()?;
+        let execute = WasmMsg::Execute {
+            contract_addr: contract_addr.into(),
+            msg,
+            funds: vec![],
+        };
+        Ok(execute.into())
+    }
+}
+
+/// This is just a helper to properly serialize the above message.
+/// The actual hook contract should include this variant in the larger ExecuteMsg enum
+#[cw_serde]
+enum HookExecuteMsg {
+    Cw721Hook(Cw721HookMsg),
+}
+
+/// Creates one sub message per registered hook and hook message.
+/// A failing hook reverts the action, unless it was registered with `revert_on_error: false`.
+pub fn prepare_hooks<TCustomResponseMessage>(
+    storage: &dyn Storage,
+    msgs: Vec<Cw721HookMsg>,
+) -> StdResult<Vec<SubMsg<TCustomResponseMessage>>>
+where
+    TCustomResponseMessage: Clone + std::fmt::Debug + PartialEq + JsonSchema,
+{
+    let hooks: Vec<(Addr, bool)> = Cw721Config::<Empty, Empty, Empty, Empty>::default()
+        .hooks
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    let mut sub_msgs = Vec::with_capacity(hooks.len() * msgs.len());
+    for msg in msgs {
+        for (addr, revert_on_error) in &hooks {
+            let cosmos_msg = msg.clone().into_cosmos_msg(addr)?;
+            if *revert_on_error {
+                sub_msgs.push(SubMsg::new(cosmos_msg));
+            } else {
+                sub_msgs.push(SubMsg::reply_on_error(cosmos_msg, HOOK_REPLY_ID));
+            }
+        }
+    }
+    Ok(sub_msgs)
+}
@@ -1,6 +1,8 @@
 pub mod error;
+pub mod events;
 pub mod execute;
 pub mod helpers;
+pub mod hooks;
 pub mod msg;
 pub mod query;
 pub mod receiver;
@@ -1,14 +1,66 @@
+use std::fmt::Debug;
+
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary, Coin};
+use cosmwasm_std::{Addr, Api, Binary, Coin, CustomMsg, Decimal, Empty, Order, Timestamp, Uint128};
 use cw_ownable::{Action, Ownership};
 use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::Serialize;
 
-use crate::state::CollectionInfo;
+use crate::error::Cw721ContractError;
+use crate::execute::validate_royalty;
+use crate::receiver::ReceiveMsgVersion;
+use crate::state::{
+    CollectionInfo, CollectionInfoExtension, DefaultOptionCollectionInfoExtension,
+    DefaultOptionMetadataExtension, MetadataUpdateAuthority, NftInfo, PauseStatus,
+    SequentialTokenIds, TransferPolicy,
+};
 use crate::Approval;
 
 #[cw_serde]
 pub enum Cw721ExecuteMsg<TMetadataExtension, TMetadataExtensionMsg> {
     UpdateOwnership(Action),
+    UpdateCreatorOwnership(Action),
+    /// Updates collection name and/or symbol. Only the creator can call this.
+    /// Setting `freeze` permanently locks collection info against further updates.
+    UpdateCollectionInfo {
+        name: Option<String>,
+        symbol: Option<String>,
+        freeze: Option<bool>,
+    },
+    /// Pauses or resumes minting, transfers/sends and burns independently. Only the creator can call this.
+    /// Unset fields are left untouched.
+    UpdatePauseStatus {
+        minting: Option<bool>,
+        transfers: Option<bool>,
+        burns: Option<bool>,
+    },
+    /// Lowers the maximum number of tokens that can ever be minted. Only the creator can call this.
+    /// It can't be raised again, nor set below the number of already minted tokens.
+    UpdateMaxSupply {
+        max_supply: u64,
+    },
+    /// Sets the default royalty of all tokens, `None` removes it. Only the creator can call this.
+    /// Without it, `royalty_info` of the collection info extension applies.
+    SetCollectionRoyalty {
+        royalty: Option<RoyaltyInfoMsg>,
+    },
+    /// Sets the royalty of a single token, overriding the collection royalty.
+    /// `None` removes the override. Only the creator can call this.
+    SetTokenRoyalty {
+        token_id: String,
+        royalty: Option<RoyaltyInfoMsg>,
+    },
+    /// Registers a contract receiving a `Cw721HookMsg` on every mint, transfer, send and burn.
+    /// A failing hook reverts the action, unless `revert_on_error` is set to false. Only the creator can call this.
+    AddHook {
+        addr: String,
+        revert_on_error: Option<bool>,
+    },
+    /// Removes a registered hook. Only the creator can call this.
+    RemoveHook {
+        addr: String,
+    },
 
     /// Transfer is a base message to move a token to another account without triggering actions
     TransferNft {
@@ -17,10 +69,41 @@ pub enum Cw721ExecuteMsg<TMetadataExtension, TMetadataExtensionMsg> {
     },
     /// Send is a base message to transfer a token to a contract and trigger an action
     /// on the receiving contract.
+    /// With `receive_msg_version` set to `V2`, the receiver gets a `Cw721ReceiveMsgV2`
+    /// and all funds attached to this message are forwarded to it.
     SendNft {
         contract: String,
         token_id: String,
         msg: Binary,
+        receive_msg_version: Option<ReceiveMsgVersion>,
+    },
+    /// Transfers a token like `TransferNft`, but if `recipient` is a contract, it also
+    /// triggers `ReceiveNft` with `msg` (empty if not set) on it, like `SendNft`.
+    /// The whole transfer reverts if the receiving contract does not handle `ReceiveNft`.
+    /// `receive_msg_version` works the same as for `SendNft`.
+    /// Funds are rejected unless they are forwarded, i.e. to a contract with `V2`.
+    SafeTransferNft {
+        recipient: String,
+        token_id: String,
+        msg: Option<Binary>,
+        receive_msg_version: Option<ReceiveMsgVersion>,
+    },
+    /// Transfers multiple tokens to `recipient` at once.
+    /// Fails if any of the tokens cannot be transferred by the sender.
+    BatchTransferNft {
+        recipient: String,
+        token_ids: Vec<String>,
+    },
+    /// Sends multiple tokens to `contract` at once, triggering one `ReceiveNft`
+    /// per token on the receiving contract with the same `msg`.
+    /// With `receive_msg_version` set to `V2`, one `ReceiveNftV2` is triggered per token instead,
+    /// and all funds attached to this message are forwarded with the first one.
+    /// Fails if any of the tokens cannot be sent by the sender.
+    BatchSendNft {
+        contract: String,
+        token_ids: Vec<String>,
+        msg: Binary,
+        receive_msg_version: Option<ReceiveMsgVersion>,
     },
     /// Allows operator to transfer / send the token from the owner's account.
     /// If expiration is set, then this allowance has a time/height limit
@@ -44,11 +127,28 @@ pub enum Cw721ExecuteMsg<TMetadataExtension, TMetadataExtensionMsg> {
     RevokeAll {
         operator: String,
     },
+    /// Deletes expired approvals and operators. Anyone can call this.
+    /// If neither `token_ids` nor `owners` is set, all approvals, then all operators are scanned
+    /// in batches of `limit` entries. Otherwise up to `limit` expired entries of the given tokens,
+    /// then owners are removed. If not all entries are done, the response data holds the
+    /// `PruneCursor` to pass as `start_after` in the next call, together with the same tokens and owners.
+    PruneExpired {
+        /// tokens whose expired approvals are removed
+        token_ids: Option<Vec<String>>,
+        /// owners whose expired operators are removed
+        owners: Option<Vec<String>>,
+        /// continues a scan of all approvals and operators
+        start_after: Option<PruneCursor>,
+        /// max number of entries scanned, or removed for given tokens and owners,
+        /// defaults to 10, min 1, max 1000
+        limit: Option<u32>,
+    },
 
     /// Mint a new NFT, can only be called by the contract minter
     Mint {
-        /// Unique ID of the NFT
-        token_id: String,
+        /// Unique ID of the NFT.
+        /// May be omitted if sequential token ids are enabled, then the next id is assigned.
+        token_id: Option<String>,
         /// The owner of the newly minter NFT
         owner: String,
         /// Universal resource identifier for this NFT
@@ -59,12 +159,38 @@ pub enum Cw721ExecuteMsg<TMetadataExtension, TMetadataExtensionMsg> {
         extension: TMetadataExtension,
     },
 
+    /// Mint multiple NFTs at once, can only be called by the contract minter.
+    /// Fails if any of the token ids is already claimed.
+    /// Token ids may be omitted if sequential token ids are enabled, then they are assigned in order.
+    /// The minted token ids are returned in the response data.
+    BatchMint {
+        tokens: Vec<MintMsg<TMetadataExtension>>,
+    },
+
+    /// Locks an NFT, so it can't be transferred, sent, approved or burned until unlocked or expired.
+    /// Can be called by the owner or anyone approved to send the token.
+    /// Only the owner can lock it without expiration.
+    LockNft {
+        token_id: String,
+        expires: Option<Expiration>,
+    },
+    /// Unlocks a previously locked NFT. Only the account that locked it can call this,
+    /// even after its approval is revoked.
+    UnlockNft {
+        token_id: String,
+    },
+
     /// Burn an NFT the sender has access to
     Burn {
         token_id: String,
     },
+    /// Burn multiple NFTs at once. Fails if the sender has no access to any of them.
+    BatchBurn {
+        token_ids: Vec<String>,
+    },
 
-    /// Extension msg
+    /// Updates `token_uri` and onchain metadata of an NFT.
+    /// Only the configured `MetadataUpdateAuthority` can call this.
     Extension {
         msg: TMetadataExtensionMsg,
     },
@@ -80,10 +206,32 @@ pub enum Cw721ExecuteMsg<TMetadataExtension, TMetadataExtensionMsg> {
     WithdrawFunds {
         amount: Coin,
     },
+
+    /// Continues backfilling indexes left pending by `migrate`, in batches of `limit` entries,
+    /// defaults to 1000, max 1000. Call this until the `pending` attribute is false.
+    /// Only the creator can call this.
+    MigrateIndexes {
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct MintMsg<TMetadataExtension> {
+    /// Unique ID of the NFT.
+    /// May be omitted if sequential token ids are enabled, then the next id is assigned.
+    pub token_id: Option<String>,
+    /// The owner of the newly minted NFT
+    pub owner: String,
+    /// Universal resource identifier for this NFT
+    /// Should point to a JSON file that conforms to the ERC721
+    /// Metadata JSON Schema
+    pub token_uri: Option<String>,
+    /// Any custom extension used by this contract
+    pub extension: TMetadataExtension,
 }
 
 #[cw_serde]
-pub struct Cw721InstantiateMsg {
+pub struct Cw721InstantiateMsg<TCollectionInfoExtension> {
     /// Name of the NFT contract
     pub name: String,
     /// Symbol of the NFT contract
@@ -94,12 +242,35 @@ pub struct Cw721InstantiateMsg {
     /// or contract. You will likely replace this with custom logic in custom NFTs
     pub minter: Option<String>,
 
+    /// The creator owns the contract and can update collection info and metadata.
+    /// Defaults to the sender.
+    pub creator: Option<String>,
+
     pub withdraw_address: Option<String>,
+
+    /// Who is allowed to update token metadata, defaults to `MetadataUpdateAuthority::Creator`.
+    pub metadata_update_authority: Option<MetadataUpdateAuthority>,
+
+    /// Whether and by whom tokens can be transferred, defaults to `TransferPolicy::Transferable`.
+    pub transfer_policy: Option<TransferPolicy>,
+
+    /// Allows minting without a token id, ids are then assigned from a counter.
+    pub sequential_token_ids: Option<SequentialTokenIds>,
+
+    /// Maximum number of tokens that can ever be minted, burned tokens included. Unlimited if not set.
+    pub max_supply: Option<u64>,
+
+    /// Indexes `attributes` of onchain metadata for `TokensByTrait` and `TraitCounts`. Defaults to false.
+    pub index_traits: Option<bool>,
+
+    /// Optional collection-level metadata, e.g. description, image and royalty info.
+    /// See `IntoCollectionInfoExtension`, e.g. `DefaultOptionCollectionInfoExtensionMsg`.
+    pub collection_info_extension: TCollectionInfoExtension,
 }
 
 #[cw_serde]
 #[derive(QueryResponses)]
-pub enum Cw721QueryMsg<TMetadataExtension> {
+pub enum Cw721QueryMsg<TMetadataExtension, TCollectionInfoExtension> {
     /// Return the owner of the given token, error if token does not exist
     #[returns(OwnerOfResponse)]
     OwnerOf {
@@ -134,18 +305,54 @@ pub enum Cw721QueryMsg<TMetadataExtension> {
         /// unset or false will filter out expired items, you must set to true to see them
         include_expired: Option<bool>,
         start_after: Option<String>,
+        /// upper bound (exclusive), e.g. for loading the previous page
+        start_before: Option<String>,
+        limit: Option<u32>,
+        /// unset defaults to ascending
+        order: Option<OrderBy>,
+    },
+    /// List all token_ids the spender has been approved for, operator grants excluded
+    #[returns(TokensResponse)]
+    TokensApprovedFor {
+        spender: String,
+        /// unset or false will filter out expired approvals, you must set to true to see them
+        include_expired: Option<bool>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// List all owners that granted the operator access to all of their tokens
+    #[returns(ApprovedOwnersResponse)]
+    ApprovedOwnersForOperator {
+        operator: String,
+        /// unset or false will filter out expired items, you must set to true to see them
+        include_expired: Option<bool>,
+        start_after: Option<String>,
         limit: Option<u32>,
     },
     /// Total number of tokens issued
     #[returns(NumTokensResponse)]
     NumTokens {},
+    /// Number of tokens owned by the given address
+    #[returns(BalanceOfResponse)]
+    BalanceOf { owner: String },
 
     #[returns(CollectionInfo)]
     ContractInfo {},
 
+    /// Returns name, symbol and collection info extension of the contract.
+    #[returns(CollectionInfoAndExtensionResponse<TCollectionInfoExtension>)]
+    GetCollectionInfo {},
+
+    /// Deprecated: use GetMinterOwnership instead!
     #[returns(Ownership<Addr>)]
     Ownership {},
 
+    #[returns(Ownership<Addr>)]
+    GetMinterOwnership {},
+
+    #[returns(Ownership<Addr>)]
+    GetCreatorOwnership {},
+
     /// With MetaData Extension.
     /// Returns metadata about one particular token, based on *ERC721 Metadata JSON Schema*
     /// but directly from the contract
@@ -167,16 +374,44 @@ pub enum Cw721QueryMsg<TMetadataExtension> {
     Tokens {
         owner: String,
         start_after: Option<String>,
+        /// upper bound (exclusive), e.g. for loading the previous page
+        start_before: Option<String>,
         limit: Option<u32>,
+        /// unset defaults to ascending
+        order: Option<OrderBy>,
     },
     /// With Enumerable extension.
     /// Requires pagination. Lists all token_ids controlled by the contract.
     #[returns(TokensResponse)]
     AllTokens {
+        start_after: Option<String>,
+        /// upper bound (exclusive), e.g. for loading the previous page
+        start_before: Option<String>,
+        limit: Option<u32>,
+        /// unset defaults to ascending
+        order: Option<OrderBy>,
+    },
+    /// With Enumerable extension.
+    /// Like `Tokens`, but returns owner, token_uri and extension of each token as well.
+    #[returns(TokensWithInfoResponse<TMetadataExtension>)]
+    TokensWithInfo {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        /// unset or false will omit approvals, set to true to include non-expired approvals
+        include_approvals: Option<bool>,
+    },
+    /// With Enumerable extension.
+    /// Like `AllTokens`, but returns owner, token_uri and extension of each token as well.
+    #[returns(TokensWithInfoResponse<TMetadataExtension>)]
+    AllTokensWithInfo {
         start_after: Option<String>,
         limit: Option<u32>,
+        /// unset or false will omit approvals, set to true to include non-expired approvals
+        include_approvals: Option<bool>,
     },
 
+    /// Deprecated: use GetMinterOwnership instead!
     /// Return the minter
     #[returns(MinterResponse)]
     Minter {},
@@ -184,12 +419,192 @@ pub enum Cw721QueryMsg<TMetadataExtension> {
     #[returns(Option<String>)]
     GetWithdrawAddress {},
 
+    /// Returns which operations are currently paused
+    #[returns(PauseStatus)]
+    PauseStatus {},
+
+    /// Returns max supply, total minted and total burned tokens
+    #[returns(SupplyResponse)]
+    Supply {},
+
+    /// Requires pagination. Lists all registered hooks.
+    #[returns(HooksResponse)]
+    Hooks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the active lock of a token, or `None` if it is not locked
+    #[returns(Option<TokenLockResponse>)]
+    GetTokenLock { token_id: String },
+    /// Returns whether a token with the given id has been burned
+    #[returns(bool)]
+    IsBurned { token_id: String },
+    /// With Enumerable extension.
+    /// Requires pagination. Lists all burned tokens.
+    #[returns(BurnedTokensResponse)]
+    BurnedTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// With Enumerable extension.
+    /// Requires pagination. Lists all active token locks.
+    #[returns(TokenLocksResponse)]
+    AllTokenLocks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Should be called on sale to see if royalties are owed by the marketplace selling the NFT.
+    /// Returns the royalty of the token, falling back to the collection royalty.
+    /// See https://eips.ethereum.org/EIPS/eip-2981
+    #[returns(RoyaltiesInfoResponse)]
+    RoyaltyInfo {
+        token_id: String,
+        /// the denom of this sale must also be the denom returned by RoyaltiesInfoResponse
+        sale_price: Uint128,
+    },
+    /// Returns whether any royalties are set, so marketplaces know whether to check `RoyaltyInfo`
+    #[returns(CheckRoyaltiesResponse)]
+    CheckRoyalties {},
+
+    /// Requires pagination and the trait index. Lists all token_ids having the given attribute.
+    #[returns(TokensResponse)]
+    TokensByTrait {
+        trait_type: String,
+        value: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Requires pagination and the trait index. Lists the number of tokens per value
+    /// of the given trait type, e.g. for rarity tables.
+    #[returns(TraitCountsResponse)]
+    TraitCounts {
+        trait_type: String,
+        /// value to start after
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
     // -- below queries, Extension and GetCollectionInfoExtension, are just dummies, since type annotations are required for
     // -- TMetadataExtension and TCollectionInfoExtension, Error:
     // -- "type annotations needed: cannot infer type for type parameter `TMetadataExtension` declared on the enum `Cw721QueryMsg`"
     /// Do not use - dummy extension query, needed for inferring type parameter during compile
     #[returns(())]
     Extension { msg: TMetadataExtension },
+    /// Do not use - dummy collection info extension query, needed for inferring type parameter during compile
+    #[returns(())]
+    GetCollectionInfoExtension { msg: TCollectionInfoExtension },
+}
+
+/// Default `TMetadataExtensionMsg` for contracts using `DefaultOptionMetadataExtension`.
+pub type DefaultOptionMetadataExtensionMsg = UpdateNftInfoMsg<DefaultOptionMetadataExtension>;
+
+/// Updates `token_uri` and/or extension of an existing NFT.
+#[cw_serde]
+pub struct UpdateNftInfoMsg<TMetadataExtension> {
+    pub token_id: String,
+    /// Unset leaves `token_uri` unchanged, an empty string removes it.
+    pub token_uri: Option<String>,
+    /// Unset leaves extension unchanged, otherwise it is replaced.
+    pub extension: Option<TMetadataExtension>,
+}
+
+impl<TMetadataExtension> CustomMsg for UpdateNftInfoMsg<TMetadataExtension> where
+    TMetadataExtension: Serialize + Clone + Debug + PartialEq + JsonSchema
+{
+}
+
+/// Applies a `TMetadataExtensionMsg` to the `NftInfo` it targets.
+pub trait NftInfoUpdate<TMetadataExtension> {
+    /// Token to be updated, `None` if the message carries no update at all.
+    fn token_id(&self) -> Option<&str>;
+
+    /// Returns the updated `NftInfo`.
+    fn apply(self, nft_info: NftInfo<TMetadataExtension>) -> NftInfo<TMetadataExtension>;
+}
+
+impl<TMetadataExtension> NftInfoUpdate<TMetadataExtension> for Empty {
+    fn token_id(&self) -> Option<&str> {
+        None
+    }
+
+    fn apply(self, nft_info: NftInfo<TMetadataExtension>) -> NftInfo<TMetadataExtension> {
+        nft_info
+    }
+}
+
+impl<TMetadataExtension> NftInfoUpdate<TMetadataExtension>
+    for UpdateNftInfoMsg<TMetadataExtension>
+{
+    fn token_id(&self) -> Option<&str> {
+        Some(&self.token_id)
+    }
+
+    fn apply(self, mut nft_info: NftInfo<TMetadataExtension>) -> NftInfo<TMetadataExtension> {
+        if let Some(token_uri) = self.token_uri {
+            nft_info.token_uri = if token_uri.is_empty() {
+                None
+            } else {
+                Some(token_uri)
+            };
+        }
+        if let Some(extension) = self.extension {
+            nft_info.extension = extension;
+        }
+        nft_info
+    }
+}
+
+/// Position of a `PruneExpired` scan, which reads all approvals first, then all operators.
+#[cw_serde]
+pub enum PruneCursor {
+    /// last approval read
+    Approval { spender: String, token_id: String },
+    /// last operator read
+    Operator { owner: String, operator: String },
+}
+
+/// Default `collection_info_extension` of `Cw721InstantiateMsg`, with an unvalidated royalty.
+pub type DefaultOptionCollectionInfoExtensionMsg = Option<CollectionInfoExtension<RoyaltyInfoMsg>>;
+
+/// Validates a `collection_info_extension` of `Cw721InstantiateMsg` before it is stored.
+pub trait IntoCollectionInfoExtension<TCollectionInfoExtension> {
+    fn into_collection_info_extension(
+        self,
+        api: &dyn Api,
+    ) -> Result<TCollectionInfoExtension, Cw721ContractError>;
+}
+
+impl IntoCollectionInfoExtension<Empty> for Empty {
+    fn into_collection_info_extension(self, _api: &dyn Api) -> Result<Empty, Cw721ContractError> {
+        Ok(self)
+    }
+}
+
+impl IntoCollectionInfoExtension<DefaultOptionCollectionInfoExtension>
+    for DefaultOptionCollectionInfoExtensionMsg
+{
+    fn into_collection_info_extension(
+        self,
+        api: &dyn Api,
+    ) -> Result<DefaultOptionCollectionInfoExtension, Cw721ContractError> {
+        self.map(|extension| {
+            Ok(CollectionInfoExtension {
+                description: extension.description,
+                image: extension.image,
+                external_link: extension.external_link,
+                explicit_content: extension.explicit_content,
+                start_trading_time: extension.start_trading_time,
+                royalty_info: extension
+                    .royalty_info
+                    .map(|royalty| validate_royalty(api, royalty))
+                    .transpose()?,
+            })
+        })
+        .transpose()
+    }
 }
 
 #[cw_serde]
@@ -197,6 +612,10 @@ pub enum Cw721MigrateMsg {
     WithUpdate {
         minter: Option<String>,
         creator: Option<String>,
+        /// Number of tokens ever minted, burned tokens included, for collections minted before
+        /// it was tracked. Defaults to the number of existing tokens, so earlier burns are not
+        /// counted by max supply and `Supply`.
+        total_minted: Option<u64>,
     },
 }
 
@@ -206,6 +625,13 @@ pub struct CollectionInfoMsg {
     pub symbol: String,
 }
 
+#[cw_serde]
+pub struct CollectionInfoAndExtensionResponse<TCollectionInfoExtension> {
+    pub name: String,
+    pub symbol: String,
+    pub extension: TCollectionInfoExtension,
+}
+
 #[cw_serde]
 pub struct OwnerOfResponse {
     /// Owner of the token
@@ -234,11 +660,29 @@ pub struct OperatorsResponse {
     pub operators: Vec<Approval>,
 }
 
+#[cw_serde]
+pub struct OwnerApproval {
+    /// Account that granted the operator control over all of its tokens
+    pub owner: Addr,
+    /// When the grant expires (maybe Expiration::never)
+    pub expires: Expiration,
+}
+
+#[cw_serde]
+pub struct ApprovedOwnersResponse {
+    pub owners: Vec<OwnerApproval>,
+}
+
 #[cw_serde]
 pub struct NumTokensResponse {
     pub count: u64,
 }
 
+#[cw_serde]
+pub struct BalanceOfResponse {
+    pub balance: u64,
+}
+
 #[cw_serde]
 pub struct NftInfoResponse<TMetadataExtension> {
     /// Universal resource identifier for this NFT
@@ -265,6 +709,116 @@ pub struct TokensResponse {
     pub tokens: Vec<String>,
 }
 
+#[cw_serde]
+pub struct SupplyResponse {
+    /// `None` if supply is unlimited
+    pub max_supply: Option<u64>,
+    pub total_minted: u64,
+    pub total_burned: u64,
+}
+
+/// Order of paginated queries
+#[cw_serde]
+pub enum OrderBy {
+    Ascending,
+    Descending,
+}
+
+impl From<OrderBy> for Order {
+    fn from(order: OrderBy) -> Self {
+        match order {
+            OrderBy::Ascending => Order::Ascending,
+            OrderBy::Descending => Order::Descending,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct TokenWithInfo<TMetadataExtension> {
+    pub token_id: String,
+    pub owner: String,
+    pub token_uri: Option<String>,
+    pub extension: TMetadataExtension,
+    /// Only set if approvals are requested
+    pub approvals: Option<Vec<Approval>>,
+}
+
+#[cw_serde]
+pub struct TokensWithInfoResponse<TMetadataExtension> {
+    pub tokens: Vec<TokenWithInfo<TMetadataExtension>>,
+}
+
+#[cw_serde]
+pub struct BurnedTokenResponse {
+    pub token_id: String,
+    pub burner: Addr,
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+#[cw_serde]
+pub struct BurnedTokensResponse {
+    pub tokens: Vec<BurnedTokenResponse>,
+}
+
+#[cw_serde]
+pub struct RoyaltyInfoMsg {
+    pub payment_address: String,
+    /// Share of sales, e.g. `0.05` for 5%
+    pub share: Decimal,
+}
+
+#[cw_serde]
+pub struct RoyaltiesInfoResponse {
+    pub address: String,
+    // Note that this must be the same denom as that passed in to RoyaltyInfo
+    // rounding up or down is at the discretion of the implementer
+    pub royalty_amount: Uint128,
+}
+
+/// Shows if the contract implements royalties
+/// if royalty_payments is true, marketplaces should pay them
+#[cw_serde]
+pub struct CheckRoyaltiesResponse {
+    pub royalty_payments: bool,
+}
+
+#[cw_serde]
+pub struct TraitCount {
+    pub trait_type: String,
+    pub value: String,
+    /// Number of tokens having this attribute
+    pub count: u64,
+}
+
+#[cw_serde]
+pub struct TraitCountsResponse {
+    pub traits: Vec<TraitCount>,
+}
+
+#[cw_serde]
+pub struct HookInfo {
+    pub addr: Addr,
+    pub revert_on_error: bool,
+}
+
+#[cw_serde]
+pub struct HooksResponse {
+    pub hooks: Vec<HookInfo>,
+}
+
+#[cw_serde]
+pub struct TokenLockResponse {
+    pub token_id: String,
+    pub locker: Addr,
+    pub expires: Expiration,
+}
+
+#[cw_serde]
+pub struct TokenLocksResponse {
+    pub locks: Vec<TokenLockResponse>,
+}
+
 /// Deprecated: use Cw721QueryMsg::GetMinterOwnership instead!
 /// Shows who can mint these tokens.
 #[cw_serde]
@@ -1,5 +1,6 @@
 use cosmwasm_std::{
     to_json_binary, Addr, Binary, BlockInfo, Deps, Empty, Env, Order, StdError, StdResult, Storage,
+    Uint128,
 };
 use cw_ownable::Ownership;
 use cw_storage_plus::Bound;
@@ -9,11 +10,17 @@ use serde::Serialize;
 
 use crate::{
     msg::{
-        AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, Cw721QueryMsg, MinterResponse,
-        NftInfoResponse, NumTokensResponse, OperatorResponse, OperatorsResponse, OwnerOfResponse,
-        TokensResponse,
+        AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, ApprovedOwnersResponse,
+        BalanceOfResponse, BurnedTokenResponse, BurnedTokensResponse, CheckRoyaltiesResponse,
+        CollectionInfoAndExtensionResponse, Cw721QueryMsg, HookInfo, HooksResponse, MinterResponse,
+        NftInfoResponse, NumTokensResponse, OperatorResponse, OperatorsResponse, OrderBy,
+        OwnerApproval, OwnerOfResponse, RoyaltiesInfoResponse, SupplyResponse, TokenLockResponse,
+        TokenLocksResponse, TokenWithInfo, TokensResponse, TokensWithInfoResponse, TraitCount,
+        TraitCountsResponse,
+    },
+    state::{
+        Approval, CollectionInfo, Cw721Config, NftInfo, PauseStatus, RoyaltySource, CREATOR, MINTER,
     },
-    state::{Approval, CollectionInfo, Cw721Config, NftInfo, MINTER},
 };
 
 pub const DEFAULT_LIMIT: u32 = 10;
@@ -22,20 +29,26 @@ pub const MAX_LIMIT: u32 = 1000;
 pub trait Cw721Query<
     // Metadata defined in NftInfo.
     TMetadataExtension,
+    // Extension defined in CollectionInfo.
+    TCollectionInfoExtension,
 > where
     TMetadataExtension: Serialize + DeserializeOwned + Clone,
+    TCollectionInfoExtension: Serialize + DeserializeOwned + Clone + Default + RoyaltySource,
 {
     fn query(
         &self,
         deps: Deps,
         env: Env,
-        msg: Cw721QueryMsg<TMetadataExtension>,
+        msg: Cw721QueryMsg<TMetadataExtension, TCollectionInfoExtension>,
     ) -> StdResult<Binary> {
         match msg {
             Cw721QueryMsg::Minter {} => to_json_binary(&self.query_minter(deps.storage)?),
             Cw721QueryMsg::ContractInfo {} => {
                 to_json_binary(&self.query_collection_info(deps, env)?)
             }
+            Cw721QueryMsg::GetCollectionInfo {} => {
+                to_json_binary(&self.query_collection_info_and_extension(deps, env)?)
+            }
             Cw721QueryMsg::NftInfo { token_id } => {
                 to_json_binary(&self.query_nft_info(deps, env, token_id)?)
             }
@@ -72,24 +85,101 @@ pub trait Cw721Query<
                 owner,
                 include_expired,
                 start_after,
+                start_before,
                 limit,
+                order,
             } => to_json_binary(&self.query_operators(
                 deps,
                 env,
                 owner,
                 include_expired.unwrap_or(false),
                 start_after,
+                start_before,
+                limit,
+                order,
+            )?),
+            Cw721QueryMsg::TokensApprovedFor {
+                spender,
+                include_expired,
+                start_after,
+                limit,
+            } => to_json_binary(&self.query_tokens_approved_for(
+                deps,
+                env,
+                spender,
+                include_expired.unwrap_or(false),
+                start_after,
+                limit,
+            )?),
+            Cw721QueryMsg::ApprovedOwnersForOperator {
+                operator,
+                include_expired,
+                start_after,
+                limit,
+            } => to_json_binary(&self.query_approved_owners_for_operator(
+                deps,
+                env,
+                operator,
+                include_expired.unwrap_or(false),
+                start_after,
                 limit,
             )?),
             Cw721QueryMsg::NumTokens {} => to_json_binary(&self.query_num_tokens(deps, env)?),
+            Cw721QueryMsg::BalanceOf { owner } => {
+                to_json_binary(&self.query_balance_of(deps, env, owner)?)
+            }
             Cw721QueryMsg::Tokens {
                 owner,
                 start_after,
+                start_before,
                 limit,
-            } => to_json_binary(&self.query_tokens(deps, env, owner, start_after, limit)?),
-            Cw721QueryMsg::AllTokens { start_after, limit } => {
-                to_json_binary(&self.query_all_tokens(deps, env, start_after, limit)?)
-            }
+                order,
+            } => to_json_binary(&self.query_tokens(
+                deps,
+                env,
+                owner,
+                start_after,
+                start_before,
+                limit,
+                order,
+            )?),
+            Cw721QueryMsg::AllTokens {
+                start_after,
+                start_before,
+                limit,
+                order,
+            } => to_json_binary(&self.query_all_tokens(
+                deps,
+                env,
+                start_after,
+                start_before,
+                limit,
+                order,
+            )?),
+            Cw721QueryMsg::TokensWithInfo {
+                owner,
+                start_after,
+                limit,
+                include_approvals,
+            } => to_json_binary(&self.query_tokens_with_info(
+                deps,
+                env,
+                owner,
+                start_after,
+                limit,
+                include_approvals.unwrap_or(false),
+            )?),
+            Cw721QueryMsg::AllTokensWithInfo {
+                start_after,
+                limit,
+                include_approvals,
+            } => to_json_binary(&self.query_all_tokens_with_info(
+                deps,
+                env,
+                start_after,
+                limit,
+                include_approvals.unwrap_or(false),
+            )?),
             Cw721QueryMsg::Approval {
                 token_id,
                 spender,
@@ -113,12 +203,61 @@ pub trait Cw721Query<
             Cw721QueryMsg::Ownership {} => {
                 to_json_binary(&self.query_minter_ownership(deps.storage)?)
             }
+            Cw721QueryMsg::GetMinterOwnership {} => {
+                to_json_binary(&self.query_minter_ownership(deps.storage)?)
+            }
+            Cw721QueryMsg::GetCreatorOwnership {} => {
+                to_json_binary(&self.query_creator_ownership(deps.storage)?)
+            }
             Cw721QueryMsg::Extension { msg } => {
                 to_json_binary(&self.query_extension(deps, env, msg)?)
             }
+            Cw721QueryMsg::GetCollectionInfoExtension { msg } => {
+                to_json_binary(&self.query_collection_info_extension(deps, env, msg)?)
+            }
             Cw721QueryMsg::GetWithdrawAddress {} => {
                 to_json_binary(&self.query_withdraw_address(deps)?)
             }
+            Cw721QueryMsg::PauseStatus {} => to_json_binary(&self.query_pause_status(deps)?),
+            Cw721QueryMsg::Supply {} => to_json_binary(&self.query_supply(deps)?),
+            Cw721QueryMsg::Hooks { start_after, limit } => {
+                to_json_binary(&self.query_hooks(deps, start_after, limit)?)
+            }
+            Cw721QueryMsg::GetTokenLock { token_id } => {
+                to_json_binary(&self.query_token_lock(deps, env, token_id)?)
+            }
+            Cw721QueryMsg::IsBurned { token_id } => {
+                to_json_binary(&self.query_is_burned(deps, token_id)?)
+            }
+            Cw721QueryMsg::BurnedTokens { start_after, limit } => {
+                to_json_binary(&self.query_burned_tokens(deps, start_after, limit)?)
+            }
+            Cw721QueryMsg::AllTokenLocks { start_after, limit } => {
+                to_json_binary(&self.query_all_token_locks(deps, env, start_after, limit)?)
+            }
+            Cw721QueryMsg::RoyaltyInfo {
+                token_id,
+                sale_price,
+            } => to_json_binary(&self.query_royalty_info(deps, env, token_id, sale_price)?),
+            Cw721QueryMsg::CheckRoyalties {} => to_json_binary(&self.query_check_royalties(deps)?),
+            Cw721QueryMsg::TokensByTrait {
+                trait_type,
+                value,
+                start_after,
+                limit,
+            } => to_json_binary(&self.query_tokens_by_trait(
+                deps,
+                env,
+                trait_type,
+                value,
+                start_after,
+                limit,
+            )?),
+            Cw721QueryMsg::TraitCounts {
+                trait_type,
+                start_after,
+                limit,
+            } => to_json_binary(&self.query_trait_counts(deps, trait_type, start_after, limit)?),
         }
     }
 
@@ -135,25 +274,61 @@ pub trait Cw721Query<
         MINTER.get_ownership(storage)
     }
 
+    fn query_creator_ownership(&self, storage: &dyn Storage) -> StdResult<Ownership<Addr>> {
+        CREATOR.get_ownership(storage)
+    }
+
     fn query_collection_info(&self, deps: Deps, _env: Env) -> StdResult<CollectionInfo> {
-        Cw721Config::<TMetadataExtension, Empty, Empty>::default()
+        Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
             .collection_info
             .load(deps.storage)
     }
 
+    fn query_collection_info_and_extension(
+        &self,
+        deps: Deps,
+        _env: Env,
+    ) -> StdResult<CollectionInfoAndExtensionResponse<TCollectionInfoExtension>> {
+        let config =
+            Cw721Config::<TMetadataExtension, Empty, Empty, TCollectionInfoExtension>::default();
+        let collection_info = config.collection_info.load(deps.storage)?;
+        // not set for contracts migrated from older versions
+        let extension = config
+            .collection_info_extension
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+        Ok(CollectionInfoAndExtensionResponse {
+            name: collection_info.name,
+            symbol: collection_info.symbol,
+            extension,
+        })
+    }
+
     fn query_num_tokens(&self, deps: Deps, _env: Env) -> StdResult<NumTokensResponse> {
-        let count =
-            Cw721Config::<TMetadataExtension, Empty, Empty>::default().token_count(deps.storage)?;
+        let count = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+            .token_count(deps.storage)?;
         Ok(NumTokensResponse { count })
     }
 
+    fn query_balance_of(
+        &self,
+        deps: Deps,
+        _env: Env,
+        owner: String,
+    ) -> StdResult<BalanceOfResponse> {
+        let owner_addr = deps.api.addr_validate(&owner)?;
+        let balance = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+            .balance(deps.storage, &owner_addr)?;
+        Ok(BalanceOfResponse { balance })
+    }
+
     fn query_nft_info(
         &self,
         deps: Deps,
         _env: Env,
         token_id: String,
     ) -> StdResult<NftInfoResponse<TMetadataExtension>> {
-        let info = Cw721Config::<TMetadataExtension, Empty, Empty>::default()
+        let info = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
             .nft_info
             .load(deps.storage, &token_id)?;
         Ok(NftInfoResponse {
@@ -169,7 +344,7 @@ pub trait Cw721Query<
         token_id: String,
         include_expired_approval: bool,
     ) -> StdResult<OwnerOfResponse> {
-        let nft_info = Cw721Config::<TMetadataExtension, Empty, Empty>::default()
+        let nft_info = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
             .nft_info
             .load(deps.storage, &token_id)?;
         Ok(OwnerOfResponse {
@@ -190,7 +365,7 @@ pub trait Cw721Query<
         let owner_addr = deps.api.addr_validate(&owner)?;
         let operator_addr = deps.api.addr_validate(&operator)?;
 
-        let info = Cw721Config::<TMetadataExtension, Empty, Empty>::default()
+        let info = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
             .operators
             .may_load(deps.storage, (&owner_addr, &operator_addr))?;
 
@@ -211,6 +386,7 @@ pub trait Cw721Query<
     }
 
     /// operators returns all operators owner given access to
+    #[allow(clippy::too_many_arguments)]
     fn query_operators(
         &self,
         deps: Deps,
@@ -218,28 +394,95 @@ pub trait Cw721Query<
         owner: String,
         include_expired_approval: bool,
         start_after: Option<String>,
+        start_before: Option<String>,
         limit: Option<u32>,
+        order: Option<OrderBy>,
     ) -> StdResult<OperatorsResponse> {
         let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
         let start_addr = maybe_addr(deps.api, start_after)?;
         let start = start_addr.as_ref().map(Bound::exclusive);
+        let end_addr = maybe_addr(deps.api, start_before)?;
+        let end = end_addr.as_ref().map(Bound::exclusive);
+        let order = order.map(Order::from).unwrap_or(Order::Ascending);
 
         let owner_addr = deps.api.addr_validate(&owner)?;
-        let res: StdResult<Vec<_>> = Cw721Config::<TMetadataExtension, Empty, Empty>::default()
-            .operators
-            .prefix(&owner_addr)
-            .range(deps.storage, start, None, Order::Ascending)
-            .filter(|r| {
-                include_expired_approval
-                    || r.is_err()
-                    || !r.as_ref().unwrap().1.is_expired(&env.block)
-            })
-            .take(limit)
-            .map(parse_approval)
-            .collect();
+        let res: StdResult<Vec<_>> =
+            Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+                .operators
+                .prefix(&owner_addr)
+                .range(deps.storage, start, end, order)
+                .filter(|r| {
+                    include_expired_approval
+                        || r.is_err()
+                        || !r.as_ref().unwrap().1.is_expired(&env.block)
+                })
+                .take(limit)
+                .map(parse_approval)
+                .collect();
         Ok(OperatorsResponse { operators: res? })
     }
 
+    /// tokens the spender has been approved for via `Approve`
+    fn query_tokens_approved_for(
+        &self,
+        deps: Deps,
+        env: Env,
+        spender: String,
+        include_expired_approval: bool,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<TokensResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+        let spender_addr = deps.api.addr_validate(&spender)?;
+        let tokens: StdResult<Vec<_>> =
+            Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+                .token_approvals
+                .prefix(&spender_addr)
+                .range(deps.storage, start, None, Order::Ascending)
+                .filter(|r| {
+                    include_expired_approval
+                        || r.is_err()
+                        || !r.as_ref().unwrap().1.is_expired(&env.block)
+                })
+                .take(limit)
+                .map(|item| item.map(|(token_id, _)| token_id))
+                .collect();
+        Ok(TokensResponse { tokens: tokens? })
+    }
+
+    /// owners that have given the operator access to all of their tokens
+    fn query_approved_owners_for_operator(
+        &self,
+        deps: Deps,
+        env: Env,
+        operator: String,
+        include_expired_approval: bool,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<ApprovedOwnersResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start_addr = maybe_addr(deps.api, start_after)?;
+        let start = start_addr.as_ref().map(Bound::exclusive);
+
+        let operator_addr = deps.api.addr_validate(&operator)?;
+        let owners: StdResult<Vec<_>> =
+            Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+                .operator_grants
+                .prefix(&operator_addr)
+                .range(deps.storage, start, None, Order::Ascending)
+                .filter(|r| {
+                    include_expired_approval
+                        || r.is_err()
+                        || !r.as_ref().unwrap().1.is_expired(&env.block)
+                })
+                .take(limit)
+                .map(|item| item.map(|(owner, expires)| OwnerApproval { owner, expires }))
+                .collect();
+        Ok(ApprovedOwnersResponse { owners: owners? })
+    }
+
     fn query_approval(
         &self,
         deps: Deps,
@@ -248,7 +491,7 @@ pub trait Cw721Query<
         spender: String,
         include_expired_approval: bool,
     ) -> StdResult<ApprovalResponse> {
-        let token = Cw721Config::<TMetadataExtension, Empty, Empty>::default()
+        let token = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
             .nft_info
             .load(deps.storage, &token_id)?;
 
@@ -289,7 +532,7 @@ pub trait Cw721Query<
         token_id: String,
         include_expired_approval: bool,
     ) -> StdResult<ApprovalsResponse> {
-        let token = Cw721Config::<TMetadataExtension, Empty, Empty>::default()
+        let token = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
             .nft_info
             .load(deps.storage, &token_id)?;
         let approvals: Vec<_> = token
@@ -305,24 +548,29 @@ pub trait Cw721Query<
         Ok(ApprovalsResponse { approvals })
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn query_tokens(
         &self,
         deps: Deps,
         _env: Env,
         owner: String,
         start_after: Option<String>,
+        start_before: Option<String>,
         limit: Option<u32>,
+        order: Option<OrderBy>,
     ) -> StdResult<TokensResponse> {
         let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
         let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+        let end = start_before.map(|s| Bound::ExclusiveRaw(s.into()));
+        let order = order.map(Order::from).unwrap_or(Order::Ascending);
 
         let owner_addr = deps.api.addr_validate(&owner)?;
-        let tokens: Vec<String> = Cw721Config::<TMetadataExtension, Empty, Empty>::default()
+        let tokens: Vec<String> = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
             .nft_info
             .idx
             .owner
             .prefix(owner_addr)
-            .keys(deps.storage, start, None, Order::Ascending)
+            .keys(deps.storage, start, end, order)
             .take(limit)
             .collect::<StdResult<Vec<_>>>()?;
 
@@ -334,15 +582,19 @@ pub trait Cw721Query<
         deps: Deps,
         _env: Env,
         start_after: Option<String>,
+        start_before: Option<String>,
         limit: Option<u32>,
+        order: Option<OrderBy>,
     ) -> StdResult<TokensResponse> {
         let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
         let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+        let end = start_before.map(|s| Bound::ExclusiveRaw(s.into()));
+        let order = order.map(Order::from).unwrap_or(Order::Ascending);
 
         let tokens: StdResult<Vec<String>> =
-            Cw721Config::<TMetadataExtension, Empty, Empty>::default()
+            Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
                 .nft_info
-                .range(deps.storage, start, None, Order::Ascending)
+                .range(deps.storage, start, end, order)
                 .take(limit)
                 .map(|item| item.map(|(k, _)| k))
                 .collect();
@@ -350,6 +602,273 @@ pub trait Cw721Query<
         Ok(TokensResponse { tokens: tokens? })
     }
 
+    fn query_tokens_with_info(
+        &self,
+        deps: Deps,
+        env: Env,
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        include_approvals: bool,
+    ) -> StdResult<TokensWithInfoResponse<TMetadataExtension>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+        let owner_addr = deps.api.addr_validate(&owner)?;
+        let tokens: StdResult<Vec<_>> =
+            Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+                .nft_info
+                .idx
+                .owner
+                .prefix(owner_addr)
+                .range(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|item| {
+                    item.map(|(token_id, nft_info)| {
+                        token_with_info(&env.block, token_id, nft_info, include_approvals)
+                    })
+                })
+                .collect();
+
+        Ok(TokensWithInfoResponse { tokens: tokens? })
+    }
+
+    fn query_all_tokens_with_info(
+        &self,
+        deps: Deps,
+        env: Env,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        include_approvals: bool,
+    ) -> StdResult<TokensWithInfoResponse<TMetadataExtension>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+        let tokens: StdResult<Vec<_>> =
+            Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+                .nft_info
+                .range(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|item| {
+                    item.map(|(token_id, nft_info)| {
+                        token_with_info(&env.block, token_id, nft_info, include_approvals)
+                    })
+                })
+                .collect();
+
+        Ok(TokensWithInfoResponse { tokens: tokens? })
+    }
+
+    fn query_pause_status(&self, deps: Deps) -> StdResult<PauseStatus> {
+        Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default().pause_status(deps.storage)
+    }
+
+    fn query_supply(&self, deps: Deps) -> StdResult<SupplyResponse> {
+        let config = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default();
+        let total_minted = config.total_minted(deps.storage)?;
+        let total_burned = total_minted.saturating_sub(config.token_count(deps.storage)?);
+        Ok(SupplyResponse {
+            max_supply: config.max_supply.may_load(deps.storage)?,
+            total_minted,
+            total_burned,
+        })
+    }
+
+    fn query_hooks(
+        &self,
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<HooksResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start_addr = maybe_addr(deps.api, start_after)?;
+        let start = start_addr.as_ref().map(Bound::exclusive);
+
+        let hooks: StdResult<Vec<_>> =
+            Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+                .hooks
+                .range(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|item| {
+                    item.map(|(addr, revert_on_error)| HookInfo {
+                        addr,
+                        revert_on_error,
+                    })
+                })
+                .collect();
+        Ok(HooksResponse { hooks: hooks? })
+    }
+
+    fn query_token_lock(
+        &self,
+        deps: Deps,
+        env: Env,
+        token_id: String,
+    ) -> StdResult<Option<TokenLockResponse>> {
+        let lock = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+            .token_locks
+            .may_load(deps.storage, &token_id)?;
+        Ok(lock
+            .filter(|lock| !lock.is_expired(&env.block))
+            .map(|lock| TokenLockResponse {
+                token_id,
+                locker: lock.locker,
+                expires: lock.expires,
+            }))
+    }
+
+    fn query_all_token_locks(
+        &self,
+        deps: Deps,
+        env: Env,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<TokenLocksResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+        let locks: StdResult<Vec<_>> =
+            Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+                .token_locks
+                .range(deps.storage, start, None, Order::Ascending)
+                .filter(|r| r.is_err() || !r.as_ref().unwrap().1.is_expired(&env.block))
+                .take(limit)
+                .map(|item| {
+                    item.map(|(token_id, lock)| TokenLockResponse {
+                        token_id,
+                        locker: lock.locker,
+                        expires: lock.expires,
+                    })
+                })
+                .collect();
+        Ok(TokenLocksResponse { locks: locks? })
+    }
+
+    /// Royalty of the token, falling back to the collection royalty and then to `royalty_info`
+    /// of the collection info extension.
+    /// NOTE: rounds down, EIP2981 specifies that the rounding behaviour is at the discretion of the implementer
+    fn query_royalty_info(
+        &self,
+        deps: Deps,
+        _env: Env,
+        token_id: String,
+        sale_price: Uint128,
+    ) -> StdResult<RoyaltiesInfoResponse> {
+        let config =
+            Cw721Config::<TMetadataExtension, Empty, Empty, TCollectionInfoExtension>::default();
+        // fails if token does not exist
+        config.nft_info.load(deps.storage, &token_id)?;
+        Ok(match config.royalty(deps.storage, &token_id)? {
+            Some(royalty) => RoyaltiesInfoResponse {
+                address: royalty.payment_address.to_string(),
+                royalty_amount: sale_price * royalty.share,
+            },
+            None => RoyaltiesInfoResponse {
+                address: String::new(),
+                royalty_amount: Uint128::zero(),
+            },
+        })
+    }
+
+    /// True if a collection royalty (including `royalty_info` of the collection info extension)
+    /// or any token royalty is set.
+    fn query_check_royalties(&self, deps: Deps) -> StdResult<CheckRoyaltiesResponse> {
+        let config =
+            Cw721Config::<TMetadataExtension, Empty, Empty, TCollectionInfoExtension>::default();
+        let royalty_payments = config.default_royalty(deps.storage)?.is_some()
+            || config
+                .token_royalties
+                .keys(deps.storage, None, None, Order::Ascending)
+                .next()
+                .is_some();
+        Ok(CheckRoyaltiesResponse { royalty_payments })
+    }
+
+    /// Empty, unless the trait index is enabled.
+    fn query_tokens_by_trait(
+        &self,
+        deps: Deps,
+        _env: Env,
+        trait_type: String,
+        value: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<TokensResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+        let tokens: StdResult<Vec<_>> =
+            Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+                .token_traits
+                .prefix((trait_type.as_str(), value.as_str()))
+                .keys(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .collect();
+        Ok(TokensResponse { tokens: tokens? })
+    }
+
+    /// Empty, unless the trait index is enabled.
+    fn query_trait_counts(
+        &self,
+        deps: Deps,
+        trait_type: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<TraitCountsResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+        let traits: StdResult<Vec<_>> =
+            Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+                .trait_counts
+                .prefix(&trait_type)
+                .range(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|item| {
+                    item.map(|(value, count)| TraitCount {
+                        trait_type: trait_type.clone(),
+                        value,
+                        count,
+                    })
+                })
+                .collect();
+        Ok(TraitCountsResponse { traits: traits? })
+    }
+
+    fn query_is_burned(&self, deps: Deps, token_id: String) -> StdResult<bool> {
+        Ok(
+            Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+                .burned_tokens
+                .has(deps.storage, &token_id),
+        )
+    }
+
+    fn query_burned_tokens(
+        &self,
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<BurnedTokensResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+        let tokens: StdResult<Vec<_>> =
+            Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
+                .burned_tokens
+                .range(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|item| {
+                    item.map(|(token_id, burned)| BurnedTokenResponse {
+                        token_id,
+                        burner: burned.burner,
+                        height: burned.height,
+                        time: burned.time,
+                    })
+                })
+                .collect();
+        Ok(BurnedTokensResponse { tokens: tokens? })
+    }
+
     fn query_all_nft_info(
         &self,
         deps: Deps,
@@ -357,7 +876,7 @@ pub trait Cw721Query<
         token_id: String,
         include_expired_approval: bool,
     ) -> StdResult<AllNftInfoResponse<TMetadataExtension>> {
-        let nft_info = Cw721Config::<TMetadataExtension, Empty, Empty>::default()
+        let nft_info = Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
             .nft_info
             .load(deps.storage, &token_id)?;
         Ok(AllNftInfoResponse {
@@ -382,8 +901,18 @@ pub trait Cw721Query<
         Ok(Binary::default())
     }
 
+    /// No-op returning empty Binary
+    fn query_collection_info_extension(
+        &self,
+        _deps: Deps,
+        _env: Env,
+        _msg: TCollectionInfoExtension,
+    ) -> StdResult<Binary> {
+        Ok(Binary::default())
+    }
+
     fn query_withdraw_address(&self, deps: Deps) -> StdResult<Option<String>> {
-        Cw721Config::<TMetadataExtension, Empty, Empty>::default()
+        Cw721Config::<TMetadataExtension, Empty, Empty, Empty>::default()
             .withdraw_address
             .may_load(deps.storage)
     }
@@ -409,6 +938,25 @@ where
         .collect()
 }
 
+pub fn token_with_info<TMetadataExtension>(
+    block: &BlockInfo,
+    token_id: String,
+    nft_info: NftInfo<TMetadataExtension>,
+    include_approvals: bool,
+) -> TokenWithInfo<TMetadataExtension>
+where
+    TMetadataExtension: Serialize + DeserializeOwned + Clone,
+{
+    let approvals = include_approvals.then(|| humanize_approvals(block, &nft_info, false));
+    TokenWithInfo {
+        token_id,
+        owner: nft_info.owner.to_string(),
+        token_uri: nft_info.token_uri,
+        extension: nft_info.extension,
+        approvals,
+    }
+}
+
 pub fn humanize_approval(approval: &Approval) -> Approval {
     Approval {
         spender: approval.spender.clone(),
@@ -1,7 +1,7 @@
 use schemars::JsonSchema;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{to_json_binary, Binary, CosmosMsg, StdResult, WasmMsg};
+use cosmwasm_std::{to_json_binary, Binary, Coin, CosmosMsg, StdResult, WasmMsg};
 
 /// Cw721ReceiveMsg should be de/serialized under `Receive()` variant in a ExecuteMsg
 #[cw_serde]
@@ -36,9 +36,71 @@ impl Cw721ReceiveMsg {
     }
 }
 
-/// This is just a helper to properly serialize the above message.
-/// The actual receiver should include this variant in the larger ExecuteMsg enum
+/// Version of the message a receiving contract gets on `SendNft`.
+/// Defaults to `V1`, so existing receivers keep working unchanged.
+#[cw_serde]
+#[derive(Default)]
+pub enum ReceiveMsgVersion {
+    /// `ReceiveNft(Cw721ReceiveMsg)`, funds attached to `SendNft` are not forwarded
+    #[default]
+    V1,
+    /// `ReceiveNftV2(Cw721ReceiveMsgV2)`, funds attached to `SendNft` are forwarded
+    V2,
+}
+
+/// Collection the received token belongs to.
+#[cw_serde]
+pub struct Cw721ReceiveCollection {
+    /// Address of the cw721 contract, same as `info.sender` on the receiving contract
+    pub address: String,
+    pub name: String,
+    pub symbol: String,
+}
+
+/// Cw721ReceiveMsgV2 should be de/serialized under `ReceiveNftV2()` variant in a ExecuteMsg
+#[cw_serde]
+pub struct Cw721ReceiveMsgV2 {
+    /// Account calling `SendNft`, either the owner or an approved spender/operator
+    pub sender: String,
+    /// Owner of the token before it was sent
+    pub owner: String,
+    pub token_id: String,
+    pub collection: Cw721ReceiveCollection,
+    /// Funds attached to `SendNft`, forwarded to the receiving contract
+    pub funds: Vec<Coin>,
+    pub msg: Binary,
+}
+
+impl Cw721ReceiveMsgV2 {
+    /// serializes the message
+    pub fn into_json_binary(self) -> StdResult<Binary> {
+        let msg = ReceiverExecuteMsg::ReceiveNftV2(self);
+        to_json_binary(&msg)
+    }
+
+    /// creates a cosmos_msg sending this struct and its funds to the named contract
+    pub fn into_cosmos_msg<TAddress: Into<String>, TCustomResponseMessage>(
+        self,
+        contract_addr: TAddress,
+    ) -> StdResult<CosmosMsg<TCustomResponseMessage>>
+    where
+        TCustomResponseMessage: Clone + std::fmt::Debug + PartialEq + JsonSchema,
+    {
+        let funds = self.funds.clone();
+        let msg = self.into_json_binary()?;
+        let execute = WasmMsg::Execute {
+            contract_addr: contract_addr.into(),
+            msg,
+            funds,
+        };
+        Ok(execute.into())
+    }
+}
+
+/// This is just a helper to properly serialize the above messages.
+/// The actual receiver should include these variants in the larger ExecuteMsg enum
 #[cw_serde]
 enum ReceiverExecuteMsg {
     ReceiveNft(Cw721ReceiveMsg),
+    ReceiveNftV2(Cw721ReceiveMsgV2),
 }
@@ -1,19 +1,28 @@
+use std::collections::BTreeSet;
 use std::marker::PhantomData;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, BlockInfo, CustomMsg, StdResult, Storage};
+use cosmwasm_std::{Addr, BlockInfo, CustomMsg, Decimal, Empty, StdResult, Storage, Timestamp};
 use cw_ownable::{OwnershipStore, OWNERSHIP_KEY};
 use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use cw_utils::Expiration;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+/// Creator owns this contract and can update collection info and metadata!
+/// !!! Important note here: !!!
+/// - creator is stored using cw-ownable's OWNERSHIP singleton
+/// - in release v0.18.0 it was used for minter (which is confusing), but now it is used for creator
+pub const CREATOR: OwnershipStore = OwnershipStore::new(OWNERSHIP_KEY);
 /// - minter is stored in the contract storage using cw_ownable::OwnershipStore (same as for OWNERSHIP but with different key)
-pub const MINTER: OwnershipStore = OwnershipStore::new(OWNERSHIP_KEY);
+pub const MINTER: OwnershipStore = OwnershipStore::new("collection_minter");
 
-/// Default CollectionInfoExtension with RoyaltyInfo
+/// Default NftInfo extension with onchain Metadata
 pub type DefaultOptionMetadataExtension = Option<Metadata>;
 
+/// Default CollectionInfoExtension with RoyaltyInfo
+pub type DefaultOptionCollectionInfoExtension = Option<CollectionInfoExtension<RoyaltyInfo>>;
+
 pub struct Cw721Config<
     'a,
     // Metadata defined in NftInfo (used for mint).
@@ -22,87 +31,474 @@ pub struct Cw721Config<
     TCustomResponseMessage,
     // Message passed for updating metadata.
     TMetadataExtensionMsg,
+    // Extension defined in CollectionInfo.
+    TCollectionInfoExtension,
 > where
     TMetadataExtension: Serialize + DeserializeOwned + Clone,
     TMetadataExtensionMsg: CustomMsg,
+    TCollectionInfoExtension: Serialize + DeserializeOwned + Clone,
 {
     /// Note: replaces deprecated/legacy key "nft_info"!
     pub collection_info: Item<'a, CollectionInfo>,
+    /// Stored separately, so `collection_info` stays compatible with older versions.
+    pub collection_info_extension: Item<'a, TCollectionInfoExtension>,
     pub token_count: Item<'a, u64>,
     /// Stored as (granter, operator) giving operator full control over granter's account.
     /// NOTE: granter is the owner, so operator has only control for NFTs owned by granter!
     pub operators: Map<'a, (&'a Addr, &'a Addr), Expiration>,
+    /// Reverse index of `operators`, stored as (operator, granter).
+    pub operator_grants: Map<'a, (&'a Addr, &'a Addr), Expiration>,
+    /// Set while `operator_grants` is backfilled after migration,
+    /// holding the last migrated (granter, operator).
+    pub operator_grants_backfill: Item<'a, Option<(Addr, Addr)>>,
     pub nft_info:
         IndexedMap<'a, &'a str, NftInfo<TMetadataExtension>, TokenIndexes<'a, TMetadataExtension>>,
+    /// Index of `NftInfo.approvals`, stored as (spender, token_id).
+    pub token_approvals: Map<'a, (&'a Addr, &'a str), Expiration>,
+    /// Set while `token_approvals` is backfilled after migration, holding the last migrated token id.
+    pub token_approvals_backfill: Item<'a, Option<String>>,
+    /// Number of tokens held per owner.
+    pub balances: Map<'a, &'a Addr, u64>,
+    /// Set while `balances` is backfilled after migration, holding the last counted token id.
+    pub balances_backfill: Item<'a, Option<String>>,
     pub withdraw_address: Item<'a, String>,
+    pub metadata_update_authority: Item<'a, MetadataUpdateAuthority>,
+    /// Once set to true, collection info can't be updated anymore.
+    pub collection_info_frozen: Item<'a, bool>,
+    /// Locked tokens can't be transferred, sent, approved or burned until unlocked or expired.
+    pub token_locks: Map<'a, &'a str, TokenLock>,
+    /// Emergency brake, set by the creator. Defaults to nothing paused.
+    pub pause_status: Item<'a, PauseStatus>,
+    pub transfer_policy: Item<'a, TransferPolicy>,
+    /// Contracts notified on mint, transfer, send and burn.
+    /// Value defines whether a failing hook reverts the action.
+    pub hooks: Map<'a, &'a Addr, bool>,
+    /// If set, token ids can be omitted on mint and are assigned from `next_token_id`.
+    pub sequential_token_ids: Item<'a, SequentialTokenIds>,
+    pub next_token_id: Item<'a, u64>,
+    /// Upper limit of tokens ever minted, burned tokens included. Can only be lowered.
+    pub max_supply: Item<'a, u64>,
+    /// Unlike `token_count`, this is not decreased on burn.
+    pub total_minted: Item<'a, u64>,
+    /// Tombstones of burned tokens, so their ids can't be minted again.
+    pub burned_tokens: Map<'a, &'a str, BurnedToken>,
+    /// If set to true, token attributes are indexed in `token_traits` and `trait_counts`.
+    pub index_traits: Item<'a, bool>,
+    /// Index of token attributes, stored as (trait_type, value, token_id).
+    pub token_traits: Map<'a, (&'a str, &'a str, &'a str), Empty>,
+    /// Number of tokens per (trait_type, value).
+    pub trait_counts: Map<'a, (&'a str, &'a str), u64>,
+    /// Default royalty of all tokens, set by the creator.
+    pub collection_royalty: Item<'a, RoyaltyInfo>,
+    /// Per-token royalties, overriding `collection_royalty`.
+    pub token_royalties: Map<'a, &'a str, RoyaltyInfo>,
 
     pub(crate) _custom_response: PhantomData<TCustomResponseMessage>,
     pub(crate) _custom_execute: PhantomData<TMetadataExtensionMsg>,
 }
 
-impl<TMetadataExtension, TCustomResponseMessage, TMetadataExtensionMsg> Default
-    for Cw721Config<'static, TMetadataExtension, TCustomResponseMessage, TMetadataExtensionMsg>
+impl<
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    > Default
+    for Cw721Config<
+        'static,
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
 where
     TMetadataExtension: Serialize + DeserializeOwned + Clone,
     TMetadataExtensionMsg: CustomMsg,
+    TCollectionInfoExtension: Serialize + DeserializeOwned + Clone,
 {
     fn default() -> Self {
         Self::new(
             "collection_info", // Note: replaces deprecated/legacy key "nft_info"
+            "collection_info_extension",
             "num_tokens",
             "operators",
             "tokens",
             "tokens__owner",
             "withdraw_address",
+            "metadata_update_authority",
+            "collection_info_frozen",
+            "token_locks",
+            "pause_status",
+            "transfer_policy",
+            "hooks",
+            "sequential_token_ids",
+            "next_token_id",
+            "max_supply",
+            "total_minted",
+            "burned_tokens",
+            "operators__operator",
+            "tokens__approvals",
+            "balances",
+            "index_traits",
+            "tokens__trait",
+            "trait_counts",
+            "collection_royalty",
+            "token_royalties",
+            "operator_grants_backfill",
+            "token_approvals_backfill",
+            "balances_backfill",
         )
     }
 }
 
-impl<'a, TMetadataExtension, TCustomResponseMessage, TMetadataExtensionMsg>
-    Cw721Config<'a, TMetadataExtension, TCustomResponseMessage, TMetadataExtensionMsg>
+impl<
+        'a,
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
+    Cw721Config<
+        'a,
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
 where
     TMetadataExtension: Serialize + DeserializeOwned + Clone,
     TMetadataExtensionMsg: CustomMsg,
+    TCollectionInfoExtension: Serialize + DeserializeOwned + Clone,
 {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         collection_info_key: &'a str,
+        collection_info_extension_key: &'a str,
         token_count_key: &'a str,
         operator_key: &'a str,
         nft_info_key: &'a str,
         nft_info_owner_key: &'a str,
         withdraw_address_key: &'a str,
+        metadata_update_authority_key: &'a str,
+        collection_info_frozen_key: &'a str,
+        token_locks_key: &'a str,
+        pause_status_key: &'a str,
+        transfer_policy_key: &'a str,
+        hooks_key: &'a str,
+        sequential_token_ids_key: &'a str,
+        next_token_id_key: &'a str,
+        max_supply_key: &'a str,
+        total_minted_key: &'a str,
+        burned_tokens_key: &'a str,
+        operator_grants_key: &'a str,
+        token_approvals_key: &'a str,
+        balances_key: &'a str,
+        index_traits_key: &'a str,
+        token_traits_key: &'a str,
+        trait_counts_key: &'a str,
+        collection_royalty_key: &'a str,
+        token_royalties_key: &'a str,
+        operator_grants_backfill_key: &'a str,
+        token_approvals_backfill_key: &'a str,
+        balances_backfill_key: &'a str,
     ) -> Self {
         let indexes = TokenIndexes {
             owner: MultiIndex::new(token_owner_idx, nft_info_key, nft_info_owner_key),
         };
         Self {
             collection_info: Item::new(collection_info_key),
+            collection_info_extension: Item::new(collection_info_extension_key),
             token_count: Item::new(token_count_key),
             operators: Map::new(operator_key),
+            operator_grants: Map::new(operator_grants_key),
+            operator_grants_backfill: Item::new(operator_grants_backfill_key),
             nft_info: IndexedMap::new(nft_info_key, indexes),
+            token_approvals: Map::new(token_approvals_key),
+            token_approvals_backfill: Item::new(token_approvals_backfill_key),
+            balances: Map::new(balances_key),
+            balances_backfill: Item::new(balances_backfill_key),
             withdraw_address: Item::new(withdraw_address_key),
+            metadata_update_authority: Item::new(metadata_update_authority_key),
+            collection_info_frozen: Item::new(collection_info_frozen_key),
+            token_locks: Map::new(token_locks_key),
+            pause_status: Item::new(pause_status_key),
+            transfer_policy: Item::new(transfer_policy_key),
+            hooks: Map::new(hooks_key),
+            sequential_token_ids: Item::new(sequential_token_ids_key),
+            next_token_id: Item::new(next_token_id_key),
+            max_supply: Item::new(max_supply_key),
+            total_minted: Item::new(total_minted_key),
+            burned_tokens: Map::new(burned_tokens_key),
+            index_traits: Item::new(index_traits_key),
+            token_traits: Map::new(token_traits_key),
+            trait_counts: Map::new(trait_counts_key),
+            collection_royalty: Item::new(collection_royalty_key),
+            token_royalties: Map::new(token_royalties_key),
             _custom_response: PhantomData,
             _custom_execute: PhantomData,
         }
     }
 
+    pub fn metadata_update_authority(
+        &self,
+        storage: &dyn Storage,
+    ) -> StdResult<MetadataUpdateAuthority> {
+        Ok(self
+            .metadata_update_authority
+            .may_load(storage)?
+            .unwrap_or_default())
+    }
+
+    pub fn pause_status(&self, storage: &dyn Storage) -> StdResult<PauseStatus> {
+        Ok(self.pause_status.may_load(storage)?.unwrap_or_default())
+    }
+
+    pub fn transfer_policy(&self, storage: &dyn Storage) -> StdResult<TransferPolicy> {
+        Ok(self.transfer_policy.may_load(storage)?.unwrap_or_default())
+    }
+
+    /// Assigns the next unused sequential token id, or returns `None` if sequential token ids are disabled.
+    /// Ids already claimed by an explicit mint or burned are skipped.
+    pub fn assign_token_id(&self, storage: &mut dyn Storage) -> StdResult<Option<String>> {
+        let sequential_token_ids = match self.sequential_token_ids.may_load(storage)? {
+            Some(sequential_token_ids) => sequential_token_ids,
+            None => return Ok(None),
+        };
+        let prefix = sequential_token_ids.prefix.unwrap_or_default();
+        let mut next_id = self
+            .next_token_id
+            .may_load(storage)?
+            .unwrap_or(sequential_token_ids.start.unwrap_or_default());
+        let mut token_id = format!("{prefix}{next_id}");
+        while self.nft_info.has(storage, &token_id) || self.burned_tokens.has(storage, &token_id) {
+            next_id += 1;
+            token_id = format!("{prefix}{next_id}");
+        }
+        self.next_token_id.save(storage, &(next_id + 1))?;
+        Ok(Some(token_id))
+    }
+
+    /// Falls back to `token_count` for collections not migrated yet, see `migrate_total_minted`.
+    pub fn total_minted(&self, storage: &dyn Storage) -> StdResult<u64> {
+        match self.total_minted.may_load(storage)? {
+            Some(total_minted) => Ok(total_minted),
+            None => self.token_count(storage),
+        }
+    }
+
     pub fn token_count(&self, storage: &dyn Storage) -> StdResult<u64> {
         Ok(self.token_count.may_load(storage)?.unwrap_or_default())
     }
 
     pub fn increment_tokens(&self, storage: &mut dyn Storage) -> StdResult<u64> {
-        let val = self.token_count(storage)? + 1;
+        self.increment_tokens_by(storage, 1)
+    }
+
+    /// Increments both, `token_count` and `total_minted`. Returns the new `token_count`.
+    pub fn increment_tokens_by(&self, storage: &mut dyn Storage, amount: u64) -> StdResult<u64> {
+        let total_minted = self.total_minted(storage)? + amount;
+        self.total_minted.save(storage, &total_minted)?;
+        let val = self.token_count(storage)? + amount;
         self.token_count.save(storage, &val)?;
         Ok(val)
     }
 
+    pub fn balance(&self, storage: &dyn Storage, owner: &Addr) -> StdResult<u64> {
+        Ok(self.balances.may_load(storage, owner)?.unwrap_or_default())
+    }
+
+    /// Whether the token is counted in `balances`. While they are backfilled,
+    /// tokens not reached yet are left to the backfill, see `migrate_balances`.
+    pub fn balance_counted(&self, storage: &dyn Storage, token_id: &str) -> StdResult<bool> {
+        Ok(match self.balances_backfill.may_load(storage)? {
+            Some(last) => matches!(last, Some(last) if token_id <= last.as_str()),
+            None => true,
+        })
+    }
+
+    pub fn increment_balance(
+        &self,
+        storage: &mut dyn Storage,
+        owner: &Addr,
+        token_id: &str,
+    ) -> StdResult<u64> {
+        if !self.balance_counted(storage, token_id)? {
+            return self.balance(storage, owner);
+        }
+        let val = self.balance(storage, owner)? + 1;
+        self.balances.save(storage, owner, &val)?;
+        Ok(val)
+    }
+
+    /// Entry is removed once the balance drops to zero.
+    pub fn decrement_balance(
+        &self,
+        storage: &mut dyn Storage,
+        owner: &Addr,
+        token_id: &str,
+    ) -> StdResult<u64> {
+        if !self.balance_counted(storage, token_id)? {
+            return self.balance(storage, owner);
+        }
+        let val = self.balance(storage, owner)?.saturating_sub(1);
+        if val == 0 {
+            self.balances.remove(storage, owner);
+        } else {
+            self.balances.save(storage, owner, &val)?;
+        }
+        Ok(val)
+    }
+
+    /// Removes all approvals of the token from the `token_approvals` index.
+    pub fn clear_token_approvals(
+        &self,
+        storage: &mut dyn Storage,
+        token_id: &str,
+        approvals: &[Approval],
+    ) {
+        for approval in approvals {
+            self.token_approvals
+                .remove(storage, (&approval.spender, token_id));
+        }
+    }
+
+    pub fn index_traits_enabled(&self, storage: &dyn Storage) -> StdResult<bool> {
+        Ok(self.index_traits.may_load(storage)?.unwrap_or_default())
+    }
+
     pub fn decrement_tokens(&self, storage: &mut dyn Storage) -> StdResult<u64> {
-        let val = self.token_count(storage)? - 1;
+        self.decrement_tokens_by(storage, 1)
+    }
+
+    pub fn decrement_tokens_by(&self, storage: &mut dyn Storage, amount: u64) -> StdResult<u64> {
+        let val = self.token_count(storage)? - amount;
         self.token_count.save(storage, &val)?;
         Ok(val)
     }
 }
 
+impl<
+        'a,
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
+    Cw721Config<
+        'a,
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
+where
+    TMetadataExtension: Serialize + DeserializeOwned + Clone,
+    TMetadataExtensionMsg: CustomMsg,
+    TCollectionInfoExtension: Serialize + DeserializeOwned + Clone + RoyaltySource,
+{
+    /// Royalty of the token, falling back to the collection royalty.
+    pub fn royalty(&self, storage: &dyn Storage, token_id: &str) -> StdResult<Option<RoyaltyInfo>> {
+        match self.token_royalties.may_load(storage, token_id)? {
+            Some(royalty) => Ok(Some(royalty)),
+            None => self.default_royalty(storage),
+        }
+    }
+
+    /// Royalty set by `SetCollectionRoyalty`, falling back to `royalty_info` of the
+    /// collection info extension.
+    pub fn default_royalty(&self, storage: &dyn Storage) -> StdResult<Option<RoyaltyInfo>> {
+        match self.collection_royalty.may_load(storage)? {
+            Some(royalty) => Ok(Some(royalty)),
+            None => Ok(self
+                .collection_info_extension
+                .may_load(storage)?
+                .and_then(|extension| extension.royalty_info())),
+        }
+    }
+}
+
+impl<
+        'a,
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
+    Cw721Config<
+        'a,
+        TMetadataExtension,
+        TCustomResponseMessage,
+        TMetadataExtensionMsg,
+        TCollectionInfoExtension,
+    >
+where
+    TMetadataExtension: Serialize + DeserializeOwned + Clone + TraitSource,
+    TMetadataExtensionMsg: CustomMsg,
+    TCollectionInfoExtension: Serialize + DeserializeOwned + Clone,
+{
+    /// Adds the attributes of the token to the trait index, if enabled.
+    pub fn index_token_traits(
+        &self,
+        storage: &mut dyn Storage,
+        token_id: &str,
+        extension: &TMetadataExtension,
+    ) -> StdResult<()> {
+        if !self.index_traits_enabled(storage)? {
+            return Ok(());
+        }
+        for (trait_type, value) in distinct_traits(extension) {
+            let key = (trait_type.as_str(), value.as_str());
+            self.token_traits
+                .save(storage, (key.0, key.1, token_id), &Empty {})?;
+            let count = self
+                .trait_counts
+                .may_load(storage, key)?
+                .unwrap_or_default();
+            self.trait_counts.save(storage, key, &(count + 1))?;
+        }
+        Ok(())
+    }
+
+    /// Removes the attributes of the token from the trait index, if enabled.
+    /// Counts are removed once they drop to zero.
+    pub fn unindex_token_traits(
+        &self,
+        storage: &mut dyn Storage,
+        token_id: &str,
+        extension: &TMetadataExtension,
+    ) -> StdResult<()> {
+        if !self.index_traits_enabled(storage)? {
+            return Ok(());
+        }
+        for (trait_type, value) in distinct_traits(extension) {
+            let key = (trait_type.as_str(), value.as_str());
+            self.token_traits.remove(storage, (key.0, key.1, token_id));
+            let count = self
+                .trait_counts
+                .may_load(storage, key)?
+                .unwrap_or_default()
+                .saturating_sub(1);
+            if count == 0 {
+                self.trait_counts.remove(storage, key);
+            } else {
+                self.trait_counts.save(storage, key, &count)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Distinct (trait_type, value) pairs of an extension.
+fn distinct_traits<TMetadataExtension>(extension: &TMetadataExtension) -> BTreeSet<(String, String)>
+where
+    TMetadataExtension: TraitSource,
+{
+    extension
+        .traits()
+        .into_iter()
+        .map(|attribute| (attribute.trait_type, attribute.value))
+        .collect()
+}
+
 pub fn token_owner_idx<TMetadataExtension>(_pk: &[u8], d: &NftInfo<TMetadataExtension>) -> Addr {
     d.owner.clone()
 }
@@ -123,6 +519,19 @@ pub struct NftInfo<TMetadataExtension> {
     pub extension: TMetadataExtension,
 }
 
+/// Defines who is allowed to update `token_uri` and onchain metadata of a minted NFT.
+#[cw_serde]
+#[derive(Default)]
+pub enum MetadataUpdateAuthority {
+    /// Only the contract owner (creator) can update metadata.
+    #[default]
+    Creator,
+    /// Only the minter can update metadata.
+    Minter,
+    /// Only the owner of the NFT can update its metadata.
+    TokenOwner,
+}
+
 #[cw_serde]
 pub struct Approval {
     /// Account that can transfer/send the token
@@ -137,6 +546,67 @@ impl Approval {
     }
 }
 
+/// Defines whether and by whom tokens can be transferred or sent.
+#[cw_serde]
+#[derive(Default)]
+pub enum TransferPolicy {
+    /// Owner, approved spenders and operators can transfer tokens.
+    #[default]
+    Transferable,
+    /// Soulbound: tokens can't be transferred, but can still be burned.
+    NonTransferable,
+    /// Soulbound: tokens can't be transferred and only their owner can burn them.
+    OwnerBurnOnly,
+    /// Only the minter can transfer tokens, e.g. for recovery.
+    MinterOnly,
+    /// Tokens can't be transferred before the given time.
+    TransferableAfter { timestamp: Timestamp },
+}
+
+/// Token ids assigned on mint, in case no token id is given: `{prefix}{number}`.
+#[cw_serde]
+#[derive(Default)]
+pub struct SequentialTokenIds {
+    /// Prepended to every assigned token id, e.g. `"ticket-"`.
+    pub prefix: Option<String>,
+    /// Number of the first assigned token id, defaults to 0.
+    pub start: Option<u64>,
+}
+
+/// Operations that can be paused independently of each other.
+#[cw_serde]
+#[derive(Default)]
+pub struct PauseStatus {
+    pub minting: bool,
+    /// Covers transfers and sends.
+    pub transfers: bool,
+    pub burns: bool,
+}
+
+#[cw_serde]
+pub struct TokenLock {
+    /// Account that locked the token, only this account can unlock it
+    pub locker: Addr,
+    /// When the lock expires (maybe Expiration::never)
+    pub expires: Expiration,
+}
+
+impl TokenLock {
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        self.expires.is_expired(block)
+    }
+}
+
+/// Tombstone of a burned token
+#[cw_serde]
+pub struct BurnedToken {
+    pub burner: Addr,
+    /// Block height at which the token was burned
+    pub height: u64,
+    /// Block time at which the token was burned
+    pub time: Timestamp,
+}
+
 pub struct TokenIndexes<'a, TMetadataExtension>
 where
     TMetadataExtension: Serialize + DeserializeOwned + Clone,
@@ -163,6 +633,51 @@ pub struct CollectionInfo {
     pub symbol: String,
 }
 
+#[cw_serde]
+#[derive(Default)]
+pub struct CollectionInfoExtension<TRoyaltyInfo> {
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub external_link: Option<String>,
+    pub explicit_content: Option<bool>,
+    pub start_trading_time: Option<Timestamp>,
+    pub royalty_info: Option<TRoyaltyInfo>,
+}
+
+#[cw_serde]
+pub struct RoyaltyInfo {
+    pub payment_address: Addr,
+    /// Share of sales, e.g. `0.05` for 5%
+    pub share: Decimal,
+}
+
+/// Collection royalty of a collection info extension, used if none is set by `SetCollectionRoyalty`.
+pub trait RoyaltySource {
+    fn royalty_info(&self) -> Option<RoyaltyInfo>;
+}
+
+impl RoyaltySource for Empty {
+    fn royalty_info(&self) -> Option<RoyaltyInfo> {
+        None
+    }
+}
+
+impl RoyaltySource for CollectionInfoExtension<RoyaltyInfo> {
+    fn royalty_info(&self) -> Option<RoyaltyInfo> {
+        self.royalty_info.clone()
+    }
+}
+
+/// Covers e.g. `DefaultOptionCollectionInfoExtension`, `None` has no royalty.
+impl<TCollectionInfoExtension> RoyaltySource for Option<TCollectionInfoExtension>
+where
+    TCollectionInfoExtension: RoyaltySource,
+{
+    fn royalty_info(&self) -> Option<RoyaltyInfo> {
+        self.as_ref().and_then(RoyaltySource::royalty_info)
+    }
+}
+
 // see: https://docs.opensea.io/docs/metadata-standards
 #[cw_serde]
 #[derive(Default)]
@@ -184,3 +699,30 @@ pub struct Trait {
     pub trait_type: String,
     pub value: String,
 }
+
+/// Onchain attributes of a metadata extension, indexed if `index_traits` is enabled.
+pub trait TraitSource {
+    fn traits(&self) -> Vec<Trait>;
+}
+
+impl TraitSource for Empty {
+    fn traits(&self) -> Vec<Trait> {
+        vec![]
+    }
+}
+
+impl TraitSource for Metadata {
+    fn traits(&self) -> Vec<Trait> {
+        self.attributes.clone().unwrap_or_default()
+    }
+}
+
+/// Covers e.g. `DefaultOptionMetadataExtension`, `None` has no attributes.
+impl<TMetadataExtension> TraitSource for Option<TMetadataExtension>
+where
+    TMetadataExtension: TraitSource,
+{
+    fn traits(&self) -> Vec<Trait> {
+        self.as_ref().map(TraitSource::traits).unwrap_or_default()
+    }
+}
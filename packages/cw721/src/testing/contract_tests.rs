@@ -3,17 +3,35 @@
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Coin, CosmosMsg, DepsMut, Empty, Response, StdError, WasmMsg,
+    from_json, to_json_binary, Addr, Binary, Coin, ContractInfoResponse, ContractResult, CosmosMsg,
+    Decimal, Deps, DepsMut, Empty, Event, Order, Reply, Response, StdError, SubMsg, SubMsgResult,
+    SystemError, SystemResult, Timestamp, Uint128, WasmMsg, WasmQuery,
 };
 
 use crate::error::Cw721ContractError;
+use crate::events::Cw721Event;
+use crate::hooks::{Cw721HookMsg, HOOK_REPLY_ID};
 use crate::msg::{
-    ApprovalResponse, NftInfoResponse, OperatorResponse, OperatorsResponse, OwnerOfResponse,
+    ApprovalResponse, ApprovedOwnersResponse, BurnedTokenResponse, BurnedTokensResponse,
+    CollectionInfoAndExtensionResponse, DefaultOptionMetadataExtensionMsg, HookInfo, HooksResponse,
+    MintMsg, NftInfoResponse, OperatorResponse, OperatorsResponse, OrderBy, OwnerApproval,
+    OwnerOfResponse, PruneCursor, RoyaltyInfoMsg, SupplyResponse, TokenLockResponse,
+    TokenLocksResponse, TokenWithInfo, TraitCount, UpdateNftInfoMsg,
 };
 use crate::msg::{Cw721ExecuteMsg, Cw721InstantiateMsg, Cw721QueryMsg};
-use crate::receiver::Cw721ReceiveMsg;
-use crate::state::{CollectionInfo, DefaultOptionMetadataExtension, MINTER};
-use crate::{execute::Cw721Execute, query::Cw721Query, Approval, Expiration};
+use crate::receiver::{
+    Cw721ReceiveCollection, Cw721ReceiveMsg, Cw721ReceiveMsgV2, ReceiveMsgVersion,
+};
+use crate::state::{
+    CollectionInfo, CollectionInfoExtension, Cw721Config, DefaultOptionCollectionInfoExtension,
+    DefaultOptionMetadataExtension, Metadata, MetadataUpdateAuthority, PauseStatus, RoyaltyInfo,
+    SequentialTokenIds, Trait, TransferPolicy, MINTER,
+};
+use crate::{
+    execute::{is_contract, Cw721Execute},
+    query::Cw721Query,
+    Approval, Expiration,
+};
 use cw_ownable::{Action, Ownership, OwnershipError};
 
 use super::contract::Cw721Contract;
@@ -25,13 +43,26 @@ const SYMBOL: &str = "MGK";
 
 fn setup_contract(
     deps: DepsMut<'_>,
-) -> Cw721Contract<'static, DefaultOptionMetadataExtension, Empty, Empty> {
+) -> Cw721Contract<
+    'static,
+    DefaultOptionMetadataExtension,
+    Empty,
+    Empty,
+    DefaultOptionCollectionInfoExtension,
+> {
     let contract = Cw721Contract::default();
     let msg = Cw721InstantiateMsg {
         name: CONTRACT_NAME.to_string(),
         symbol: SYMBOL.to_string(),
         minter: Some(String::from(MINTER_ADDR)),
+        creator: None,
         withdraw_address: None,
+        metadata_update_authority: None,
+        transfer_policy: None,
+        sequential_token_ids: None,
+        max_supply: None,
+        index_traits: None,
+        collection_info_extension: None,
     };
     let info = mock_info("creator", &[]);
     let res = contract
@@ -51,13 +82,25 @@ fn setup_contract(
 #[test]
 fn proper_instantiation() {
     let mut deps = mock_dependencies();
-    let contract = Cw721Contract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
 
     let msg = Cw721InstantiateMsg {
         name: CONTRACT_NAME.to_string(),
         symbol: SYMBOL.to_string(),
         minter: Some(String::from(MINTER_ADDR)),
+        creator: None,
         withdraw_address: Some(String::from(CREATOR_ADDR)),
+        metadata_update_authority: None,
+        transfer_policy: None,
+        sequential_token_ids: None,
+        max_supply: None,
+        index_traits: None,
+        collection_info_extension: None,
     };
     let info = mock_info("creator", &[]);
     let env = mock_env();
@@ -103,7 +146,7 @@ fn proper_instantiation() {
 
     // list the token_ids
     let tokens = contract
-        .query_all_tokens(deps.as_ref(), env, None, None)
+        .query_all_tokens(deps.as_ref(), env, None, None, None, None)
         .unwrap();
     assert_eq!(0, tokens.tokens.len());
 }
@@ -111,13 +154,36 @@ fn proper_instantiation() {
 #[test]
 fn proper_instantiation_with_collection_info() {
     let mut deps = mock_dependencies();
-    let contract = Cw721Contract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
-
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
+
+    let collection_info_extension = CollectionInfoExtension {
+        description: Some("description".to_string()),
+        image: Some("https://moonphases.org".to_string()),
+        external_link: Some("https://moonphases.org/mission".to_string()),
+        explicit_content: Some(false),
+        start_trading_time: Some(Timestamp::from_seconds(42)),
+        royalty_info: Some(RoyaltyInfoMsg {
+            payment_address: "payment_address".to_string(),
+            share: Decimal::percent(5),
+        }),
+    };
     let msg = Cw721InstantiateMsg {
         name: CONTRACT_NAME.to_string(),
         symbol: SYMBOL.to_string(),
         minter: Some(String::from(MINTER_ADDR)),
+        creator: None,
         withdraw_address: Some(String::from(CREATOR_ADDR)),
+        metadata_update_authority: None,
+        transfer_policy: None,
+        sequential_token_ids: None,
+        max_supply: None,
+        index_traits: None,
+        collection_info_extension: Some(collection_info_extension.clone()),
     };
     let collection_info = mock_info("creator", &[]);
     let env = mock_env();
@@ -148,6 +214,27 @@ fn proper_instantiation_with_collection_info() {
             symbol: SYMBOL.to_string(),
         }
     );
+    let info = contract
+        .query_collection_info_and_extension(deps.as_ref(), env.clone())
+        .unwrap();
+    assert_eq!(
+        info,
+        CollectionInfoAndExtensionResponse {
+            name: CONTRACT_NAME.to_string(),
+            symbol: SYMBOL.to_string(),
+            extension: Some(CollectionInfoExtension {
+                royalty_info: Some(RoyaltyInfo {
+                    payment_address: Addr::unchecked("payment_address"),
+                    share: Decimal::percent(5),
+                }),
+                description: collection_info_extension.description,
+                image: collection_info_extension.image,
+                external_link: collection_info_extension.external_link,
+                explicit_content: collection_info_extension.explicit_content,
+                start_trading_time: collection_info_extension.start_trading_time,
+            }),
+        }
+    );
 
     let withdraw_address = contract
         .config
@@ -163,11 +250,73 @@ fn proper_instantiation_with_collection_info() {
 
     // list the token_ids
     let tokens = contract
-        .query_all_tokens(deps.as_ref(), env, None, None)
+        .query_all_tokens(deps.as_ref(), env, None, None, None, None)
         .unwrap();
     assert_eq!(0, tokens.tokens.len());
 }
 
+#[test]
+fn instantiation_validates_royalty_info() {
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
+    let instantiate = |royalty_info: RoyaltyInfoMsg| {
+        let msg = Cw721InstantiateMsg {
+            name: CONTRACT_NAME.to_string(),
+            symbol: SYMBOL.to_string(),
+            minter: None,
+            creator: None,
+            withdraw_address: None,
+            metadata_update_authority: None,
+            transfer_policy: None,
+            sequential_token_ids: None,
+            max_supply: None,
+            index_traits: None,
+            collection_info_extension: Some(CollectionInfoExtension {
+                description: None,
+                image: None,
+                external_link: None,
+                explicit_content: None,
+                start_trading_time: None,
+                royalty_info: Some(royalty_info),
+            }),
+        };
+        let mut deps = mock_dependencies();
+        contract.instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CREATOR_ADDR, &[]),
+            msg,
+            "contract_name",
+            "contract_version",
+        )
+    };
+
+    // share must not exceed 100%
+    let err = instantiate(RoyaltyInfoMsg {
+        payment_address: "payment_address".to_string(),
+        share: Decimal::percent(101),
+    })
+    .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::InvalidRoyaltyShare {
+            share: Decimal::percent(101)
+        }
+    );
+
+    // payment address must be valid
+    let err = instantiate(RoyaltyInfoMsg {
+        payment_address: "".to_string(),
+        share: Decimal::percent(5),
+    })
+    .unwrap_err();
+    assert!(matches!(err, Cw721ContractError::Std(_)));
+}
+
 #[test]
 fn minting() {
     let mut deps = mock_dependencies();
@@ -177,7 +326,7 @@ fn minting() {
     let token_uri = "https://www.merriam-webster.com/dictionary/petrify".to_string();
 
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner: String::from("medusa"),
         token_uri: Some(token_uri.clone()),
         extension: None,
@@ -234,7 +383,7 @@ fn minting() {
 
     // Cannot mint same token_id again
     let mint_msg2 = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
+        token_id: Some(token_id.clone()),
         owner: String::from("hercules"),
         token_uri: None,
         extension: None,
@@ -248,697 +397,4592 @@ fn minting() {
 
     // list the token_ids
     let tokens = contract
-        .query_all_tokens(deps.as_ref(), env, None, None)
+        .query_all_tokens(deps.as_ref(), env, None, None, None, None)
         .unwrap();
     assert_eq!(1, tokens.tokens.len());
     assert_eq!(vec![token_id], tokens.tokens);
 }
 
 #[test]
-fn test_update_minter() {
+fn batch_minting() {
     let mut deps = mock_dependencies();
     let contract = setup_contract(deps.as_mut());
 
-    let token_id = "petrify".to_string();
-    let token_uri = "https://www.merriam-webster.com/dictionary/petrify".to_string();
-
-    let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id,
-        owner: String::from("medusa"),
-        token_uri: Some(token_uri.clone()),
-        extension: None,
+    let tokens: Vec<MintMsg<DefaultOptionMetadataExtension>> = ["grow1", "grow2", "grow3"]
+        .iter()
+        .map(|token_id| MintMsg {
+            token_id: Some(token_id.to_string()),
+            owner: String::from("demeter"),
+            token_uri: None,
+            extension: None,
+        })
+        .collect();
+    let batch_mint_msg = Cw721ExecuteMsg::BatchMint {
+        tokens: tokens.clone(),
     };
 
-    // Minter can mint
-    let minter_info = mock_info(MINTER_ADDR, &[]);
-    let _ = contract
-        .execute(deps.as_mut(), mock_env(), minter_info.clone(), mint_msg)
-        .unwrap();
-
-    // Update the owner to "random". The new owner should be able to
-    // mint new tokens, the old one should not.
-    contract
+    // random cannot mint
+    let err = contract
         .execute(
             deps.as_mut(),
             mock_env(),
-            minter_info.clone(),
-            Cw721ExecuteMsg::UpdateOwnership(Action::TransferOwnership {
-                new_owner: "random".to_string(),
-                expiry: None,
-            }),
+            mock_info("random", &[]),
+            batch_mint_msg.clone(),
         )
-        .unwrap();
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
 
-    // Minter does not change until ownership transfer completes.
-    // Pending ownership transfer should be discoverable via query.
-    let ownership: Ownership<Addr> = from_json(
-        contract
-            .query(deps.as_ref(), mock_env(), Cw721QueryMsg::Ownership {})
-            .unwrap(),
-    )
-    .unwrap();
+    // empty batch is rejected
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::BatchMint { tokens: vec![] },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::EmptyBatch {});
 
+    // minter can mint
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER_ADDR, &[]),
+            batch_mint_msg,
+        )
+        .unwrap();
     assert_eq!(
-        ownership,
-        Ownership::<Addr> {
-            owner: Some(Addr::unchecked(MINTER_ADDR)),
-            pending_owner: Some(Addr::unchecked("random")),
-            pending_expiry: None,
-        }
+        res,
+        Response::new()
+            .set_data(to_json_binary(&["grow1", "grow2", "grow3"]).unwrap())
+            .add_attribute("action", "batch_mint")
+            .add_attribute("minter", MINTER_ADDR)
+            .add_attribute("count", "3")
+            .add_events(tokens.iter().map(|t| {
+                Cw721Event::Mint {
+                    minter: MINTER_ADDR.to_string(),
+                    owner: "demeter".to_string(),
+                    token_id: t.token_id.clone().unwrap(),
+                    token_uri: None,
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            }))
     );
-
-    // Accept the ownership transfer.
-    let random_info = mock_info("random", &[]);
-    contract
+    let count = contract
+        .query_num_tokens(deps.as_ref(), mock_env())
+        .unwrap();
+    assert_eq!(3, count.count);
+    let owned = contract
+        .query_tokens(
+            deps.as_ref(),
+            mock_env(),
+            "demeter".to_string(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(owned.tokens, vec!["grow1", "grow2", "grow3"]);
+
+    // a single claimed id fails the whole batch
+    let batch_mint_msg = Cw721ExecuteMsg::BatchMint {
+        tokens: vec![
+            MintMsg {
+                token_id: Some("grow4".to_string()),
+                owner: String::from("demeter"),
+                token_uri: None,
+                extension: None,
+            },
+            tokens[0].clone(),
+        ],
+    };
+    let err = contract
         .execute(
             deps.as_mut(),
             mock_env(),
-            random_info.clone(),
-            Cw721ExecuteMsg::UpdateOwnership(Action::AcceptOwnership),
+            mock_info(MINTER_ADDR, &[]),
+            batch_mint_msg,
         )
-        .unwrap();
-
-    // Minter changes after ownership transfer is accepted.
-    let minter_ownership: Ownership<Addr> = from_json(
-        contract
-            .query(deps.as_ref(), mock_env(), Cw721QueryMsg::Ownership {})
-            .unwrap(),
-    )
-    .unwrap();
-    assert_eq!(minter_ownership.owner, Some(random_info.sender.clone()));
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Claimed {});
+}
 
-    let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: "randoms_token".to_string(),
+#[test]
+fn minting_sequential_token_ids() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let mint_msg = |token_id: Option<&str>| Cw721ExecuteMsg::Mint {
+        token_id: token_id.map(|t| t.to_string()),
         owner: String::from("medusa"),
-        token_uri: Some(token_uri),
+        token_uri: None,
         extension: None,
     };
 
-    // Old owner can not mint.
-    let err: Cw721ContractError = contract
-        .execute(deps.as_mut(), mock_env(), minter_info, mint_msg.clone())
+    // token id is required by default
+    let contract = setup_contract(deps.as_mut());
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg(None),
+        )
         .unwrap_err();
-    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
-
-    // New owner can mint.
-    let _ = contract
-        .execute(deps.as_mut(), mock_env(), random_info, mint_msg)
-        .unwrap();
-}
+    assert_eq!(err, Cw721ContractError::TokenIdRequired {});
 
-#[test]
-fn burning() {
     let mut deps = mock_dependencies();
-    let contract = setup_contract(deps.as_mut());
-
-    let token_id = "petrify".to_string();
-    let token_uri = "https://www.merriam-webster.com/dictionary/petrify".to_string();
-
-    let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
-        owner: MINTER_ADDR.to_string(),
-        token_uri: Some(token_uri),
-        extension: None,
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
+    let msg = Cw721InstantiateMsg {
+        name: CONTRACT_NAME.to_string(),
+        symbol: SYMBOL.to_string(),
+        minter: Some(String::from(MINTER_ADDR)),
+        creator: None,
+        withdraw_address: None,
+        metadata_update_authority: None,
+        transfer_policy: None,
+        sequential_token_ids: Some(SequentialTokenIds {
+            prefix: Some("ticket-".to_string()),
+            start: Some(1),
+        }),
+        max_supply: None,
+        index_traits: None,
+        collection_info_extension: None,
     };
+    contract
+        .instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            msg,
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
 
-    let burn_msg = Cw721ExecuteMsg::Burn { token_id };
+    // assigned token id is returned in data and attributes
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg(None),
+        )
+        .unwrap();
+    assert_eq!(res.data, Some(to_json_binary("ticket-1").unwrap()));
+    assert!(res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "token_id" && attr.value == "ticket-1"));
 
-    // mint some NFT
-    let allowed = mock_info(MINTER_ADDR, &[]);
-    let _ = contract
-        .execute(deps.as_mut(), mock_env(), allowed.clone(), mint_msg)
+    // explicit token ids can still be minted, and are skipped by the counter
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg(Some("ticket-2")),
+        )
+        .unwrap();
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg(None),
+        )
         .unwrap();
+    assert_eq!(res.data, Some(to_json_binary("ticket-3").unwrap()));
 
-    // random not allowed to burn
-    let random = mock_info("random", &[]);
-    let env = mock_env();
+    // only the minter can mint, also without token id
     let err = contract
-        .execute(deps.as_mut(), env.clone(), random, burn_msg.clone())
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("random", &[]),
+            mint_msg(None),
+        )
         .unwrap_err();
-
     assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
 
-    let _ = contract
-        .execute(deps.as_mut(), env.clone(), allowed, burn_msg)
-        .unwrap();
-
-    // ensure num tokens decreases
-    let count = contract
-        .query_num_tokens(deps.as_ref(), env.clone())
+    // batch mint assigns omitted token ids in order
+    let tokens = [None, Some("ticket-5"), None]
+        .into_iter()
+        .map(|token_id| MintMsg {
+            token_id: token_id.map(|t| t.to_string()),
+            owner: String::from("medusa"),
+            token_uri: None,
+            extension: None,
+        })
+        .collect();
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::BatchMint { tokens },
+        )
         .unwrap();
-    assert_eq!(0, count.count);
-
-    // trying to get nft returns error
-    let _ = contract
-        .query_nft_info(deps.as_ref(), env.clone(), "petrify".to_string())
-        .unwrap_err();
+    assert_eq!(
+        res.data,
+        Some(to_json_binary(&["ticket-4", "ticket-5", "ticket-6"]).unwrap())
+    );
 
-    // list the token_ids
     let tokens = contract
-        .query_all_tokens(deps.as_ref(), env, None, None)
+        .query_all_tokens(deps.as_ref(), env, None, None, None, None)
         .unwrap();
-    assert!(tokens.tokens.is_empty());
+    assert_eq!(
+        tokens.tokens,
+        vec!["ticket-1", "ticket-2", "ticket-3", "ticket-4", "ticket-5", "ticket-6"]
+    );
 }
 
 #[test]
-fn transferring_nft() {
+fn max_supply() {
     let mut deps = mock_dependencies();
-    let contract = setup_contract(deps.as_mut());
-
-    // Mint a token
-    let token_id = "melt".to_string();
-    let token_uri = "https://www.merriam-webster.com/dictionary/melt".to_string();
-
-    let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
-        owner: String::from("venus"),
-        token_uri: Some(token_uri),
-        extension: None,
+    let env = mock_env();
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
+    let msg = Cw721InstantiateMsg {
+        name: CONTRACT_NAME.to_string(),
+        symbol: SYMBOL.to_string(),
+        minter: Some(String::from(MINTER_ADDR)),
+        creator: None,
+        withdraw_address: None,
+        metadata_update_authority: None,
+        transfer_policy: None,
+        sequential_token_ids: None,
+        max_supply: Some(3),
+        index_traits: None,
+        collection_info_extension: None,
     };
-
-    let minter = mock_info(MINTER_ADDR, &[]);
     contract
-        .execute(deps.as_mut(), mock_env(), minter, mint_msg)
+        .instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            msg,
+            "contract_name",
+            "contract_version",
+        )
         .unwrap();
+    let mint_msg = |token_id: &str| MintMsg {
+        token_id: Some(token_id.to_string()),
+        owner: String::from("medusa"),
+        token_uri: None,
+        extension: None,
+    };
 
-    // random cannot transfer
-    let random = mock_info("random", &[]);
-    let transfer_msg = Cw721ExecuteMsg::TransferNft {
-        recipient: String::from("random"),
-        token_id: token_id.clone(),
+    // burned tokens still count towards max supply
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::BatchMint {
+                tokens: vec![mint_msg("1"), mint_msg("2")],
+            },
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("medusa", &[]),
+            Cw721ExecuteMsg::Burn {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+    let supply = contract.query_supply(deps.as_ref()).unwrap();
+    assert_eq!(
+        supply,
+        SupplyResponse {
+            max_supply: Some(3),
+            total_minted: 2,
+            total_burned: 1,
+        }
+    );
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::BatchMint {
+                tokens: vec![mint_msg("3"), mint_msg("4")],
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::MaxSupplyReached { max_supply: 3 });
+
+    // only creator can update max supply, and only lower it
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::UpdateMaxSupply { max_supply: 2 },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::UpdateMaxSupply { max_supply: 4 },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::MaxSupplyRaised { max_supply: 3 });
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::UpdateMaxSupply { max_supply: 1 },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::MaxSupplyBelowMinted { total_minted: 2 }
+    );
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::UpdateMaxSupply { max_supply: 2 },
+        )
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::Mint {
+                token_id: Some("3".to_string()),
+                owner: String::from("medusa"),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::MaxSupplyReached { max_supply: 2 });
+
+    // without max supply, minting is unlimited
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+    let supply = contract.query_supply(deps.as_ref()).unwrap();
+    assert_eq!(supply.max_supply, None);
+    contract
+        .execute(
+            deps.as_mut(),
+            env,
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::UpdateMaxSupply { max_supply: 100 },
+        )
+        .unwrap();
+    let supply = contract.query_supply(deps.as_ref()).unwrap();
+    assert_eq!(supply.max_supply, Some(100));
+}
+
+#[test]
+fn test_update_minter() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    let token_id = "petrify".to_string();
+    let token_uri = "https://www.merriam-webster.com/dictionary/petrify".to_string();
+
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: Some(token_id),
+        owner: String::from("medusa"),
+        token_uri: Some(token_uri.clone()),
+        extension: None,
+    };
+
+    // Minter can mint
+    let minter_info = mock_info(MINTER_ADDR, &[]);
+    let _ = contract
+        .execute(deps.as_mut(), mock_env(), minter_info.clone(), mint_msg)
+        .unwrap();
+
+    // Update the owner to "random". The new owner should be able to
+    // mint new tokens, the old one should not.
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            minter_info.clone(),
+            Cw721ExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+                new_owner: "random".to_string(),
+                expiry: None,
+            }),
+        )
+        .unwrap();
+
+    // Minter does not change until ownership transfer completes.
+    // Pending ownership transfer should be discoverable via query.
+    let ownership: Ownership<Addr> = from_json(
+        contract
+            .query(deps.as_ref(), mock_env(), Cw721QueryMsg::Ownership {})
+            .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        ownership,
+        Ownership::<Addr> {
+            owner: Some(Addr::unchecked(MINTER_ADDR)),
+            pending_owner: Some(Addr::unchecked("random")),
+            pending_expiry: None,
+        }
+    );
+
+    // Accept the ownership transfer.
+    let random_info = mock_info("random", &[]);
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            random_info.clone(),
+            Cw721ExecuteMsg::UpdateOwnership(Action::AcceptOwnership),
+        )
+        .unwrap();
+
+    // Minter changes after ownership transfer is accepted.
+    let minter_ownership: Ownership<Addr> = from_json(
+        contract
+            .query(deps.as_ref(), mock_env(), Cw721QueryMsg::Ownership {})
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(minter_ownership.owner, Some(random_info.sender.clone()));
+
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: Some("randoms_token".to_string()),
+        owner: String::from("medusa"),
+        token_uri: Some(token_uri),
+        extension: None,
     };
 
+    // Old owner can not mint.
+    let err: Cw721ContractError = contract
+        .execute(deps.as_mut(), mock_env(), minter_info, mint_msg.clone())
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // New owner can mint.
+    let _ = contract
+        .execute(deps.as_mut(), mock_env(), random_info, mint_msg)
+        .unwrap();
+}
+
+#[test]
+fn test_update_creator_ownership() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    // creator and minter are separate roles
+    let creator_ownership: Ownership<Addr> = from_json(
+        contract
+            .query(
+                deps.as_ref(),
+                mock_env(),
+                Cw721QueryMsg::GetCreatorOwnership {},
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(creator_ownership.owner, Some(Addr::unchecked(CREATOR_ADDR)));
+    let minter_ownership: Ownership<Addr> = from_json(
+        contract
+            .query(
+                deps.as_ref(),
+                mock_env(),
+                Cw721QueryMsg::GetMinterOwnership {},
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(minter_ownership.owner, Some(Addr::unchecked(MINTER_ADDR)));
+
+    // minter cannot transfer creator ownership
+    let err: Cw721ContractError = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::UpdateCreatorOwnership(Action::TransferOwnership {
+                new_owner: "random".to_string(),
+                expiry: None,
+            }),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // creator can transfer, new creator accepts
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::UpdateCreatorOwnership(Action::TransferOwnership {
+                new_owner: "random".to_string(),
+                expiry: None,
+            }),
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("random", &[]),
+            Cw721ExecuteMsg::UpdateCreatorOwnership(Action::AcceptOwnership),
+        )
+        .unwrap();
+
+    let creator_ownership: Ownership<Addr> = from_json(
+        contract
+            .query(
+                deps.as_ref(),
+                mock_env(),
+                Cw721QueryMsg::GetCreatorOwnership {},
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(creator_ownership.owner, Some(Addr::unchecked("random")));
+
+    // minter is unchanged
+    let minter_ownership: Ownership<Addr> = from_json(
+        contract
+            .query(
+                deps.as_ref(),
+                mock_env(),
+                Cw721QueryMsg::GetMinterOwnership {},
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(minter_ownership.owner, Some(Addr::unchecked(MINTER_ADDR)));
+
+    // old creator can no longer set withdraw address, new creator can
     let err = contract
-        .execute(deps.as_mut(), mock_env(), random, transfer_msg)
+        .set_withdraw_address(
+            deps.as_mut(),
+            &Addr::unchecked(CREATOR_ADDR),
+            "foo".to_string(),
+        )
         .unwrap_err();
     assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    contract
+        .set_withdraw_address(deps.as_mut(), &Addr::unchecked("random"), "foo".to_string())
+        .unwrap();
+}
 
-    // owner can
-    let random = mock_info("venus", &[]);
-    let transfer_msg = Cw721ExecuteMsg::TransferNft {
-        recipient: String::from("random"),
-        token_id: token_id.clone(),
+#[test]
+fn test_update_collection_info() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+    let env = mock_env();
+
+    // only creator can update
+    let update_msg = Cw721ExecuteMsg::UpdateCollectionInfo {
+        name: Some("new name".to_string()),
+        symbol: None,
+        freeze: None,
     };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            update_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
 
+    // name and symbol must not be empty
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::UpdateCollectionInfo {
+                name: Some(" ".to_string()),
+                symbol: None,
+                freeze: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::CollectionNameEmpty {});
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::UpdateCollectionInfo {
+                name: None,
+                symbol: Some("".to_string()),
+                freeze: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::CollectionSymbolEmpty {});
+
+    // creator can update, symbol is left untouched
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            update_msg,
+        )
+        .unwrap();
+    let collection_info = contract
+        .query_collection_info(deps.as_ref(), env.clone())
+        .unwrap();
+    assert_eq!(
+        collection_info,
+        CollectionInfo {
+            name: "new name".to_string(),
+            symbol: SYMBOL.to_string(),
+        }
+    );
+
+    // update and freeze
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::UpdateCollectionInfo {
+                name: None,
+                symbol: Some("NEW".to_string()),
+                freeze: Some(true),
+            },
+        )
+        .unwrap();
+    let collection_info = contract
+        .query_collection_info(deps.as_ref(), env.clone())
+        .unwrap();
+    assert_eq!(collection_info.symbol, "NEW".to_string());
+
+    // frozen collection info can't be updated anymore
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env,
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::UpdateCollectionInfo {
+                name: Some("newer name".to_string()),
+                symbol: None,
+                freeze: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::CollectionInfoFrozen {});
+}
+
+#[test]
+fn test_update_metadata_extension() {
+    let mut deps = mock_dependencies();
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        DefaultOptionMetadataExtensionMsg,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
+    let msg = Cw721InstantiateMsg {
+        name: CONTRACT_NAME.to_string(),
+        symbol: SYMBOL.to_string(),
+        minter: Some(String::from(MINTER_ADDR)),
+        creator: None,
+        withdraw_address: None,
+        metadata_update_authority: Some(MetadataUpdateAuthority::TokenOwner),
+        transfer_policy: None,
+        sequential_token_ids: None,
+        max_supply: None,
+        index_traits: None,
+        collection_info_extension: None,
+    };
+    contract
+        .instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            msg,
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+
+    let token_id = "sword".to_string();
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: Some(token_id.clone()),
+        owner: String::from("medusa"),
+        token_uri: Some("https://example.com/sword.json".to_string()),
+        extension: Some(Metadata {
+            name: Some("Sword".to_string()),
+            ..Metadata::default()
+        }),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg,
+        )
+        .unwrap();
+
+    let upgraded = Some(Metadata {
+        name: Some("Sword +1".to_string()),
+        ..Metadata::default()
+    });
+    let update_msg = Cw721ExecuteMsg::Extension {
+        msg: UpdateNftInfoMsg {
+            token_id: token_id.clone(),
+            token_uri: None,
+            extension: Some(upgraded.clone()),
+        },
+    };
+
+    // minter is not the token owner and cannot update
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER_ADDR, &[]),
+            update_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // token owner can update, token_uri is left untouched
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("medusa", &[]),
+            update_msg,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_event(
+                Cw721Event::UpdateMetadata {
+                    sender: "medusa".to_string(),
+                    token_id: token_id.clone(),
+                    token_uri: Some("https://example.com/sword.json".to_string()),
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
+            .add_attribute("action", "update_metadata_extension")
+            .add_attribute("sender", "medusa")
+            .add_attribute("token_id", token_id.clone())
+            .add_attribute("token_uri", "https://example.com/sword.json")
+    );
+    let info = contract
+        .query_nft_info(deps.as_ref(), mock_env(), token_id.clone())
+        .unwrap();
+    assert_eq!(
+        info,
+        NftInfoResponse {
+            token_uri: Some("https://example.com/sword.json".to_string()),
+            extension: upgraded.clone(),
+        }
+    );
+
+    // empty token_uri removes it
+    let update_msg = Cw721ExecuteMsg::Extension {
+        msg: UpdateNftInfoMsg {
+            token_id: token_id.clone(),
+            token_uri: Some("".to_string()),
+            extension: None,
+        },
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("medusa", &[]),
+            update_msg,
+        )
+        .unwrap();
+    let info = contract
+        .query_nft_info(deps.as_ref(), mock_env(), token_id)
+        .unwrap();
+    assert_eq!(
+        info,
+        NftInfoResponse {
+            token_uri: None,
+            extension: upgraded,
+        }
+    );
+}
+
+#[test]
+fn trait_index() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        DefaultOptionMetadataExtensionMsg,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
+    let msg = Cw721InstantiateMsg {
+        name: CONTRACT_NAME.to_string(),
+        symbol: SYMBOL.to_string(),
+        minter: Some(String::from(MINTER_ADDR)),
+        creator: None,
+        withdraw_address: None,
+        metadata_update_authority: Some(MetadataUpdateAuthority::TokenOwner),
+        transfer_policy: None,
+        sequential_token_ids: None,
+        max_supply: None,
+        index_traits: Some(true),
+        collection_info_extension: None,
+    };
+    contract
+        .instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            msg,
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+    let metadata = |traits: &[(&str, &str)]| {
+        Some(Metadata {
+            attributes: Some(
+                traits
+                    .iter()
+                    .map(|(trait_type, value)| Trait {
+                        display_type: None,
+                        trait_type: trait_type.to_string(),
+                        value: value.to_string(),
+                    })
+                    .collect(),
+            ),
+            ..Metadata::default()
+        })
+    };
+    let mint_msg = |token_id: &str, traits: &[(&str, &str)]| MintMsg {
+        token_id: Some(token_id.to_string()),
+        owner: String::from("medusa"),
+        token_uri: None,
+        extension: metadata(traits),
+    };
+    let trait_count = |value: &str, count: u64| TraitCount {
+        trait_type: "Background".to_string(),
+        value: value.to_string(),
+        count,
+    };
+
+    // duplicate attributes of a token are counted once
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::BatchMint {
+                tokens: vec![
+                    mint_msg("1", &[("Background", "Gold"), ("Eyes", "Blue")]),
+                    mint_msg("2", &[("Background", "Gold")]),
+                    mint_msg("3", &[("Background", "Silver"), ("Background", "Silver")]),
+                ],
+            },
+        )
+        .unwrap();
+    let tokens = contract
+        .query_tokens_by_trait(
+            deps.as_ref(),
+            env.clone(),
+            "Background".to_string(),
+            "Gold".to_string(),
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(tokens.tokens, vec!["1", "2"]);
+    let tokens = contract
+        .query_tokens_by_trait(
+            deps.as_ref(),
+            env.clone(),
+            "Background".to_string(),
+            "Gold".to_string(),
+            Some("1".to_string()),
+            None,
+        )
+        .unwrap();
+    assert_eq!(tokens.tokens, vec!["2"]);
+    let counts = contract
+        .query_trait_counts(deps.as_ref(), "Background".to_string(), None, None)
+        .unwrap();
+    assert_eq!(
+        counts.traits,
+        vec![trait_count("Gold", 2), trait_count("Silver", 1)]
+    );
+
+    // metadata update moves the token to its new attributes
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("medusa", &[]),
+            Cw721ExecuteMsg::Extension {
+                msg: UpdateNftInfoMsg {
+                    token_id: "2".to_string(),
+                    token_uri: None,
+                    extension: Some(metadata(&[("Background", "Silver")])),
+                },
+            },
+        )
+        .unwrap();
+    let counts = contract
+        .query_trait_counts(deps.as_ref(), "Background".to_string(), None, None)
+        .unwrap();
+    assert_eq!(
+        counts.traits,
+        vec![trait_count("Gold", 1), trait_count("Silver", 2)]
+    );
+
+    // burn removes the token, counts dropping to zero are removed
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("medusa", &[]),
+            Cw721ExecuteMsg::Burn {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+    let tokens = contract
+        .query_tokens_by_trait(
+            deps.as_ref(),
+            env.clone(),
+            "Background".to_string(),
+            "Gold".to_string(),
+            None,
+            None,
+        )
+        .unwrap();
+    assert!(tokens.tokens.is_empty());
+    let counts = contract
+        .query_trait_counts(deps.as_ref(), "Background".to_string(), None, None)
+        .unwrap();
+    assert_eq!(counts.traits, vec![trait_count("Silver", 2)]);
+    let counts = contract
+        .query_trait_counts(deps.as_ref(), "Eyes".to_string(), None, None)
+        .unwrap();
+    assert!(counts.traits.is_empty());
+
+    // nothing is indexed, unless enabled on instantiation
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::BatchMint {
+                tokens: vec![mint_msg("1", &[("Background", "Gold")])],
+            },
+        )
+        .unwrap();
+    let counts = contract
+        .query_trait_counts(deps.as_ref(), "Background".to_string(), None, None)
+        .unwrap();
+    assert!(counts.traits.is_empty());
+}
+
+#[test]
+fn royalties() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+    let env = mock_env();
+    for token_id in ["1", "2"] {
+        contract
+            .execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(MINTER_ADDR, &[]),
+                Cw721ExecuteMsg::Mint {
+                    token_id: Some(token_id.to_string()),
+                    owner: MINTER_ADDR.to_string(),
+                    token_uri: None,
+                    extension: None,
+                },
+            )
+            .unwrap();
+    }
+    let royalty = |payment_address: &str, percent: u64| RoyaltyInfoMsg {
+        payment_address: payment_address.to_string(),
+        share: Decimal::percent(percent),
+    };
+    let royalty_info = |deps: Deps, token_id: &str| {
+        contract
+            .query_royalty_info(deps, mock_env(), token_id.to_string(), Uint128::new(1_010))
+            .unwrap()
+    };
+
+    // nothing set
+    let res = royalty_info(deps.as_ref(), "1");
+    assert_eq!(res.address, "");
+    assert_eq!(res.royalty_amount, Uint128::zero());
+    assert!(
+        !contract
+            .query_check_royalties(deps.as_ref())
+            .unwrap()
+            .royalty_payments
+    );
+
+    // only creator can set royalties
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::SetCollectionRoyalty {
+                royalty: Some(royalty("artist", 5)),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // share must not exceed 100%
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::SetCollectionRoyalty {
+                royalty: Some(royalty("artist", 101)),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::InvalidRoyaltyShare {
+            share: Decimal::percent(101)
+        }
+    );
+
+    // collection royalty applies to all tokens, rounding down
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::SetCollectionRoyalty {
+                royalty: Some(royalty("artist", 5)),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.attributes
+            .iter()
+            .find(|attr| attr.key == "share")
+            .unwrap()
+            .value,
+        "0.05"
+    );
+    for token_id in ["1", "2"] {
+        let res = royalty_info(deps.as_ref(), token_id);
+        assert_eq!(res.address, "artist");
+        assert_eq!(res.royalty_amount, Uint128::new(50));
+    }
+    assert!(
+        contract
+            .query_check_royalties(deps.as_ref())
+            .unwrap()
+            .royalty_payments
+    );
+
+    // token royalty overrides collection royalty
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::SetTokenRoyalty {
+                token_id: "1".to_string(),
+                royalty: Some(royalty("collab", 10)),
+            },
+        )
+        .unwrap();
+    let res = royalty_info(deps.as_ref(), "1");
+    assert_eq!(res.address, "collab");
+    assert_eq!(res.royalty_amount, Uint128::new(101));
+    let res = royalty_info(deps.as_ref(), "2");
+    assert_eq!(res.address, "artist");
+
+    // removing the override falls back to the collection royalty
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::SetTokenRoyalty {
+                token_id: "1".to_string(),
+                royalty: None,
+            },
+        )
+        .unwrap();
+    let res = royalty_info(deps.as_ref(), "1");
+    assert_eq!(res.address, "artist");
+    assert_eq!(res.royalty_amount, Uint128::new(50));
+
+    // token must exist
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::SetTokenRoyalty {
+                token_id: "unknown".to_string(),
+                royalty: Some(royalty("collab", 10)),
+            },
+        )
+        .unwrap_err();
+    contract
+        .query_royalty_info(
+            deps.as_ref(),
+            env.clone(),
+            "unknown".to_string(),
+            Uint128::new(1_010),
+        )
+        .unwrap_err();
+
+    // burning removes the token royalty
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::SetTokenRoyalty {
+                token_id: "2".to_string(),
+                royalty: Some(royalty("collab", 10)),
+            },
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::Burn {
+                token_id: "2".to_string(),
+            },
+        )
+        .unwrap();
+    let config = Cw721Config::<DefaultOptionMetadataExtension, Empty, Empty, Empty>::default();
+    assert!(!config.token_royalties.has(deps.as_ref().storage, "2"));
+
+    // removing the collection royalty disables royalty payments
+    contract
+        .execute(
+            deps.as_mut(),
+            env,
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::SetCollectionRoyalty { royalty: None },
+        )
+        .unwrap();
+    assert!(
+        !contract
+            .query_check_royalties(deps.as_ref())
+            .unwrap()
+            .royalty_payments
+    );
+}
+
+#[test]
+fn royalties_fall_back_to_collection_info_extension() {
+    let mut deps = mock_dependencies();
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
+    let msg = Cw721InstantiateMsg {
+        name: CONTRACT_NAME.to_string(),
+        symbol: SYMBOL.to_string(),
+        minter: Some(String::from(MINTER_ADDR)),
+        creator: None,
+        withdraw_address: None,
+        metadata_update_authority: None,
+        transfer_policy: None,
+        sequential_token_ids: None,
+        max_supply: None,
+        index_traits: None,
+        collection_info_extension: Some(CollectionInfoExtension {
+            description: None,
+            image: None,
+            external_link: None,
+            explicit_content: None,
+            start_trading_time: None,
+            royalty_info: Some(RoyaltyInfoMsg {
+                payment_address: "artist".to_string(),
+                share: Decimal::percent(5),
+            }),
+        }),
+    };
+    let env = mock_env();
+    contract
+        .instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            msg,
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::Mint {
+                token_id: Some("1".to_string()),
+                owner: MINTER_ADDR.to_string(),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap();
+
+    // royalty info set on instantiation applies
+    let res = contract
+        .query_royalty_info(
+            deps.as_ref(),
+            env.clone(),
+            "1".to_string(),
+            Uint128::new(100),
+        )
+        .unwrap();
+    assert_eq!(res.address, "artist");
+    assert_eq!(res.royalty_amount, Uint128::new(5));
+    assert!(
+        contract
+            .query_check_royalties(deps.as_ref())
+            .unwrap()
+            .royalty_payments
+    );
+
+    // collection royalty takes precedence
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::SetCollectionRoyalty {
+                royalty: Some(RoyaltyInfoMsg {
+                    payment_address: "collab".to_string(),
+                    share: Decimal::percent(10),
+                }),
+            },
+        )
+        .unwrap();
+    let res = contract
+        .query_royalty_info(deps.as_ref(), env, "1".to_string(), Uint128::new(100))
+        .unwrap();
+    assert_eq!(res.address, "collab");
+    assert_eq!(res.royalty_amount, Uint128::new(10));
+}
+
+#[test]
+fn burning() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    let token_id = "petrify".to_string();
+    let token_uri = "https://www.merriam-webster.com/dictionary/petrify".to_string();
+
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: Some(token_id.clone()),
+        owner: MINTER_ADDR.to_string(),
+        token_uri: Some(token_uri),
+        extension: None,
+    };
+
+    let burn_msg = Cw721ExecuteMsg::Burn { token_id };
+
+    // mint some NFT
+    let allowed = mock_info(MINTER_ADDR, &[]);
+    let _ = contract
+        .execute(deps.as_mut(), mock_env(), allowed.clone(), mint_msg)
+        .unwrap();
+
+    // random not allowed to burn
+    let random = mock_info("random", &[]);
+    let env = mock_env();
+    let err = contract
+        .execute(deps.as_mut(), env.clone(), random, burn_msg.clone())
+        .unwrap_err();
+
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    let _ = contract
+        .execute(deps.as_mut(), env.clone(), allowed.clone(), burn_msg)
+        .unwrap();
+
+    // ensure num tokens decreases
+    let count = contract
+        .query_num_tokens(deps.as_ref(), env.clone())
+        .unwrap();
+    assert_eq!(0, count.count);
+
+    // trying to get nft returns error
+    let _ = contract
+        .query_nft_info(deps.as_ref(), env.clone(), "petrify".to_string())
+        .unwrap_err();
+
+    // list the token_ids
+    let tokens = contract
+        .query_all_tokens(deps.as_ref(), env.clone(), None, None, None, None)
+        .unwrap();
+    assert!(tokens.tokens.is_empty());
+
+    // burned token is tombstoned
+    assert!(contract
+        .query_is_burned(deps.as_ref(), "petrify".to_string())
+        .unwrap());
+    assert!(!contract
+        .query_is_burned(deps.as_ref(), "unknown".to_string())
+        .unwrap());
+    let burned = contract
+        .query_burned_tokens(deps.as_ref(), None, None)
+        .unwrap();
+    assert_eq!(
+        burned,
+        BurnedTokensResponse {
+            tokens: vec![BurnedTokenResponse {
+                token_id: "petrify".to_string(),
+                burner: Addr::unchecked(MINTER_ADDR),
+                height: env.block.height,
+                time: env.block.time,
+            }]
+        }
+    );
+
+    // burned token id can't be minted again
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env,
+            allowed,
+            Cw721ExecuteMsg::Mint {
+                token_id: Some("petrify".to_string()),
+                owner: String::from("random"),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::TokenBurned {
+            token_id: "petrify".to_string()
+        }
+    );
+}
+
+#[test]
+fn transferring_nft() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    // Mint a token
+    let token_id = "melt".to_string();
+    let token_uri = "https://www.merriam-webster.com/dictionary/melt".to_string();
+
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: Some(token_id.clone()),
+        owner: String::from("venus"),
+        token_uri: Some(token_uri),
+        extension: None,
+    };
+
+    let minter = mock_info(MINTER_ADDR, &[]);
+    contract
+        .execute(deps.as_mut(), mock_env(), minter, mint_msg)
+        .unwrap();
+
+    // random cannot transfer
+    let random = mock_info("random", &[]);
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: String::from("random"),
+        token_id: token_id.clone(),
+    };
+
+    let err = contract
+        .execute(deps.as_mut(), mock_env(), random, transfer_msg)
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // owner can
+    let random = mock_info("venus", &[]);
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: String::from("random"),
+        token_id: token_id.clone(),
+    };
+
+    let res = contract
+        .execute(deps.as_mut(), mock_env(), random, transfer_msg)
+        .unwrap();
+
+    assert_eq!(
+        res,
+        Response::new()
+            .add_event(
+                Cw721Event::Transfer {
+                    sender: "venus".to_string(),
+                    from: "venus".to_string(),
+                    to: "random".to_string(),
+                    token_id: token_id.clone(),
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
+            .add_attribute("action", "transfer_nft")
+            .add_attribute("sender", "venus")
+            .add_attribute("recipient", "random")
+            .add_attribute("token_id", token_id)
+    );
+}
+
+#[test]
+fn sending_nft() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    // Mint a token
+    let token_id = "melt".to_string();
+    let token_uri = "https://www.merriam-webster.com/dictionary/melt".to_string();
+
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: Some(token_id.clone()),
+        owner: String::from("venus"),
+        token_uri: Some(token_uri),
+        extension: None,
+    };
+
+    let minter = mock_info(MINTER_ADDR, &[]);
+    contract
+        .execute(deps.as_mut(), mock_env(), minter, mint_msg)
+        .unwrap();
+
+    let msg = to_json_binary("You now have the melting power").unwrap();
+    let target = String::from("another_contract");
+    let send_msg = Cw721ExecuteMsg::SendNft {
+        contract: target.clone(),
+        token_id: token_id.clone(),
+        msg: msg.clone(),
+        receive_msg_version: None,
+    };
+
+    let random = mock_info("random", &[]);
+    let err = contract
+        .execute(deps.as_mut(), mock_env(), random, send_msg.clone())
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // but owner can
+    let random = mock_info("venus", &[]);
+    let res = contract
+        .execute(deps.as_mut(), mock_env(), random, send_msg)
+        .unwrap();
+
+    let payload = Cw721ReceiveMsg {
+        sender: String::from("venus"),
+        token_id: token_id.clone(),
+        msg,
+    };
+    let expected = payload.into_cosmos_msg(target.clone()).unwrap();
+    // ensure expected serializes as we think it should
+    match &expected {
+        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) => {
+            assert_eq!(contract_addr, &target)
+        }
+        m => panic!("Unexpected message type: {m:?}"),
+    }
+    // and make sure this is the request sent by the contract
+    assert_eq!(
+        res,
+        Response::new()
+            .add_message(expected)
+            .add_event(
+                Cw721Event::Send {
+                    sender: "venus".to_string(),
+                    from: "venus".to_string(),
+                    to: "another_contract".to_string(),
+                    token_id: token_id.clone(),
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
+            .add_attribute("action", "send_nft")
+            .add_attribute("sender", "venus")
+            .add_attribute("recipient", "another_contract")
+            .add_attribute("token_id", token_id)
+    );
+}
+
+#[test]
+fn sending_nft_v2() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    let token_id = "melt".to_string();
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: Some(token_id.clone()),
+        owner: String::from("venus"),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg,
+        )
+        .unwrap();
+
+    // venus allows random to send the token
+    let approve_msg = Cw721ExecuteMsg::Approve {
+        spender: String::from("random"),
+        token_id: token_id.clone(),
+        expires: None,
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            approve_msg,
+        )
+        .unwrap();
+
+    // operator sends with funds, receiver gets previous owner, caller, collection and funds
+    let msg = to_json_binary("You now have the melting power").unwrap();
+    let funds = vec![Coin::new(100, "uark")];
+    let send_msg = Cw721ExecuteMsg::SendNft {
+        contract: String::from("another_contract"),
+        token_id: token_id.clone(),
+        msg: msg.clone(),
+        receive_msg_version: Some(ReceiveMsgVersion::V2),
+    };
+    let env = mock_env();
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("random", &funds),
+            send_msg,
+        )
+        .unwrap();
+
+    let payload = Cw721ReceiveMsgV2 {
+        sender: String::from("random"),
+        owner: String::from("venus"),
+        token_id: token_id.clone(),
+        collection: Cw721ReceiveCollection {
+            address: env.contract.address.to_string(),
+            name: CONTRACT_NAME.to_string(),
+            symbol: SYMBOL.to_string(),
+        },
+        funds: funds.clone(),
+        msg,
+    };
+    let expected = payload.clone().into_cosmos_msg("another_contract").unwrap();
+    // ensure expected serializes as we think it should
+    match &expected {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds: sent_funds,
+        }) => {
+            assert_eq!(contract_addr, "another_contract");
+            assert_eq!(sent_funds, &funds);
+            assert_eq!(msg, &payload.clone().into_json_binary().unwrap());
+            assert!(msg.as_slice().starts_with(br#"{"receive_nft_v2":"#));
+        }
+        m => panic!("Unexpected message type: {m:?}"),
+    }
+    assert_eq!(res.messages, vec![SubMsg::new(expected)]);
+
+    // token has moved
+    let owner = contract
+        .query_owner_of(deps.as_ref(), env, token_id, false)
+        .unwrap();
+    assert_eq!(owner.owner, "another_contract");
+}
+
+#[test]
+fn safe_transferring_nft() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+    // only "another_contract" and "third_contract" are contracts, "unreachable" can't be queried
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::ContractInfo { contract_addr }
+            if contract_addr == "another_contract" || contract_addr == "third_contract" =>
+        {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&ContractInfoResponse::new(1, "creator")).unwrap(),
+            ))
+        }
+        WasmQuery::ContractInfo { contract_addr } if contract_addr == "unreachable" => {
+            SystemResult::Err(SystemError::Unknown {})
+        }
+        WasmQuery::ContractInfo { contract_addr } => {
+            SystemResult::Err(SystemError::NoSuchContract {
+                addr: contract_addr.clone(),
+            })
+        }
+        _ => SystemResult::Err(SystemError::UnsupportedRequest {
+            kind: "wasm".to_string(),
+        }),
+    });
+
+    let token_id = "melt".to_string();
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: Some(token_id.clone()),
+        owner: String::from("venus"),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg,
+        )
+        .unwrap();
+
+    // random cannot transfer
+    let transfer_msg = Cw721ExecuteMsg::SafeTransferNft {
+        recipient: String::from("random"),
+        token_id: token_id.clone(),
+        msg: None,
+        receive_msg_version: None,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("random", &[]),
+            transfer_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // other querier errors fail, instead of treating the recipient as an account
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            Cw721ExecuteMsg::SafeTransferNft {
+                recipient: String::from("unreachable"),
+                token_id: token_id.clone(),
+                msg: None,
+                receive_msg_version: None,
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(err, Cw721ContractError::Std(_)));
+
+    // funds are only forwarded to contracts with V2, otherwise they are rejected
+    let funds = vec![Coin::new(100, "uark")];
+    for (recipient, receive_msg_version) in [
+        ("random", Some(ReceiveMsgVersion::V2)),
+        ("another_contract", None),
+    ] {
+        let err = contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("venus", &funds),
+                Cw721ExecuteMsg::SafeTransferNft {
+                    recipient: recipient.to_string(),
+                    token_id: token_id.clone(),
+                    msg: None,
+                    receive_msg_version,
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err, Cw721ContractError::FundsNotForwarded {});
+    }
+
+    // account recipient: plain transfer, no receive message
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            transfer_msg,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_event(
+                Cw721Event::Transfer {
+                    sender: "venus".to_string(),
+                    from: "venus".to_string(),
+                    to: "random".to_string(),
+                    token_id: token_id.clone(),
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
+            .add_attribute("action", "safe_transfer_nft")
+            .add_attribute("sender", "venus")
+            .add_attribute("recipient", "random")
+            .add_attribute("token_id", token_id.clone())
+            .add_attribute("receiver_notified", "false")
+    );
+
+    // contract recipient: receiver is notified, like on send
+    let msg = to_json_binary("You now have the melting power").unwrap();
+    let transfer_msg = Cw721ExecuteMsg::SafeTransferNft {
+        recipient: String::from("another_contract"),
+        token_id: token_id.clone(),
+        msg: Some(msg.clone()),
+        receive_msg_version: None,
+    };
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("random", &[]),
+            transfer_msg,
+        )
+        .unwrap();
+    let expected = Cw721ReceiveMsg {
+        sender: String::from("random"),
+        token_id: token_id.clone(),
+        msg,
+    }
+    .into_cosmos_msg("another_contract")
+    .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_message(expected)
+            .add_event(
+                Cw721Event::Send {
+                    sender: "random".to_string(),
+                    from: "random".to_string(),
+                    to: "another_contract".to_string(),
+                    token_id: token_id.clone(),
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
+            .add_attribute("action", "safe_transfer_nft")
+            .add_attribute("sender", "random")
+            .add_attribute("recipient", "another_contract")
+            .add_attribute("token_id", token_id.clone())
+            .add_attribute("receiver_notified", "true")
+    );
+
+    // with V2, the receiver gets the previous owner, collection and funds, like on send
+    let transfer_msg = Cw721ExecuteMsg::SafeTransferNft {
+        recipient: String::from("third_contract"),
+        token_id: token_id.clone(),
+        msg: None,
+        receive_msg_version: Some(ReceiveMsgVersion::V2),
+    };
+    let env = mock_env();
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("another_contract", &funds),
+            transfer_msg,
+        )
+        .unwrap();
+    let expected = Cw721ReceiveMsgV2 {
+        sender: String::from("another_contract"),
+        owner: String::from("another_contract"),
+        token_id,
+        collection: Cw721ReceiveCollection {
+            address: env.contract.address.to_string(),
+            name: CONTRACT_NAME.to_string(),
+            symbol: SYMBOL.to_string(),
+        },
+        funds,
+        msg: Binary::default(),
+    }
+    .into_cosmos_msg("third_contract")
+    .unwrap();
+    assert_eq!(res.messages, vec![SubMsg::new(expected)]);
+}
+
+#[test]
+fn is_contract_detects_missing_contracts() {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::ContractInfo { contract_addr } if contract_addr == "contract" => {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&ContractInfoResponse::new(1, "creator")).unwrap(),
+            ))
+        }
+        WasmQuery::ContractInfo { contract_addr } if contract_addr == "failed_query" => {
+            SystemResult::Ok(ContractResult::Err("contract not found".to_string()))
+        }
+        WasmQuery::ContractInfo { contract_addr } if contract_addr == "unreachable" => {
+            SystemResult::Err(SystemError::Unknown {})
+        }
+        WasmQuery::ContractInfo { contract_addr } => {
+            SystemResult::Err(SystemError::NoSuchContract {
+                addr: contract_addr.clone(),
+            })
+        }
+        _ => SystemResult::Err(SystemError::UnsupportedRequest {
+            kind: "wasm".to_string(),
+        }),
+    });
+
+    assert!(is_contract(&deps.as_ref().querier, "contract").unwrap());
+    // detected by error variant, regardless of how the querier words it
+    assert!(!is_contract(&deps.as_ref().querier, "account").unwrap());
+    // e.g. cw-multi-test reports missing contracts as a failed query
+    assert!(!is_contract(&deps.as_ref().querier, "failed_query").unwrap());
+    // other system errors are not mistaken for an account
+    is_contract(&deps.as_ref().querier, "unreachable").unwrap_err();
+}
+
+#[test]
+fn batch_transferring_and_sending_nft() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    let token_ids: Vec<String> = ["melt1", "melt2", "melt3"]
+        .iter()
+        .map(|t| t.to_string())
+        .collect();
+    let batch_mint_msg = Cw721ExecuteMsg::BatchMint {
+        tokens: token_ids
+            .iter()
+            .map(|token_id| MintMsg {
+                token_id: Some(token_id.clone()),
+                owner: String::from("venus"),
+                token_uri: None,
+                extension: None,
+            })
+            .collect(),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER_ADDR, &[]),
+            batch_mint_msg,
+        )
+        .unwrap();
+
+    // random cannot transfer
+    let transfer_msg = Cw721ExecuteMsg::BatchTransferNft {
+        recipient: String::from("random"),
+        token_ids: token_ids[..2].to_vec(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("random", &[]),
+            transfer_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // owner can
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            transfer_msg,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "batch_transfer_nft")
+            .add_attribute("sender", "venus")
+            .add_attribute("recipient", "random")
+            .add_attribute("count", "2")
+            .add_events(["melt1", "melt2"].map(|token_id| {
+                Cw721Event::Transfer {
+                    sender: "venus".to_string(),
+                    from: "venus".to_string(),
+                    to: "random".to_string(),
+                    token_id: token_id.to_string(),
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            }))
+    );
+    let owned = contract
+        .query_tokens(
+            deps.as_ref(),
+            mock_env(),
+            "random".to_string(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(owned.tokens, token_ids[..2].to_vec());
+
+    // sending fails as a whole if one token is not owned by the sender
+    let msg = to_json_binary("You now have the melting power").unwrap();
+    let send_msg = Cw721ExecuteMsg::BatchSendNft {
+        contract: String::from("another_contract"),
+        token_ids: token_ids.clone(),
+        msg: msg.clone(),
+        receive_msg_version: None,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("random", &[]),
+            send_msg,
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // one receive message is sent per token
+    let send_msg = Cw721ExecuteMsg::BatchSendNft {
+        contract: String::from("another_contract"),
+        token_ids: token_ids[..2].to_vec(),
+        msg: msg.clone(),
+        receive_msg_version: None,
+    };
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("random", &[]),
+            send_msg,
+        )
+        .unwrap();
+    let expected: Vec<CosmosMsg> = token_ids[..2]
+        .iter()
+        .map(|token_id| {
+            Cw721ReceiveMsg {
+                sender: String::from("random"),
+                token_id: token_id.clone(),
+                msg: msg.clone(),
+            }
+            .into_cosmos_msg("another_contract")
+            .unwrap()
+        })
+        .collect();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_messages(expected)
+            .add_attribute("action", "batch_send_nft")
+            .add_attribute("sender", "random")
+            .add_attribute("recipient", "another_contract")
+            .add_attribute("count", "2")
+            .add_events(["melt1", "melt2"].map(|token_id| {
+                Cw721Event::Send {
+                    sender: "random".to_string(),
+                    from: "random".to_string(),
+                    to: "another_contract".to_string(),
+                    token_id: token_id.to_string(),
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            }))
+    );
+
+    // with v2, funds are forwarded with the first receive message only
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: Some("melt4".to_string()),
+        owner: String::from("venus"),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg,
+        )
+        .unwrap();
+    let funds = vec![Coin::new(100, "uark")];
+    let send_msg = Cw721ExecuteMsg::BatchSendNft {
+        contract: String::from("another_contract"),
+        token_ids: vec!["melt3".to_string(), "melt4".to_string()],
+        msg: msg.clone(),
+        receive_msg_version: Some(ReceiveMsgVersion::V2),
+    };
+    let env = mock_env();
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("venus", &funds),
+            send_msg,
+        )
+        .unwrap();
+    let expected: Vec<SubMsg> = [("melt3", funds.clone()), ("melt4", vec![])]
+        .into_iter()
+        .map(|(token_id, funds)| {
+            SubMsg::new(
+                Cw721ReceiveMsgV2 {
+                    sender: String::from("venus"),
+                    owner: String::from("venus"),
+                    token_id: token_id.to_string(),
+                    collection: Cw721ReceiveCollection {
+                        address: env.contract.address.to_string(),
+                        name: CONTRACT_NAME.to_string(),
+                        symbol: SYMBOL.to_string(),
+                    },
+                    funds,
+                    msg: msg.clone(),
+                }
+                .into_cosmos_msg("another_contract")
+                .unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(res.messages, expected);
+}
+
+#[test]
+fn locking_nft() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+    let env = mock_env();
+
+    let token_id = "staked".to_string();
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::Mint {
+                token_id: Some(token_id.clone()),
+                owner: String::from("demeter"),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap();
+
+    // random cannot lock
+    let lock_msg = Cw721ExecuteMsg::LockNft {
+        token_id: token_id.clone(),
+        expires: Some(Expiration::AtHeight(env.block.height + 100)),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("random", &[]),
+            lock_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // approved locker can lock
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::Approve {
+                spender: String::from("staking"),
+                token_id: token_id.clone(),
+                expires: None,
+            },
+        )
+        .unwrap();
+
+    // only the owner can lock without expiration
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("staking", &[]),
+            Cw721ExecuteMsg::LockNft {
+                token_id: token_id.clone(),
+                expires: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::LockExpirationRequired {});
+
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("staking", &[]),
+            lock_msg.clone(),
+        )
+        .unwrap();
+
+    // already locked
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            lock_msg,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::TokenLocked {
+            token_id: token_id.clone()
+        }
+    );
+
+    let lock: Option<TokenLockResponse> = from_json(
+        contract
+            .query(
+                deps.as_ref(),
+                env.clone(),
+                Cw721QueryMsg::GetTokenLock {
+                    token_id: token_id.clone(),
+                },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    let expected = TokenLockResponse {
+        token_id: token_id.clone(),
+        locker: Addr::unchecked("staking"),
+        expires: Expiration::AtHeight(env.block.height + 100),
+    };
+    assert_eq!(lock, Some(expected.clone()));
+    let locks: TokenLocksResponse = from_json(
+        contract
+            .query(
+                deps.as_ref(),
+                env.clone(),
+                Cw721QueryMsg::AllTokenLocks {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(locks.locks, vec![expected]);
+
+    // locked token can't be transferred, sent, approved or burned
+    let locked_err = Cw721ContractError::TokenLocked {
+        token_id: token_id.clone(),
+    };
+    let msgs = vec![
+        Cw721ExecuteMsg::TransferNft {
+            recipient: String::from("random"),
+            token_id: token_id.clone(),
+        },
+        Cw721ExecuteMsg::SendNft {
+            contract: String::from("another_contract"),
+            token_id: token_id.clone(),
+            msg: to_json_binary("yes").unwrap(),
+            receive_msg_version: None,
+        },
+        Cw721ExecuteMsg::Approve {
+            spender: String::from("random"),
+            token_id: token_id.clone(),
+            expires: None,
+        },
+        Cw721ExecuteMsg::Burn {
+            token_id: token_id.clone(),
+        },
+        Cw721ExecuteMsg::BatchBurn {
+            token_ids: vec![token_id.clone()],
+        },
+    ];
+    for msg in msgs {
+        let err = contract
+            .execute(deps.as_mut(), env.clone(), mock_info("demeter", &[]), msg)
+            .unwrap_err();
+        assert_eq!(err, locked_err);
+    }
+
+    // only locker can unlock
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::UnlockNft {
+                token_id: token_id.clone(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("staking", &[]),
+            Cw721ExecuteMsg::UnlockNft {
+                token_id: token_id.clone(),
+            },
+        )
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("staking", &[]),
+            Cw721ExecuteMsg::UnlockNft {
+                token_id: token_id.clone(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::TokenNotLocked {
+            token_id: token_id.clone()
+        }
+    );
+
+    // lock expires
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::LockNft {
+                token_id: token_id.clone(),
+                expires: Some(Expiration::AtHeight(env.block.height + 1)),
+            },
+        )
+        .unwrap();
+    let mut later = env.clone();
+    later.block.height += 1;
+    let lock: Option<TokenLockResponse> = from_json(
+        contract
+            .query(
+                deps.as_ref(),
+                later.clone(),
+                Cw721QueryMsg::GetTokenLock {
+                    token_id: token_id.clone(),
+                },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(lock, None);
+
+    // unlocked token can be transferred again
+    contract
+        .execute(
+            deps.as_mut(),
+            later,
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::TransferNft {
+                recipient: String::from("random"),
+                token_id,
+            },
+        )
+        .unwrap();
+}
+
+#[test]
+fn revoking_approval_keeps_lock() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+    let env = mock_env();
+
+    for token_id in ["staked1", "staked2"] {
+        contract
+            .execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(MINTER_ADDR, &[]),
+                Cw721ExecuteMsg::Mint {
+                    token_id: Some(token_id.to_string()),
+                    owner: String::from("demeter"),
+                    token_uri: None,
+                    extension: None,
+                },
+            )
+            .unwrap();
+    }
+    let unlock = |deps: DepsMut, sender: &str, token_id: &str| {
+        contract.execute(
+            deps,
+            env.clone(),
+            mock_info(sender, &[]),
+            Cw721ExecuteMsg::UnlockNft {
+                token_id: token_id.to_string(),
+            },
+        )
+    };
+
+    // approved spender locks a token far into the future
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::Approve {
+                spender: String::from("staking"),
+                token_id: "staked1".to_string(),
+                expires: None,
+            },
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("staking", &[]),
+            Cw721ExecuteMsg::LockNft {
+                token_id: "staked1".to_string(),
+                expires: Some(Expiration::AtHeight(u64::MAX)),
+            },
+        )
+        .unwrap();
+
+    // owner can't unlock, neither while the locker is approved nor after revoking
+    let err = unlock(deps.as_mut(), "demeter", "staked1").unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::Revoke {
+                spender: String::from("staking"),
+                token_id: "staked1".to_string(),
+            },
+        )
+        .unwrap();
+    let err = unlock(deps.as_mut(), "demeter", "staked1").unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::TransferNft {
+                recipient: String::from("random"),
+                token_id: "staked1".to_string(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::TokenLocked {
+            token_id: "staked1".to_string()
+        }
+    );
+
+    // locker can still unlock
+    let err = unlock(deps.as_mut(), "random", "staked1").unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    unlock(deps.as_mut(), "staking", "staked1").unwrap();
+    let lock = contract
+        .config
+        .token_locks
+        .may_load(deps.as_ref().storage, "staked1")
+        .unwrap();
+    assert_eq!(lock, None);
+
+    // same for an operator, after revoking all
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::ApproveAll {
+                operator: String::from("staking"),
+                expires: None,
+            },
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("staking", &[]),
+            Cw721ExecuteMsg::LockNft {
+                token_id: "staked2".to_string(),
+                expires: Some(Expiration::AtHeight(env.block.height + 10)),
+            },
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::RevokeAll {
+                operator: String::from("staking"),
+            },
+        )
+        .unwrap();
+    let err = unlock(deps.as_mut(), "demeter", "staked2").unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: String::from("random"),
+        token_id: "staked2".to_string(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            transfer_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::TokenLocked {
+            token_id: "staked2".to_string()
+        }
+    );
+
+    // token can be transferred once the lock expires
+    let mut later = env.clone();
+    later.block.height += 10;
+    contract
+        .execute(
+            deps.as_mut(),
+            later,
+            mock_info("demeter", &[]),
+            transfer_msg,
+        )
+        .unwrap();
+}
+
+#[test]
+fn pausing() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+    let env = mock_env();
+
+    let mint_msg = |token_id: &str| Cw721ExecuteMsg::Mint {
+        token_id: Some(token_id.to_string()),
+        owner: String::from("demeter"),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg("1"),
+        )
+        .unwrap();
+
+    // only creator can pause
+    let pause_msg = Cw721ExecuteMsg::UpdatePauseStatus {
+        minting: Some(true),
+        transfers: None,
+        burns: None,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            pause_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            pause_msg,
+        )
+        .unwrap();
+    assert_eq!(
+        res.events,
+        vec![Cw721Event::UpdatePauseStatus {
+            sender: CREATOR_ADDR.to_string(),
+            minting: true,
+            transfers: false,
+            burns: false,
+        }
+        .into_event(deps.as_ref().storage)
+        .unwrap()]
+    );
+
+    // minter can't bypass paused minting, transfers are still allowed
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg("2"),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::MintingPaused {});
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::TransferNft {
+                recipient: String::from("venus"),
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+
+    // pause transfers and burns, resume minting
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::UpdatePauseStatus {
+                minting: Some(false),
+                transfers: Some(true),
+                burns: Some(true),
+            },
+        )
+        .unwrap();
+    let status: PauseStatus = from_json(
+        contract
+            .query(deps.as_ref(), env.clone(), Cw721QueryMsg::PauseStatus {})
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        status,
+        PauseStatus {
+            minting: false,
+            transfers: true,
+            burns: true,
+        }
+    );
+
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg("2"),
+        )
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("venus", &[]),
+            Cw721ExecuteMsg::SendNft {
+                contract: String::from("another_contract"),
+                token_id: "1".to_string(),
+                msg: to_json_binary("yes").unwrap(),
+                receive_msg_version: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::TransfersPaused {});
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env,
+            mock_info("venus", &[]),
+            Cw721ExecuteMsg::BatchBurn {
+                token_ids: vec!["1".to_string()],
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::BurnsPaused {});
+}
+
+#[test]
+fn hooks() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+    let env = mock_env();
+
+    // only creator can add hooks
+    let add_hook_msg = |addr: &str, revert_on_error: Option<bool>| Cw721ExecuteMsg::AddHook {
+        addr: addr.to_string(),
+        revert_on_error,
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            add_hook_msg("hook_a", None),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            add_hook_msg("hook_a", None),
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_event(
+                Cw721Event::AddHook {
+                    sender: CREATOR_ADDR.to_string(),
+                    hook: "hook_a".to_string(),
+                    revert_on_error: true,
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
+            .add_attribute("action", "add_hook")
+            .add_attribute("sender", CREATOR_ADDR)
+            .add_attribute("hook", "hook_a")
+            .add_attribute("revert_on_error", "true")
+    );
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            add_hook_msg("hook_b", Some(false)),
+        )
+        .unwrap();
+
+    // hooks can't be registered twice
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            add_hook_msg("hook_a", Some(false)),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::HookAlreadyRegistered {
+            addr: "hook_a".to_string()
+        }
+    );
+
+    let hooks = contract.query_hooks(deps.as_ref(), None, None).unwrap();
+    assert_eq!(
+        hooks,
+        HooksResponse {
+            hooks: vec![
+                HookInfo {
+                    addr: Addr::unchecked("hook_a"),
+                    revert_on_error: true,
+                },
+                HookInfo {
+                    addr: Addr::unchecked("hook_b"),
+                    revert_on_error: false,
+                },
+            ]
+        }
+    );
+    let hooks = contract
+        .query_hooks(deps.as_ref(), Some("hook_a".to_string()), None)
+        .unwrap();
+    assert_eq!(hooks.hooks.len(), 1);
+    assert_eq!(hooks.hooks[0].addr, Addr::unchecked("hook_b"));
+
+    // every hook is notified, only the lenient one replies on error
+    let expected_sub_msgs = |hook_msg: Cw721HookMsg| {
+        vec![
+            SubMsg::new(hook_msg.clone().into_cosmos_msg("hook_a").unwrap()),
+            SubMsg::reply_on_error(hook_msg.into_cosmos_msg("hook_b").unwrap(), HOOK_REPLY_ID),
+        ]
+    };
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::Mint {
+                token_id: Some("1".to_string()),
+                owner: String::from("demeter"),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.messages,
+        expected_sub_msgs(Cw721HookMsg::Mint {
+            token_id: "1".to_string(),
+            owner: String::from("demeter"),
+        })
+    );
+
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::TransferNft {
+                recipient: String::from("random"),
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.messages,
+        expected_sub_msgs(Cw721HookMsg::Transfer {
+            token_id: "1".to_string(),
+            sender: String::from("demeter"),
+            recipient: String::from("random"),
+        })
+    );
+
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("random", &[]),
+            Cw721ExecuteMsg::Burn {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.messages,
+        expected_sub_msgs(Cw721HookMsg::Burn {
+            token_id: "1".to_string(),
+            sender: String::from("random"),
+        })
+    );
+
+    // failing lenient hooks are caught in reply
+    let res = contract
+        .reply(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: HOOK_REPLY_ID,
+                result: SubMsgResult::Err("hook failed".to_string()),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "hook_failed")
+            .add_attribute("error", "hook failed")
+    );
+    let err = contract
+        .reply(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: 1,
+                result: SubMsgResult::Err("hook failed".to_string()),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::UnknownReplyId { id: 1 });
+
+    // remove hooks
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR_ADDR, &[]),
+            Cw721ExecuteMsg::RemoveHook {
+                addr: "unknown".to_string(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::HookNotRegistered {
+            addr: "unknown".to_string()
+        }
+    );
+    for hook in ["hook_a", "hook_b"] {
+        contract
+            .execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(CREATOR_ADDR, &[]),
+                Cw721ExecuteMsg::RemoveHook {
+                    addr: hook.to_string(),
+                },
+            )
+            .unwrap();
+    }
+    let hooks = contract.query_hooks(deps.as_ref(), None, None).unwrap();
+    assert_eq!(hooks.hooks, vec![]);
+
+    // no hooks, no sub messages
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            env,
+            mock_info(MINTER_ADDR, &[]),
+            Cw721ExecuteMsg::Mint {
+                token_id: Some("2".to_string()),
+                owner: String::from("demeter"),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap();
+    assert!(res.messages.is_empty());
+}
+
+fn setup_contract_with_transfer_policy(
+    deps: DepsMut<'_>,
+    transfer_policy: TransferPolicy,
+) -> Cw721Contract<
+    'static,
+    DefaultOptionMetadataExtension,
+    Empty,
+    Empty,
+    DefaultOptionCollectionInfoExtension,
+> {
+    let contract = Cw721Contract::default();
+    let msg = Cw721InstantiateMsg {
+        name: CONTRACT_NAME.to_string(),
+        symbol: SYMBOL.to_string(),
+        minter: Some(String::from(MINTER_ADDR)),
+        creator: None,
+        withdraw_address: None,
+        metadata_update_authority: None,
+        transfer_policy: Some(transfer_policy),
+        sequential_token_ids: None,
+        max_supply: None,
+        index_traits: None,
+        collection_info_extension: None,
+    };
+    contract
+        .instantiate(
+            deps,
+            mock_env(),
+            mock_info(CREATOR_ADDR, &[]),
+            msg,
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+    contract
+}
+
+#[test]
+fn transfer_policies() {
+    let env = mock_env();
+    let mint_msg = |token_id: &str| Cw721ExecuteMsg::Mint {
+        token_id: Some(token_id.to_string()),
+        owner: String::from("demeter"),
+        token_uri: None,
+        extension: None,
+    };
+    let transfer_msg = |token_id: &str| Cw721ExecuteMsg::TransferNft {
+        recipient: String::from("venus"),
+        token_id: token_id.to_string(),
+    };
+    let burn_msg = |token_id: &str| Cw721ExecuteMsg::Burn {
+        token_id: token_id.to_string(),
+    };
+
+    // soulbound: no transfers, but approvals and burns by approved spenders work
+    let mut deps = mock_dependencies();
+    let contract =
+        setup_contract_with_transfer_policy(deps.as_mut(), TransferPolicy::NonTransferable);
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg("1"),
+        )
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            transfer_msg("1"),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NonTransferable {});
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::Approve {
+                spender: String::from("random"),
+                token_id: "1".to_string(),
+                expires: None,
+            },
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("random", &[]),
+            burn_msg("1"),
+        )
+        .unwrap();
+
+    // owner burn only: approved spenders can't burn, owner can
+    let mut deps = mock_dependencies();
+    let contract =
+        setup_contract_with_transfer_policy(deps.as_mut(), TransferPolicy::OwnerBurnOnly);
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg("1"),
+        )
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            transfer_msg("1"),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::NonTransferable {});
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::ApproveAll {
+                operator: String::from("random"),
+                expires: None,
+            },
+        )
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("random", &[]),
+            burn_msg("1"),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            burn_msg("1"),
+        )
+        .unwrap();
+
+    // minter only: owner can't transfer, minter can
+    let mut deps = mock_dependencies();
+    let contract = setup_contract_with_transfer_policy(deps.as_mut(), TransferPolicy::MinterOnly);
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg("1"),
+        )
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            transfer_msg("1"),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            transfer_msg("1"),
+        )
+        .unwrap();
+    let owner = contract
+        .query_owner_of(deps.as_ref(), env.clone(), "1".to_string(), false)
+        .unwrap();
+    assert_eq!(owner.owner, "venus");
+
+    // transferable after timestamp
+    let mut deps = mock_dependencies();
+    let timestamp = env.block.time.plus_seconds(100);
+    let contract = setup_contract_with_transfer_policy(
+        deps.as_mut(),
+        TransferPolicy::TransferableAfter { timestamp },
+    );
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg("1"),
+        )
+        .unwrap();
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            transfer_msg("1"),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::TransferableAfter { timestamp });
+    let mut later = env;
+    later.block.time = timestamp;
+    contract
+        .execute(
+            deps.as_mut(),
+            later,
+            mock_info("demeter", &[]),
+            transfer_msg("1"),
+        )
+        .unwrap();
+}
+
+#[test]
+fn batch_burning() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    let token_ids: Vec<String> = ["melt1", "melt2", "melt3"]
+        .iter()
+        .map(|t| t.to_string())
+        .collect();
+    let batch_mint_msg = Cw721ExecuteMsg::BatchMint {
+        tokens: token_ids
+            .iter()
+            .map(|token_id| MintMsg {
+                token_id: Some(token_id.clone()),
+                owner: String::from("venus"),
+                token_uri: None,
+                extension: None,
+            })
+            .collect(),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER_ADDR, &[]),
+            batch_mint_msg,
+        )
+        .unwrap();
+
+    // empty batch is rejected
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            Cw721ExecuteMsg::BatchBurn { token_ids: vec![] },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::EmptyBatch {});
+
+    // duplicates are rejected
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            Cw721ExecuteMsg::BatchBurn {
+                token_ids: vec!["melt1".to_string(), "melt1".to_string()],
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::DuplicateTokenId {
+            token_id: "melt1".to_string()
+        }
+    );
+
+    // random cannot burn
+    let burn_msg = Cw721ExecuteMsg::BatchBurn {
+        token_ids: token_ids[..2].to_vec(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("random", &[]),
+            burn_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // but an operator can
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            Cw721ExecuteMsg::ApproveAll {
+                operator: String::from("operator"),
+                expires: None,
+            },
+        )
+        .unwrap();
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("operator", &[]),
+            burn_msg,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_attribute("action", "batch_burn")
+            .add_attribute("sender", "operator")
+            .add_attribute("token_id", "melt1")
+            .add_attribute("token_id", "melt2")
+            .add_events(["melt1", "melt2"].map(|token_id| {
+                Cw721Event::Burn {
+                    sender: "operator".to_string(),
+                    owner: "venus".to_string(),
+                    token_id: token_id.to_string(),
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            }))
+    );
+
+    let count = contract
+        .query_num_tokens(deps.as_ref(), mock_env())
+        .unwrap();
+    assert_eq!(count.count, 1);
+    let _ = contract
+        .query_nft_info(deps.as_ref(), mock_env(), "melt1".to_string())
+        .unwrap_err();
+    let tokens = contract
+        .query_all_tokens(deps.as_ref(), mock_env(), None, None, None, None)
+        .unwrap();
+    assert_eq!(tokens.tokens, vec!["melt3".to_string()]);
+}
+
+#[test]
+fn approving_revoking() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    // Mint a token
+    let token_id = "grow".to_string();
+    let token_uri = "https://www.merriam-webster.com/dictionary/grow".to_string();
+
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: Some(token_id.clone()),
+        owner: String::from("demeter"),
+        token_uri: Some(token_uri),
+        extension: None,
+    };
+
+    let minter = mock_info(MINTER_ADDR, &[]);
+    contract
+        .execute(deps.as_mut(), mock_env(), minter, mint_msg)
+        .unwrap();
+
+    // token owner shows in approval query
+    let res = contract
+        .query_approval(
+            deps.as_ref(),
+            mock_env(),
+            token_id.clone(),
+            String::from("demeter"),
+            false,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ApprovalResponse {
+            approval: Approval {
+                spender: Addr::unchecked("demeter"),
+                expires: Expiration::Never {}
+            }
+        }
+    );
+
+    // Give random transferring power
+    let approve_msg = Cw721ExecuteMsg::Approve {
+        spender: String::from("random"),
+        token_id: token_id.clone(),
+        expires: None,
+    };
+    let owner = mock_info("demeter", &[]);
+    let res = contract
+        .execute(deps.as_mut(), mock_env(), owner, approve_msg)
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_event(
+                Cw721Event::Approve {
+                    sender: "demeter".to_string(),
+                    owner: "demeter".to_string(),
+                    spender: "random".to_string(),
+                    token_id: token_id.clone(),
+                    expires: Expiration::Never {},
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
+            .add_attribute("action", "approve")
+            .add_attribute("sender", "demeter")
+            .add_attribute("spender", "random")
+            .add_attribute("token_id", token_id.clone())
+    );
+
+    // test approval query
+    let res = contract
+        .query_approval(
+            deps.as_ref(),
+            mock_env(),
+            token_id.clone(),
+            String::from("random"),
+            true,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ApprovalResponse {
+            approval: Approval {
+                spender: Addr::unchecked("random"),
+                expires: Expiration::Never {}
+            }
+        }
+    );
+
+    // random can now transfer
+    let random = mock_info("random", &[]);
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: String::from("person"),
+        token_id: token_id.clone(),
+    };
+    contract
+        .execute(deps.as_mut(), mock_env(), random, transfer_msg)
+        .unwrap();
+
+    // Approvals are removed / cleared
+    let query_msg = Cw721QueryMsg::OwnerOf {
+        token_id: token_id.clone(),
+        include_expired: None,
+    };
+    let res: OwnerOfResponse = from_json(
+        contract
+            .query(deps.as_ref(), mock_env(), query_msg.clone())
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        OwnerOfResponse {
+            owner: String::from("person"),
+            approvals: vec![],
+        }
+    );
+
+    // Approve, revoke, and check for empty, to test revoke
+    let approve_msg = Cw721ExecuteMsg::Approve {
+        spender: String::from("random"),
+        token_id: token_id.clone(),
+        expires: None,
+    };
+    let owner = mock_info("person", &[]);
+    contract
+        .execute(deps.as_mut(), mock_env(), owner.clone(), approve_msg)
+        .unwrap();
+
+    let revoke_msg = Cw721ExecuteMsg::Revoke {
+        spender: String::from("random"),
+        token_id,
+    };
+    contract
+        .execute(deps.as_mut(), mock_env(), owner, revoke_msg)
+        .unwrap();
+
+    // Approvals are now removed / cleared
+    let res: OwnerOfResponse = from_json(
+        contract
+            .query(deps.as_ref(), mock_env(), query_msg)
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        OwnerOfResponse {
+            owner: String::from("person"),
+            approvals: vec![],
+        }
+    );
+}
+
+#[test]
+fn approving_all_revoking_all() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    // Mint a couple tokens (from the same owner)
+    let token_id1 = "grow1".to_string();
+    let token_uri1 = "https://www.merriam-webster.com/dictionary/grow1".to_string();
+
+    let token_id2 = "grow2".to_string();
+    let token_uri2 = "https://www.merriam-webster.com/dictionary/grow2".to_string();
+
+    let mint_msg1 = Cw721ExecuteMsg::Mint {
+        token_id: Some(token_id1.clone()),
+        owner: String::from("demeter"),
+        token_uri: Some(token_uri1),
+        extension: None,
+    };
+
+    let minter = mock_info(MINTER_ADDR, &[]);
+    contract
+        .execute(deps.as_mut(), mock_env(), minter.clone(), mint_msg1)
+        .unwrap();
+
+    let mint_msg2 = Cw721ExecuteMsg::Mint {
+        token_id: Some(token_id2.clone()),
+        owner: String::from("demeter"),
+        token_uri: Some(token_uri2),
+        extension: None,
+    };
+
+    let env = mock_env();
+    contract
+        .execute(deps.as_mut(), env.clone(), minter, mint_msg2)
+        .unwrap();
+
+    // paginate the token_ids
+    let tokens = contract
+        .query_all_tokens(deps.as_ref(), env.clone(), None, None, Some(1), None)
+        .unwrap();
+    assert_eq!(1, tokens.tokens.len());
+    assert_eq!(vec![token_id1.clone()], tokens.tokens);
+    let tokens = contract
+        .query_all_tokens(
+            deps.as_ref(),
+            env,
+            Some(token_id1.clone()),
+            None,
+            Some(3),
+            None,
+        )
+        .unwrap();
+    assert_eq!(1, tokens.tokens.len());
+    assert_eq!(vec![token_id2.clone()], tokens.tokens);
+
+    // demeter gives random full (operator) power over her tokens
+    let approve_all_msg = Cw721ExecuteMsg::ApproveAll {
+        operator: String::from("random"),
+        expires: None,
+    };
+    let owner = mock_info("demeter", &[]);
+    let res = contract
+        .execute(deps.as_mut(), mock_env(), owner, approve_all_msg)
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_event(
+                Cw721Event::ApproveAll {
+                    owner: "demeter".to_string(),
+                    operator: "random".to_string(),
+                    expires: Expiration::Never {},
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
+            .add_attribute("action", "approve_all")
+            .add_attribute("sender", "demeter")
+            .add_attribute("operator", "random")
+    );
+
+    // random can now transfer
+    let random = mock_info("random", &[]);
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: String::from("person"),
+        token_id: token_id1,
+    };
+    contract
+        .execute(deps.as_mut(), mock_env(), random.clone(), transfer_msg)
+        .unwrap();
+
+    // random can now send
+    let inner_msg = WasmMsg::Execute {
+        contract_addr: "another_contract".into(),
+        msg: to_json_binary("You now also have the growing power").unwrap(),
+        funds: vec![],
+    };
+    let msg: CosmosMsg = CosmosMsg::Wasm(inner_msg);
+
+    let send_msg = Cw721ExecuteMsg::SendNft {
+        contract: String::from("another_contract"),
+        token_id: token_id2,
+        msg: to_json_binary(&msg).unwrap(),
+        receive_msg_version: None,
+    };
+    contract
+        .execute(deps.as_mut(), mock_env(), random, send_msg)
+        .unwrap();
+
+    // Approve_all, revoke_all, and check for empty, to test revoke_all
+    let approve_all_msg = Cw721ExecuteMsg::ApproveAll {
+        operator: String::from("operator"),
+        expires: None,
+    };
+    // person is now the owner of the tokens
+    let owner = mock_info("person", &[]);
+    contract
+        .execute(deps.as_mut(), mock_env(), owner, approve_all_msg)
+        .unwrap();
+
+    // query for operator should return approval
+    let res = contract
+        .query_operator(
+            deps.as_ref(),
+            mock_env(),
+            String::from("person"),
+            String::from("operator"),
+            true,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        OperatorResponse {
+            approval: Approval {
+                spender: Addr::unchecked("operator"),
+                expires: Expiration::Never {}
+            }
+        }
+    );
+
+    // query for other should throw error
+    let res = contract.query_operator(
+        deps.as_ref(),
+        mock_env(),
+        String::from("person"),
+        String::from("other"),
+        true,
+    );
+    match res {
+        Err(StdError::NotFound { kind }) => assert_eq!(kind, "Approval not found"),
+        _ => panic!("Unexpected error"),
+    }
+
+    let res = contract
+        .query_operators(
+            deps.as_ref(),
+            mock_env(),
+            String::from("person"),
+            true,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        OperatorsResponse {
+            operators: vec![Approval {
+                spender: Addr::unchecked("operator"),
+                expires: Expiration::Never {}
+            }]
+        }
+    );
+
+    // second approval
+    let buddy_expires = Expiration::AtHeight(1234567);
+    let approve_all_msg = Cw721ExecuteMsg::ApproveAll {
+        operator: String::from("buddy"),
+        expires: Some(buddy_expires),
+    };
+    let owner = mock_info("person", &[]);
+    contract
+        .execute(deps.as_mut(), mock_env(), owner.clone(), approve_all_msg)
+        .unwrap();
+
+    // and paginate queries
+    let res = contract
+        .query_operators(
+            deps.as_ref(),
+            mock_env(),
+            String::from("person"),
+            true,
+            None,
+            None,
+            Some(1),
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        OperatorsResponse {
+            operators: vec![Approval {
+                spender: Addr::unchecked("buddy"),
+                expires: buddy_expires,
+            }]
+        }
+    );
+    let res = contract
+        .query_operators(
+            deps.as_ref(),
+            mock_env(),
+            String::from("person"),
+            true,
+            Some(String::from("buddy")),
+            None,
+            Some(2),
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        OperatorsResponse {
+            operators: vec![Approval {
+                spender: Addr::unchecked("operator"),
+                expires: Expiration::Never {}
+            }]
+        }
+    );
+
+    let revoke_all_msg = Cw721ExecuteMsg::RevokeAll {
+        operator: String::from("operator"),
+    };
+    contract
+        .execute(deps.as_mut(), mock_env(), owner, revoke_all_msg)
+        .unwrap();
+
+    // query for operator should return error
+    let res = contract.query_operator(
+        deps.as_ref(),
+        mock_env(),
+        String::from("person"),
+        String::from("operator"),
+        true,
+    );
+    match res {
+        Err(StdError::NotFound { kind }) => assert_eq!(kind, "Approval not found"),
+        _ => panic!("Unexpected error"),
+    }
+
+    // Approvals are removed / cleared without affecting others
+    let res = contract
+        .query_operators(
+            deps.as_ref(),
+            mock_env(),
+            String::from("person"),
+            false,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        OperatorsResponse {
+            operators: vec![Approval {
+                spender: Addr::unchecked("buddy"),
+                expires: buddy_expires,
+            }]
+        }
+    );
+
+    // ensure the filter works (nothing should be here
+    let mut late_env = mock_env();
+    late_env.block.height = 1234568; //expired
     let res = contract
-        .execute(deps.as_mut(), mock_env(), random, transfer_msg)
+        .query_operators(
+            deps.as_ref(),
+            late_env.clone(),
+            String::from("person"),
+            false,
+            None,
+            None,
+            None,
+            None,
+        )
         .unwrap();
+    assert_eq!(0, res.operators.len());
 
-    assert_eq!(
-        res,
-        Response::new()
-            .add_attribute("action", "transfer_nft")
-            .add_attribute("sender", "venus")
-            .add_attribute("recipient", "random")
-            .add_attribute("token_id", token_id)
+    // query operator should also return error
+    let res = contract.query_operator(
+        deps.as_ref(),
+        late_env,
+        String::from("person"),
+        String::from("buddy"),
+        false,
     );
+
+    match res {
+        Err(StdError::NotFound { kind }) => assert_eq!(kind, "Approval not found"),
+        _ => panic!("Unexpected error"),
+    }
 }
 
 #[test]
-fn sending_nft() {
+fn query_approved_owners_for_operator() {
     let mut deps = mock_dependencies();
     let contract = setup_contract(deps.as_mut());
 
-    // Mint a token
-    let token_id = "melt".to_string();
-    let token_uri = "https://www.merriam-webster.com/dictionary/melt".to_string();
-
-    let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
-        owner: String::from("venus"),
-        token_uri: Some(token_uri),
-        extension: None,
-    };
+    // two owners grant the same operator, one of them also grants another operator
+    let alice_expires = Expiration::AtHeight(1234567);
+    for (owner, operator, expires) in [
+        ("alice", "market", Some(alice_expires)),
+        ("bob", "market", None),
+        ("bob", "other_market", None),
+    ] {
+        contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(owner, &[]),
+                Cw721ExecuteMsg::ApproveAll {
+                    operator: operator.to_string(),
+                    expires,
+                },
+            )
+            .unwrap();
+    }
 
-    let minter = mock_info(MINTER_ADDR, &[]);
-    contract
-        .execute(deps.as_mut(), mock_env(), minter, mint_msg)
+    let res = contract
+        .query_approved_owners_for_operator(
+            deps.as_ref(),
+            mock_env(),
+            "market".to_string(),
+            false,
+            None,
+            None,
+        )
         .unwrap();
+    assert_eq!(
+        res,
+        ApprovedOwnersResponse {
+            owners: vec![
+                OwnerApproval {
+                    owner: Addr::unchecked("alice"),
+                    expires: alice_expires,
+                },
+                OwnerApproval {
+                    owner: Addr::unchecked("bob"),
+                    expires: Expiration::Never {},
+                },
+            ]
+        }
+    );
 
-    let msg = to_json_binary("You now have the melting power").unwrap();
-    let target = String::from("another_contract");
-    let send_msg = Cw721ExecuteMsg::SendNft {
-        contract: target.clone(),
-        token_id: token_id.clone(),
-        msg: msg.clone(),
-    };
-
-    let random = mock_info("random", &[]);
-    let err = contract
-        .execute(deps.as_mut(), mock_env(), random, send_msg.clone())
-        .unwrap_err();
-    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+    // paginate
+    let res = contract
+        .query_approved_owners_for_operator(
+            deps.as_ref(),
+            mock_env(),
+            "market".to_string(),
+            false,
+            Some("alice".to_string()),
+            Some(1),
+        )
+        .unwrap();
+    assert_eq!(res.owners.len(), 1);
+    assert_eq!(res.owners[0].owner, Addr::unchecked("bob"));
 
-    // but owner can
-    let random = mock_info("venus", &[]);
+    // expired grants are filtered out unless requested
+    let mut late_env = mock_env();
+    late_env.block.height = 1234568;
     let res = contract
-        .execute(deps.as_mut(), mock_env(), random, send_msg)
+        .query_approved_owners_for_operator(
+            deps.as_ref(),
+            late_env.clone(),
+            "market".to_string(),
+            false,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(res.owners.len(), 1);
+    assert_eq!(res.owners[0].owner, Addr::unchecked("bob"));
+    let res = contract
+        .query_approved_owners_for_operator(
+            deps.as_ref(),
+            late_env,
+            "market".to_string(),
+            true,
+            None,
+            None,
+        )
         .unwrap();
+    assert_eq!(res.owners.len(), 2);
 
-    let payload = Cw721ReceiveMsg {
-        sender: String::from("venus"),
-        token_id: token_id.clone(),
-        msg,
-    };
-    let expected = payload.into_cosmos_msg(target.clone()).unwrap();
-    // ensure expected serializes as we think it should
-    match &expected {
-        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) => {
-            assert_eq!(contract_addr, &target)
-        }
-        m => panic!("Unexpected message type: {m:?}"),
-    }
-    // and make sure this is the request sent by the contract
-    assert_eq!(
-        res,
-        Response::new()
-            .add_message(expected)
-            .add_attribute("action", "send_nft")
-            .add_attribute("sender", "venus")
-            .add_attribute("recipient", "another_contract")
-            .add_attribute("token_id", token_id)
-    );
+    // revoking removes the owner from the index, without affecting other operators
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bob", &[]),
+            Cw721ExecuteMsg::RevokeAll {
+                operator: "market".to_string(),
+            },
+        )
+        .unwrap();
+    let res = contract
+        .query_approved_owners_for_operator(
+            deps.as_ref(),
+            mock_env(),
+            "market".to_string(),
+            true,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(res.owners.len(), 1);
+    assert_eq!(res.owners[0].owner, Addr::unchecked("alice"));
+    let res = contract
+        .query_approved_owners_for_operator(
+            deps.as_ref(),
+            mock_env(),
+            "other_market".to_string(),
+            true,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(res.owners.len(), 1);
+    assert_eq!(res.owners[0].owner, Addr::unchecked("bob"));
 }
 
 #[test]
-fn approving_revoking() {
+fn query_tokens_approved_for() {
     let mut deps = mock_dependencies();
     let contract = setup_contract(deps.as_mut());
 
-    // Mint a token
-    let token_id = "grow".to_string();
-    let token_uri = "https://www.merriam-webster.com/dictionary/grow".to_string();
+    let minter = mock_info(MINTER_ADDR, &[]);
+    for token_id in ["1", "2", "3"] {
+        let mint_msg = Cw721ExecuteMsg::Mint {
+            token_id: Some(token_id.to_string()),
+            owner: String::from("demeter"),
+            token_uri: None,
+            extension: None,
+        };
+        contract
+            .execute(deps.as_mut(), mock_env(), minter.clone(), mint_msg)
+            .unwrap();
+    }
 
-    let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.clone(),
-        owner: String::from("demeter"),
-        token_uri: Some(token_uri),
-        extension: None,
-    };
+    let owner = mock_info("demeter", &[]);
+    let expires = Expiration::AtHeight(1234567);
+    for (token_id, spender, expires) in [
+        ("1", "market", None),
+        ("2", "market", Some(expires)),
+        ("3", "other", None),
+    ] {
+        let approve_msg = Cw721ExecuteMsg::Approve {
+            spender: spender.to_string(),
+            token_id: token_id.to_string(),
+            expires,
+        };
+        contract
+            .execute(deps.as_mut(), mock_env(), owner.clone(), approve_msg)
+            .unwrap();
+    }
 
-    let minter = mock_info(MINTER_ADDR, &[]);
-    contract
-        .execute(deps.as_mut(), mock_env(), minter, mint_msg)
+    let res = contract
+        .query_tokens_approved_for(
+            deps.as_ref(),
+            mock_env(),
+            "market".to_string(),
+            false,
+            None,
+            None,
+        )
         .unwrap();
+    assert_eq!(res.tokens, vec!["1".to_string(), "2".to_string()]);
 
-    // token owner shows in approval query
+    // paginate
     let res = contract
-        .query_approval(
+        .query_tokens_approved_for(
             deps.as_ref(),
             mock_env(),
-            token_id.clone(),
-            String::from("demeter"),
+            "market".to_string(),
             false,
+            Some("1".to_string()),
+            Some(1),
         )
         .unwrap();
-    assert_eq!(
-        res,
-        ApprovalResponse {
-            approval: Approval {
-                spender: Addr::unchecked("demeter"),
-                expires: Expiration::Never {}
-            }
-        }
-    );
+    assert_eq!(res.tokens, vec!["2".to_string()]);
 
-    // Give random transferring power
-    let approve_msg = Cw721ExecuteMsg::Approve {
-        spender: String::from("random"),
-        token_id: token_id.clone(),
-        expires: None,
-    };
-    let owner = mock_info("demeter", &[]);
+    // expired approvals are filtered out unless requested
+    let mut late_env = mock_env();
+    late_env.block.height = 1234568;
     let res = contract
-        .execute(deps.as_mut(), mock_env(), owner, approve_msg)
+        .query_tokens_approved_for(
+            deps.as_ref(),
+            late_env.clone(),
+            "market".to_string(),
+            false,
+            None,
+            None,
+        )
         .unwrap();
-    assert_eq!(
-        res,
-        Response::new()
-            .add_attribute("action", "approve")
-            .add_attribute("sender", "demeter")
-            .add_attribute("spender", "random")
-            .add_attribute("token_id", token_id.clone())
-    );
-
-    // test approval query
+    assert_eq!(res.tokens, vec!["1".to_string()]);
     let res = contract
-        .query_approval(
+        .query_tokens_approved_for(
             deps.as_ref(),
-            mock_env(),
-            token_id.clone(),
-            String::from("random"),
+            late_env,
+            "market".to_string(),
             true,
+            None,
+            None,
         )
         .unwrap();
-    assert_eq!(
-        res,
-        ApprovalResponse {
-            approval: Approval {
-                spender: Addr::unchecked("random"),
-                expires: Expiration::Never {}
-            }
-        }
-    );
+    assert_eq!(res.tokens, vec!["1".to_string(), "2".to_string()]);
 
-    // random can now transfer
-    let random = mock_info("random", &[]);
-    let transfer_msg = Cw721ExecuteMsg::TransferNft {
-        recipient: String::from("person"),
-        token_id: token_id.clone(),
+    // revoke removes the approval from the index
+    let revoke_msg = Cw721ExecuteMsg::Revoke {
+        spender: "market".to_string(),
+        token_id: "1".to_string(),
     };
     contract
-        .execute(deps.as_mut(), mock_env(), random, transfer_msg)
+        .execute(deps.as_mut(), mock_env(), owner.clone(), revoke_msg)
         .unwrap();
+    let res = contract
+        .query_tokens_approved_for(
+            deps.as_ref(),
+            mock_env(),
+            "market".to_string(),
+            true,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(res.tokens, vec!["2".to_string()]);
 
-    // Approvals are removed / cleared
-    let query_msg = Cw721QueryMsg::OwnerOf {
-        token_id: token_id.clone(),
-        include_expired: None,
-    };
-    let res: OwnerOfResponse = from_json(
-        contract
-            .query(deps.as_ref(), mock_env(), query_msg.clone())
-            .unwrap(),
-    )
-    .unwrap();
-    assert_eq!(
-        res,
-        OwnerOfResponse {
-            owner: String::from("person"),
-            approvals: vec![],
-        }
-    );
-
-    // Approve, revoke, and check for empty, to test revoke
-    let approve_msg = Cw721ExecuteMsg::Approve {
-        spender: String::from("random"),
-        token_id: token_id.clone(),
-        expires: None,
+    // transfer clears all approvals of the token
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: "ceres".to_string(),
+        token_id: "2".to_string(),
     };
-    let owner = mock_info("person", &[]);
     contract
-        .execute(deps.as_mut(), mock_env(), owner.clone(), approve_msg)
+        .execute(deps.as_mut(), mock_env(), owner.clone(), transfer_msg)
+        .unwrap();
+    let res = contract
+        .query_tokens_approved_for(
+            deps.as_ref(),
+            mock_env(),
+            "market".to_string(),
+            true,
+            None,
+            None,
+        )
         .unwrap();
+    assert!(res.tokens.is_empty());
 
-    let revoke_msg = Cw721ExecuteMsg::Revoke {
-        spender: String::from("random"),
-        token_id,
+    // so does burn
+    let burn_msg = Cw721ExecuteMsg::Burn {
+        token_id: "3".to_string(),
     };
     contract
-        .execute(deps.as_mut(), mock_env(), owner, revoke_msg)
+        .execute(deps.as_mut(), mock_env(), owner, burn_msg)
         .unwrap();
-
-    // Approvals are now removed / cleared
-    let res: OwnerOfResponse = from_json(
-        contract
-            .query(deps.as_ref(), mock_env(), query_msg)
-            .unwrap(),
-    )
-    .unwrap();
-    assert_eq!(
-        res,
-        OwnerOfResponse {
-            owner: String::from("person"),
-            approvals: vec![],
-        }
-    );
+    let res = contract
+        .query_tokens_approved_for(
+            deps.as_ref(),
+            mock_env(),
+            "other".to_string(),
+            true,
+            None,
+            None,
+        )
+        .unwrap();
+    assert!(res.tokens.is_empty());
 }
 
 #[test]
-fn approving_all_revoking_all() {
+fn prune_expired() {
     let mut deps = mock_dependencies();
     let contract = setup_contract(deps.as_mut());
 
-    // Mint a couple tokens (from the same owner)
-    let token_id1 = "grow1".to_string();
-    let token_uri1 = "https://www.merriam-webster.com/dictionary/grow1".to_string();
+    let minter = mock_info(MINTER_ADDR, &[]);
+    for token_id in ["1", "2"] {
+        let mint_msg = Cw721ExecuteMsg::Mint {
+            token_id: Some(token_id.to_string()),
+            owner: String::from("demeter"),
+            token_uri: None,
+            extension: None,
+        };
+        contract
+            .execute(deps.as_mut(), mock_env(), minter.clone(), mint_msg)
+            .unwrap();
+    }
 
-    let token_id2 = "grow2".to_string();
-    let token_uri2 = "https://www.merriam-webster.com/dictionary/grow2".to_string();
+    // approvals and operators, some of them expiring
+    let owner = mock_info("demeter", &[]);
+    let expires = Expiration::AtHeight(20000);
+    for (token_id, spender, expires) in [
+        ("1", "expiring", Some(expires)),
+        ("1", "forever", None),
+        ("2", "expiring", Some(expires)),
+    ] {
+        let approve_msg = Cw721ExecuteMsg::Approve {
+            spender: spender.to_string(),
+            token_id: token_id.to_string(),
+            expires,
+        };
+        contract
+            .execute(deps.as_mut(), mock_env(), owner.clone(), approve_msg)
+            .unwrap();
+    }
+    for (operator, expires) in [("expiring", Some(expires)), ("forever", None)] {
+        let approve_all_msg = Cw721ExecuteMsg::ApproveAll {
+            operator: operator.to_string(),
+            expires,
+        };
+        contract
+            .execute(deps.as_mut(), mock_env(), owner.clone(), approve_all_msg)
+            .unwrap();
+    }
 
-    let mint_msg1 = Cw721ExecuteMsg::Mint {
-        token_id: token_id1.clone(),
-        owner: String::from("demeter"),
-        token_uri: Some(token_uri1),
-        extension: None,
+    // nothing expired yet
+    let anyone = mock_info("anyone", &[]);
+    let prune_msg = Cw721ExecuteMsg::PruneExpired {
+        token_ids: None,
+        owners: None,
+        start_after: None,
+        limit: None,
     };
-
-    let minter = mock_info(MINTER_ADDR, &[]);
-    contract
-        .execute(deps.as_mut(), mock_env(), minter.clone(), mint_msg1)
+    let res = contract
+        .execute(deps.as_mut(), mock_env(), anyone.clone(), prune_msg)
         .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_event(
+                Cw721Event::PruneExpired {
+                    sender: "anyone".to_string(),
+                    pruned_approvals: 0,
+                    pruned_operators: 0,
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
+            .add_attribute("action", "prune_expired")
+            .add_attribute("sender", "anyone")
+            .add_attribute("pruned_approvals", "0")
+            .add_attribute("pruned_operators", "0")
+    );
 
-    let mint_msg2 = Cw721ExecuteMsg::Mint {
-        token_id: token_id2.clone(),
-        owner: String::from("demeter"),
-        token_uri: Some(token_uri2),
-        extension: None,
+    // anyone can prune expired approvals of given tokens and operators of given owners
+    let mut late_env = mock_env();
+    late_env.block.height = 20001;
+    let prune_msg = Cw721ExecuteMsg::PruneExpired {
+        token_ids: Some(vec!["1".to_string()]),
+        owners: Some(vec!["demeter".to_string()]),
+        start_after: None,
+        limit: None,
     };
-
-    let env = mock_env();
-    contract
-        .execute(deps.as_mut(), env.clone(), minter, mint_msg2)
+    let res = contract
+        .execute(deps.as_mut(), late_env.clone(), anyone.clone(), prune_msg)
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_event(
+                Cw721Event::PruneExpired {
+                    sender: "anyone".to_string(),
+                    pruned_approvals: 1,
+                    pruned_operators: 1,
+                }
+                .into_event(deps.as_ref().storage)
+                .unwrap()
+            )
+            .add_attribute("action", "prune_expired")
+            .add_attribute("sender", "anyone")
+            .add_attribute("pruned_approvals", "1")
+            .add_attribute("pruned_operators", "1")
+    );
+    let approvals = contract
+        .query_approvals(deps.as_ref(), late_env.clone(), "1".to_string(), true)
+        .unwrap();
+    assert_eq!(
+        approvals.approvals,
+        vec![Approval {
+            spender: Addr::unchecked("forever"),
+            expires: Expiration::Never {},
+        }]
+    );
+    let operators = contract
+        .query_operators(
+            deps.as_ref(),
+            late_env.clone(),
+            "demeter".to_string(),
+            true,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        operators.operators,
+        vec![Approval {
+            spender: Addr::unchecked("forever"),
+            expires: Expiration::Never {},
+        }]
+    );
+    let owners = contract
+        .query_approved_owners_for_operator(
+            deps.as_ref(),
+            late_env.clone(),
+            "expiring".to_string(),
+            true,
+            None,
+            None,
+        )
         .unwrap();
+    assert!(owners.owners.is_empty());
 
-    // paginate the token_ids
+    // token 2 is untouched, but found when scanning all approvals
     let tokens = contract
-        .query_all_tokens(deps.as_ref(), env.clone(), None, Some(1))
+        .query_tokens_approved_for(
+            deps.as_ref(),
+            late_env.clone(),
+            "expiring".to_string(),
+            true,
+            None,
+            None,
+        )
         .unwrap();
-    assert_eq!(1, tokens.tokens.len());
-    assert_eq!(vec![token_id1.clone()], tokens.tokens);
+    assert_eq!(tokens.tokens, vec!["2".to_string()]);
+    let prune_msg = Cw721ExecuteMsg::PruneExpired {
+        token_ids: None,
+        owners: None,
+        start_after: None,
+        limit: Some(1),
+    };
+    contract
+        .execute(deps.as_mut(), late_env.clone(), anyone, prune_msg)
+        .unwrap();
+    let approvals = contract
+        .query_approvals(deps.as_ref(), late_env.clone(), "2".to_string(), true)
+        .unwrap();
+    assert!(approvals.approvals.is_empty());
     let tokens = contract
-        .query_all_tokens(deps.as_ref(), env, Some(token_id1.clone()), Some(3))
+        .query_tokens_approved_for(
+            deps.as_ref(),
+            late_env,
+            "expiring".to_string(),
+            true,
+            None,
+            None,
+        )
         .unwrap();
-    assert_eq!(1, tokens.tokens.len());
-    assert_eq!(vec![token_id2.clone()], tokens.tokens);
+    assert!(tokens.tokens.is_empty());
+}
 
-    // demeter gives random full (operator) power over her tokens
-    let approve_all_msg = Cw721ExecuteMsg::ApproveAll {
-        operator: String::from("random"),
-        expires: None,
-    };
+#[test]
+fn prune_expired_in_batches() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    let minter = mock_info(MINTER_ADDR, &[]);
+    for token_id in ["1", "2", "3"] {
+        let mint_msg = Cw721ExecuteMsg::Mint {
+            token_id: Some(token_id.to_string()),
+            owner: String::from("demeter"),
+            token_uri: None,
+            extension: None,
+        };
+        contract
+            .execute(deps.as_mut(), mock_env(), minter.clone(), mint_msg)
+            .unwrap();
+    }
     let owner = mock_info("demeter", &[]);
+    let expires = Expiration::AtHeight(20000);
+    let approve = |deps: DepsMut, token_id: &str| {
+        let approve_msg = Cw721ExecuteMsg::Approve {
+            spender: "expiring".to_string(),
+            token_id: token_id.to_string(),
+            expires: Some(expires),
+        };
+        contract
+            .execute(deps, mock_env(), owner.clone(), approve_msg)
+            .unwrap();
+    };
+    let approve_all = |deps: DepsMut, operator: &str, expires: Option<Expiration>| {
+        let approve_all_msg = Cw721ExecuteMsg::ApproveAll {
+            operator: operator.to_string(),
+            expires,
+        };
+        contract
+            .execute(deps, mock_env(), owner.clone(), approve_all_msg)
+            .unwrap();
+    };
+    approve(deps.as_mut(), "1");
+    approve(deps.as_mut(), "2");
+    approve_all(deps.as_mut(), "expiring", Some(expires));
+    approve_all(deps.as_mut(), "forever", None);
+
+    let mut late_env = mock_env();
+    late_env.block.height = 20001;
+    let anyone = mock_info("anyone", &[]);
+    let pruned = |res: &Response| {
+        res.attributes
+            .iter()
+            .filter(|attr| attr.key.starts_with("pruned_"))
+            .map(|attr| attr.value.clone())
+            .collect::<Vec<_>>()
+    };
+
+    // scanning reads at most `limit` entries and returns where to continue,
+    // a limit of 0 still reads one entry, so a missing cursor always means done
+    let prune_msg = Cw721ExecuteMsg::PruneExpired {
+        token_ids: None,
+        owners: None,
+        start_after: None,
+        limit: Some(0),
+    };
     let res = contract
-        .execute(deps.as_mut(), mock_env(), owner, approve_all_msg)
+        .execute(deps.as_mut(), late_env.clone(), anyone.clone(), prune_msg)
         .unwrap();
+    assert_eq!(pruned(&res), vec!["1", "0"]);
+    let start_after: PruneCursor = from_json(res.data.unwrap()).unwrap();
     assert_eq!(
-        res,
-        Response::new()
-            .add_attribute("action", "approve_all")
-            .add_attribute("sender", "demeter")
-            .add_attribute("operator", "random")
+        start_after,
+        PruneCursor::Approval {
+            spender: "expiring".to_string(),
+            token_id: "1".to_string(),
+        }
     );
 
-    // random can now transfer
-    let random = mock_info("random", &[]);
-    let transfer_msg = Cw721ExecuteMsg::TransferNft {
-        recipient: String::from("person"),
-        token_id: token_id1,
+    // the rest of the batch continues with operators
+    let prune_msg = Cw721ExecuteMsg::PruneExpired {
+        token_ids: None,
+        owners: None,
+        start_after: Some(start_after),
+        limit: Some(2),
     };
-    contract
-        .execute(deps.as_mut(), mock_env(), random.clone(), transfer_msg)
+    let res = contract
+        .execute(deps.as_mut(), late_env.clone(), anyone.clone(), prune_msg)
         .unwrap();
+    assert_eq!(pruned(&res), vec!["1", "1"]);
+    let start_after: PruneCursor = from_json(res.data.unwrap()).unwrap();
+    assert_eq!(
+        start_after,
+        PruneCursor::Operator {
+            owner: "demeter".to_string(),
+            operator: "expiring".to_string(),
+        }
+    );
 
-    // random can now send
-    let inner_msg = WasmMsg::Execute {
-        contract_addr: "another_contract".into(),
-        msg: to_json_binary("You now also have the growing power").unwrap(),
-        funds: vec![],
+    // no data once the scan is complete
+    let prune_msg = Cw721ExecuteMsg::PruneExpired {
+        token_ids: None,
+        owners: None,
+        start_after: Some(start_after),
+        limit: None,
     };
-    let msg: CosmosMsg = CosmosMsg::Wasm(inner_msg);
-
-    let send_msg = Cw721ExecuteMsg::SendNft {
-        contract: String::from("another_contract"),
-        token_id: token_id2,
-        msg: to_json_binary(&msg).unwrap(),
+    let res = contract
+        .execute(deps.as_mut(), late_env.clone(), anyone.clone(), prune_msg)
+        .unwrap();
+    assert_eq!(pruned(&res), vec!["0", "0"]);
+    assert_eq!(res.data, None);
+
+    // duplicates are counted once and burned tokens are skipped
+    approve(deps.as_mut(), "1");
+    approve_all(deps.as_mut(), "expiring", Some(expires));
+    let burn_msg = Cw721ExecuteMsg::Burn {
+        token_id: "3".to_string(),
     };
     contract
-        .execute(deps.as_mut(), mock_env(), random, send_msg)
+        .execute(deps.as_mut(), mock_env(), owner.clone(), burn_msg)
         .unwrap();
-
-    // Approve_all, revoke_all, and check for empty, to test revoke_all
-    let approve_all_msg = Cw721ExecuteMsg::ApproveAll {
-        operator: String::from("operator"),
-        expires: None,
+    let prune_msg = Cw721ExecuteMsg::PruneExpired {
+        token_ids: Some(vec!["1".to_string(), "3".to_string(), "1".to_string()]),
+        owners: Some(vec!["demeter".to_string(), "demeter".to_string()]),
+        start_after: None,
+        limit: None,
     };
-    // person is now the owner of the tokens
-    let owner = mock_info("person", &[]);
-    contract
-        .execute(deps.as_mut(), mock_env(), owner, approve_all_msg)
+    let res = contract
+        .execute(deps.as_mut(), late_env.clone(), anyone.clone(), prune_msg)
         .unwrap();
-
-    // query for operator should return approval
+    assert_eq!(pruned(&res), vec!["1", "1"]);
+    let approvals = contract
+        .query_approvals(deps.as_ref(), late_env.clone(), "1".to_string(), true)
+        .unwrap();
+    assert!(approvals.approvals.is_empty());
+
+    // given tokens and owners stop at `limit` removed entries and return where to continue
+    approve(deps.as_mut(), "1");
+    approve(deps.as_mut(), "2");
+    approve_all(deps.as_mut(), "expiring", Some(expires));
+    approve_all(deps.as_mut(), "other", Some(expires));
+    let prune_msg =
+        |start_after: Option<PruneCursor>, limit: Option<u32>| Cw721ExecuteMsg::PruneExpired {
+            token_ids: Some(vec!["1".to_string(), "2".to_string()]),
+            owners: Some(vec!["demeter".to_string()]),
+            start_after,
+            limit,
+        };
     let res = contract
-        .query_operator(
-            deps.as_ref(),
-            mock_env(),
-            String::from("person"),
-            String::from("operator"),
-            true,
+        .execute(
+            deps.as_mut(),
+            late_env.clone(),
+            anyone.clone(),
+            prune_msg(None, Some(1)),
         )
         .unwrap();
+    assert_eq!(pruned(&res), vec!["1", "0"]);
+    let start_after: PruneCursor = from_json(res.data.unwrap()).unwrap();
     assert_eq!(
-        res,
-        OperatorResponse {
-            approval: Approval {
-                spender: Addr::unchecked("operator"),
-                expires: Expiration::Never {}
-            }
+        start_after,
+        PruneCursor::Approval {
+            spender: "expiring".to_string(),
+            token_id: "1".to_string(),
         }
     );
 
-    // query for other should throw error
-    let res = contract.query_operator(
-        deps.as_ref(),
-        mock_env(),
-        String::from("person"),
-        String::from("other"),
-        true,
+    let res = contract
+        .execute(
+            deps.as_mut(),
+            late_env.clone(),
+            anyone.clone(),
+            prune_msg(Some(start_after), Some(2)),
+        )
+        .unwrap();
+    assert_eq!(pruned(&res), vec!["1", "1"]);
+    let start_after: PruneCursor = from_json(res.data.unwrap()).unwrap();
+    assert_eq!(
+        start_after,
+        PruneCursor::Operator {
+            owner: "demeter".to_string(),
+            operator: "expiring".to_string(),
+        }
     );
-    match res {
-        Err(StdError::NotFound { kind }) => assert_eq!(kind, "Approval not found"),
-        _ => panic!("Unexpected error"),
-    }
 
     let res = contract
+        .execute(
+            deps.as_mut(),
+            late_env.clone(),
+            anyone.clone(),
+            prune_msg(Some(start_after), None),
+        )
+        .unwrap();
+    assert_eq!(pruned(&res), vec!["0", "1"]);
+    assert_eq!(res.data, None);
+
+    // operators are not scanned if only tokens are given
+    approve(deps.as_mut(), "1");
+    approve_all(deps.as_mut(), "expiring", Some(expires));
+    let prune_msg = Cw721ExecuteMsg::PruneExpired {
+        token_ids: Some(vec!["1".to_string()]),
+        owners: None,
+        start_after: None,
+        limit: None,
+    };
+    let res = contract
+        .execute(deps.as_mut(), late_env.clone(), anyone, prune_msg)
+        .unwrap();
+    assert_eq!(pruned(&res), vec!["1", "0"]);
+    let operators = contract
         .query_operators(
             deps.as_ref(),
-            mock_env(),
-            String::from("person"),
+            late_env,
+            "demeter".to_string(),
             true,
             None,
             None,
+            None,
+            None,
         )
         .unwrap();
-    assert_eq!(
-        res,
-        OperatorsResponse {
-            operators: vec![Approval {
-                spender: Addr::unchecked("operator"),
-                expires: Expiration::Never {}
-            }]
-        }
-    );
+    assert_eq!(operators.operators.len(), 2);
+}
+
+#[test]
+fn balance_of() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+    let balance_of = |deps: Deps, owner: &str| {
+        contract
+            .query_balance_of(deps, mock_env(), owner.to_string())
+            .unwrap()
+            .balance
+    };
+    assert_eq!(balance_of(deps.as_ref(), "demeter"), 0);
+
+    // mint and batch mint increase balances
+    let minter = mock_info(MINTER_ADDR, &[]);
+    for token_id in ["1", "2"] {
+        let mint_msg = Cw721ExecuteMsg::Mint {
+            token_id: Some(token_id.to_string()),
+            owner: String::from("demeter"),
+            token_uri: None,
+            extension: None,
+        };
+        contract
+            .execute(deps.as_mut(), mock_env(), minter.clone(), mint_msg)
+            .unwrap();
+    }
+    let batch_mint_msg = Cw721ExecuteMsg::BatchMint {
+        tokens: vec![MintMsg {
+            token_id: Some("3".to_string()),
+            owner: String::from("ceres"),
+            token_uri: None,
+            extension: None,
+        }],
+    };
+    contract
+        .execute(deps.as_mut(), mock_env(), minter, batch_mint_msg)
+        .unwrap();
+    assert_eq!(balance_of(deps.as_ref(), "demeter"), 2);
+    assert_eq!(balance_of(deps.as_ref(), "ceres"), 1);
 
-    // second approval
-    let buddy_expires = Expiration::AtHeight(1234567);
-    let approve_all_msg = Cw721ExecuteMsg::ApproveAll {
-        operator: String::from("buddy"),
-        expires: Some(buddy_expires),
+    // transferring to oneself does not change the balance
+    let demeter = mock_info("demeter", &[]);
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: "demeter".to_string(),
+        token_id: "2".to_string(),
     };
-    let owner = mock_info("person", &[]);
     contract
-        .execute(deps.as_mut(), mock_env(), owner.clone(), approve_all_msg)
+        .execute(deps.as_mut(), mock_env(), demeter.clone(), transfer_msg)
         .unwrap();
+    assert_eq!(balance_of(deps.as_ref(), "demeter"), 2);
 
-    // and paginate queries
-    let res = contract
-        .query_operators(
-            deps.as_ref(),
+    // transfer moves the balance
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: "ceres".to_string(),
+        token_id: "1".to_string(),
+    };
+    contract
+        .execute(deps.as_mut(), mock_env(), demeter.clone(), transfer_msg)
+        .unwrap();
+    assert_eq!(balance_of(deps.as_ref(), "demeter"), 1);
+    assert_eq!(balance_of(deps.as_ref(), "ceres"), 2);
+
+    // burn and batch burn decrease balances
+    let burn_msg = Cw721ExecuteMsg::Burn {
+        token_id: "2".to_string(),
+    };
+    contract
+        .execute(deps.as_mut(), mock_env(), demeter, burn_msg)
+        .unwrap();
+    let batch_burn_msg = Cw721ExecuteMsg::BatchBurn {
+        token_ids: vec!["1".to_string(), "3".to_string()],
+    };
+    contract
+        .execute(
+            deps.as_mut(),
             mock_env(),
-            String::from("person"),
-            true,
-            None,
-            Some(1),
+            mock_info("ceres", &[]),
+            batch_burn_msg,
         )
         .unwrap();
-    assert_eq!(
-        res,
-        OperatorsResponse {
-            operators: vec![Approval {
-                spender: Addr::unchecked("buddy"),
-                expires: buddy_expires,
-            }]
-        }
-    );
+    assert_eq!(balance_of(deps.as_ref(), "demeter"), 0);
+    assert_eq!(balance_of(deps.as_ref(), "ceres"), 0);
+    // zero balances are removed from storage
+    assert!(contract
+        .config
+        .balances
+        .keys(deps.as_ref().storage, None, None, Order::Ascending)
+        .next()
+        .is_none());
+}
+
+#[test]
+fn structured_events() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    let mint_msg = Cw721ExecuteMsg::Mint {
+        token_id: Some("1".to_string()),
+        owner: String::from("demeter"),
+        token_uri: Some("https://example.com/1.json".to_string()),
+        extension: None,
+    };
     let res = contract
-        .query_operators(
-            deps.as_ref(),
+        .execute(
+            deps.as_mut(),
             mock_env(),
-            String::from("person"),
-            true,
-            Some(String::from("buddy")),
-            Some(2),
+            mock_info(MINTER_ADDR, &[]),
+            mint_msg,
         )
         .unwrap();
     assert_eq!(
-        res,
-        OperatorsResponse {
-            operators: vec![Approval {
-                spender: Addr::unchecked("operator"),
-                expires: Expiration::Never {}
-            }]
-        }
+        res.events,
+        vec![Event::new("cw721_mint")
+            .add_attribute("contract_name", "contract_name")
+            .add_attribute("contract_version", "contract_version")
+            .add_attribute("minter", MINTER_ADDR)
+            .add_attribute("owner", "demeter")
+            .add_attribute("token_id", "1")
+            .add_attribute("token_uri", "https://example.com/1.json")]
     );
 
-    let revoke_all_msg = Cw721ExecuteMsg::RevokeAll {
-        operator: String::from("operator"),
-    };
+    // operator transfers, previous owner is included
     contract
-        .execute(deps.as_mut(), mock_env(), owner, revoke_all_msg)
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::ApproveAll {
+                operator: "operator".to_string(),
+                expires: None,
+            },
+        )
         .unwrap();
-
-    // query for operator should return error
-    let res = contract.query_operator(
-        deps.as_ref(),
-        mock_env(),
-        String::from("person"),
-        String::from("operator"),
-        true,
-    );
-    match res {
-        Err(StdError::NotFound { kind }) => assert_eq!(kind, "Approval not found"),
-        _ => panic!("Unexpected error"),
-    }
-
-    // Approvals are removed / cleared without affecting others
+    let transfer_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: "ceres".to_string(),
+        token_id: "1".to_string(),
+    };
     let res = contract
-        .query_operators(
-            deps.as_ref(),
+        .execute(
+            deps.as_mut(),
             mock_env(),
-            String::from("person"),
-            false,
-            None,
-            None,
+            mock_info("operator", &[]),
+            transfer_msg,
         )
         .unwrap();
     assert_eq!(
-        res,
-        OperatorsResponse {
-            operators: vec![Approval {
-                spender: Addr::unchecked("buddy"),
-                expires: buddy_expires,
-            }]
-        }
+        res.events,
+        vec![Event::new("cw721_transfer")
+            .add_attribute("contract_name", "contract_name")
+            .add_attribute("contract_version", "contract_version")
+            .add_attribute("sender", "operator")
+            .add_attribute("from", "demeter")
+            .add_attribute("to", "ceres")
+            .add_attribute("token_id", "1")]
     );
 
-    // ensure the filter works (nothing should be here
-    let mut late_env = mock_env();
-    late_env.block.height = 1234568; //expired
+    // burn
     let res = contract
-        .query_operators(
-            deps.as_ref(),
-            late_env.clone(),
-            String::from("person"),
-            false,
-            None,
-            None,
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("ceres", &[]),
+            Cw721ExecuteMsg::Burn {
+                token_id: "1".to_string(),
+            },
         )
         .unwrap();
-    assert_eq!(0, res.operators.len());
-
-    // query operator should also return error
-    let res = contract.query_operator(
-        deps.as_ref(),
-        late_env,
-        String::from("person"),
-        String::from("buddy"),
-        false,
+    assert_eq!(
+        res.events,
+        vec![Event::new("cw721_burn")
+            .add_attribute("contract_name", "contract_name")
+            .add_attribute("contract_version", "contract_version")
+            .add_attribute("sender", "ceres")
+            .add_attribute("owner", "ceres")
+            .add_attribute("token_id", "1")]
     );
 
-    match res {
-        Err(StdError::NotFound { kind }) => assert_eq!(kind, "Approval not found"),
-        _ => panic!("Unexpected error"),
+    // admin actions
+    let event = |ty: &str, sender: &str| {
+        Event::new(ty)
+            .add_attribute("contract_name", "contract_name")
+            .add_attribute("contract_version", "contract_version")
+            .add_attribute("sender", sender)
+    };
+    let expiry = Expiration::AtHeight(mock_env().block.height + 100);
+    let msgs = vec![
+        (
+            MINTER_ADDR,
+            Cw721ExecuteMsg::UpdateMinterOwnership(Action::TransferOwnership {
+                new_owner: "random".to_string(),
+                expiry: None,
+            }),
+            event("cw721_update_minter_ownership", MINTER_ADDR)
+                .add_attribute("owner", MINTER_ADDR)
+                .add_attribute("pending_owner", "random")
+                .add_attribute("pending_expiry", ""),
+        ),
+        (
+            CREATOR_ADDR,
+            Cw721ExecuteMsg::SetWithdrawAddress {
+                address: "foo".to_string(),
+            },
+            event("cw721_set_withdraw_address", CREATOR_ADDR).add_attribute("address", "foo"),
+        ),
+        (
+            "random",
+            Cw721ExecuteMsg::WithdrawFunds {
+                amount: Coin::new(100, "uark"),
+            },
+            Event::new("cw721_withdraw_funds")
+                .add_attribute("contract_name", "contract_name")
+                .add_attribute("contract_version", "contract_version")
+                .add_attribute("address", "foo")
+                .add_attribute("amount", "100")
+                .add_attribute("denom", "uark"),
+        ),
+        (
+            CREATOR_ADDR,
+            Cw721ExecuteMsg::RemoveWithdrawAddress {},
+            event("cw721_remove_withdraw_address", CREATOR_ADDR).add_attribute("address", "foo"),
+        ),
+        (
+            CREATOR_ADDR,
+            Cw721ExecuteMsg::MigrateIndexes { limit: None },
+            event("cw721_migrate_indexes", CREATOR_ADDR)
+                .add_attribute("migrated_operator_grants", "0")
+                .add_attribute("migrated_token_approvals", "0")
+                .add_attribute("migrated_balances", "0")
+                .add_attribute("pending", "false"),
+        ),
+        (
+            CREATOR_ADDR,
+            Cw721ExecuteMsg::UpdateCreatorOwnership(Action::TransferOwnership {
+                new_owner: "random".to_string(),
+                expiry: Some(expiry),
+            }),
+            event("cw721_update_creator_ownership", CREATOR_ADDR)
+                .add_attribute("owner", CREATOR_ADDR)
+                .add_attribute("pending_owner", "random")
+                .add_attribute("pending_expiry", expiry.to_string()),
+        ),
+    ];
+    for (sender, msg, expected) in msgs {
+        let res = contract
+            .execute(deps.as_mut(), mock_env(), mock_info(sender, &[]), msg)
+            .unwrap();
+        assert_eq!(res.events, vec![expected]);
     }
 }
 
@@ -947,17 +4991,17 @@ fn test_set_withdraw_address() {
     let mut deps = mock_dependencies();
     let contract = setup_contract(deps.as_mut());
 
-    // other than minter cant set
+    // other than creator cant set
     let err = contract
         .set_withdraw_address(deps.as_mut(), &Addr::unchecked("other"), "foo".to_string())
         .unwrap_err();
     assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
 
-    // minter can set
+    // creator can set
     contract
         .set_withdraw_address(
             deps.as_mut(),
-            &Addr::unchecked(MINTER_ADDR),
+            &Addr::unchecked(CREATOR_ADDR),
             "foo".to_string(),
         )
         .unwrap();
@@ -983,7 +5027,7 @@ fn test_remove_withdraw_address() {
 
     // no withdraw address set yet
     let err = contract
-        .remove_withdraw_address(deps.as_mut().storage, &Addr::unchecked(MINTER_ADDR))
+        .remove_withdraw_address(deps.as_mut().storage, &Addr::unchecked(CREATOR_ADDR))
         .unwrap_err();
     assert_eq!(err, Cw721ContractError::NoWithdrawAddress {});
 
@@ -991,12 +5035,12 @@ fn test_remove_withdraw_address() {
     contract
         .set_withdraw_address(
             deps.as_mut(),
-            &Addr::unchecked(MINTER_ADDR),
+            &Addr::unchecked(CREATOR_ADDR),
             "foo".to_string(),
         )
         .unwrap();
     contract
-        .remove_withdraw_address(deps.as_mut().storage, &Addr::unchecked(MINTER_ADDR))
+        .remove_withdraw_address(deps.as_mut().storage, &Addr::unchecked(CREATOR_ADDR))
         .unwrap();
     assert!(!contract
         .config
@@ -1007,7 +5051,7 @@ fn test_remove_withdraw_address() {
     contract
         .set_withdraw_address(
             deps.as_mut(),
-            &Addr::unchecked(MINTER_ADDR),
+            &Addr::unchecked(CREATOR_ADDR),
             "foo".to_string(),
         )
         .unwrap();
@@ -1034,7 +5078,7 @@ fn test_withdraw_funds() {
     contract
         .set_withdraw_address(
             deps.as_mut(),
-            &Addr::unchecked(MINTER_ADDR),
+            &Addr::unchecked(CREATOR_ADDR),
             "foo".to_string(),
         )
         .unwrap();
@@ -1057,7 +5101,7 @@ fn query_tokens_by_owner() {
     let token_id3 = "sing".to_string();
 
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id1.clone(),
+        token_id: Some(token_id1.clone()),
         owner: demeter.clone(),
         token_uri: None,
         extension: None,
@@ -1067,7 +5111,7 @@ fn query_tokens_by_owner() {
         .unwrap();
 
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id2.clone(),
+        token_id: Some(token_id2.clone()),
         owner: ceres.clone(),
         token_uri: None,
         extension: None,
@@ -1077,7 +5121,7 @@ fn query_tokens_by_owner() {
         .unwrap();
 
     let mint_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id3.clone(),
+        token_id: Some(token_id3.clone()),
         owner: demeter.clone(),
         token_uri: None,
         extension: None,
@@ -1090,45 +5134,226 @@ fn query_tokens_by_owner() {
     // get all tokens in order:
     let expected = vec![token_id1.clone(), token_id2.clone(), token_id3.clone()];
     let tokens = contract
-        .query_all_tokens(deps.as_ref(), env.clone(), None, None)
+        .query_all_tokens(deps.as_ref(), env.clone(), None, None, None, None)
         .unwrap();
     assert_eq!(&expected, &tokens.tokens);
     // paginate
     let tokens = contract
-        .query_all_tokens(deps.as_ref(), env.clone(), None, Some(2))
+        .query_all_tokens(deps.as_ref(), env.clone(), None, None, Some(2), None)
         .unwrap();
     assert_eq!(&expected[..2], &tokens.tokens[..]);
     let tokens = contract
-        .query_all_tokens(deps.as_ref(), env.clone(), Some(expected[1].clone()), None)
+        .query_all_tokens(
+            deps.as_ref(),
+            env.clone(),
+            Some(expected[1].clone()),
+            None,
+            None,
+            None,
+        )
         .unwrap();
     assert_eq!(&expected[2..], &tokens.tokens[..]);
+    // descending order
+    let reversed: Vec<String> = expected.iter().rev().cloned().collect();
+    let tokens = contract
+        .query_all_tokens(
+            deps.as_ref(),
+            env.clone(),
+            None,
+            None,
+            None,
+            Some(OrderBy::Descending),
+        )
+        .unwrap();
+    assert_eq!(&reversed, &tokens.tokens);
+    // paginate backwards
+    let tokens = contract
+        .query_all_tokens(
+            deps.as_ref(),
+            env.clone(),
+            None,
+            Some(expected[2].clone()),
+            Some(1),
+            Some(OrderBy::Descending),
+        )
+        .unwrap();
+    assert_eq!(&expected[1..2], &tokens.tokens[..]);
+    // start_after and start_before combined
+    let tokens = contract
+        .query_all_tokens(
+            deps.as_ref(),
+            env.clone(),
+            Some(expected[0].clone()),
+            Some(expected[2].clone()),
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(&expected[1..2], &tokens.tokens[..]);
 
     // get by owner
     let by_ceres = vec![token_id2];
     let by_demeter = vec![token_id1, token_id3];
     // all tokens by owner
     let tokens = contract
-        .query_tokens(deps.as_ref(), env.clone(), demeter.clone(), None, None)
+        .query_tokens(
+            deps.as_ref(),
+            env.clone(),
+            demeter.clone(),
+            None,
+            None,
+            None,
+            None,
+        )
         .unwrap();
     assert_eq!(&by_demeter, &tokens.tokens);
     let tokens = contract
-        .query_tokens(deps.as_ref(), env.clone(), ceres, None, None)
+        .query_tokens(deps.as_ref(), env.clone(), ceres, None, None, None, None)
         .unwrap();
     assert_eq!(&by_ceres, &tokens.tokens);
 
     // paginate for demeter
     let tokens = contract
-        .query_tokens(deps.as_ref(), env.clone(), demeter.clone(), None, Some(1))
+        .query_tokens(
+            deps.as_ref(),
+            env.clone(),
+            demeter.clone(),
+            None,
+            None,
+            Some(1),
+            None,
+        )
         .unwrap();
     assert_eq!(&by_demeter[..1], &tokens.tokens[..]);
+    let tokens = contract
+        .query_tokens(
+            deps.as_ref(),
+            env.clone(),
+            demeter.clone(),
+            None,
+            None,
+            Some(1),
+            Some(OrderBy::Descending),
+        )
+        .unwrap();
+    assert_eq!(&by_demeter[1..], &tokens.tokens[..]);
     let tokens = contract
         .query_tokens(
             deps.as_ref(),
             env,
             demeter,
             Some(by_demeter[0].clone()),
+            None,
             Some(3),
+            None,
         )
         .unwrap();
     assert_eq!(&by_demeter[1..], &tokens.tokens[..]);
 }
+
+#[test]
+fn query_tokens_with_info() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+    let minter = mock_info(MINTER_ADDR, &[]);
+    let env = mock_env();
+
+    let demeter = String::from("demeter");
+    let ceres = String::from("ceres");
+    for (token_id, owner) in [("grow1", &demeter), ("grow2", &ceres), ("sing", &demeter)] {
+        contract
+            .execute(
+                deps.as_mut(),
+                env.clone(),
+                minter.clone(),
+                Cw721ExecuteMsg::Mint {
+                    token_id: Some(token_id.to_string()),
+                    owner: owner.clone(),
+                    token_uri: Some(format!("https://example.com/{token_id}")),
+                    extension: None,
+                },
+            )
+            .unwrap();
+    }
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("demeter", &[]),
+            Cw721ExecuteMsg::Approve {
+                spender: String::from("random"),
+                token_id: "sing".to_string(),
+                expires: None,
+            },
+        )
+        .unwrap();
+
+    let token = |token_id: &str, owner: &str, approvals: Option<Vec<Approval>>| TokenWithInfo {
+        token_id: token_id.to_string(),
+        owner: owner.to_string(),
+        token_uri: Some(format!("https://example.com/{token_id}")),
+        extension: None,
+        approvals,
+    };
+
+    // all tokens in order, approvals omitted by default
+    let tokens = contract
+        .query_all_tokens_with_info(deps.as_ref(), env.clone(), None, None, false)
+        .unwrap();
+    assert_eq!(
+        tokens.tokens,
+        vec![
+            token("grow1", "demeter", None),
+            token("grow2", "ceres", None),
+            token("sing", "demeter", None),
+        ]
+    );
+    // paginate
+    let tokens = contract
+        .query_all_tokens_with_info(
+            deps.as_ref(),
+            env.clone(),
+            Some("grow1".to_string()),
+            Some(1),
+            false,
+        )
+        .unwrap();
+    assert_eq!(tokens.tokens, vec![token("grow2", "ceres", None)]);
+
+    // by owner, including approvals
+    let tokens = contract
+        .query_tokens_with_info(
+            deps.as_ref(),
+            env.clone(),
+            demeter.clone(),
+            None,
+            None,
+            true,
+        )
+        .unwrap();
+    assert_eq!(
+        tokens.tokens,
+        vec![
+            token("grow1", "demeter", Some(vec![])),
+            token(
+                "sing",
+                "demeter",
+                Some(vec![Approval {
+                    spender: Addr::unchecked("random"),
+                    expires: Expiration::Never {},
+                }])
+            ),
+        ]
+    );
+    let tokens = contract
+        .query_tokens_with_info(
+            deps.as_ref(),
+            env,
+            demeter,
+            Some("grow1".to_string()),
+            None,
+            false,
+        )
+        .unwrap();
+    assert_eq!(tokens.tokens, vec![token("sing", "demeter", None)]);
+}
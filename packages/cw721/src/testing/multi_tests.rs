@@ -2,11 +2,11 @@ use crate::{
     error::Cw721ContractError,
     execute::Cw721Execute,
     msg::{
-        Cw721ExecuteMsg, Cw721InstantiateMsg, Cw721MigrateMsg, Cw721QueryMsg, MinterResponse,
-        OwnerOfResponse,
+        CollectionInfoAndExtensionResponse, Cw721ExecuteMsg, Cw721InstantiateMsg, Cw721MigrateMsg,
+        Cw721QueryMsg, DefaultOptionCollectionInfoExtensionMsg, MinterResponse, OwnerOfResponse,
     },
     query::Cw721Query,
-    state::DefaultOptionMetadataExtension,
+    state::{DefaultOptionCollectionInfoExtension, DefaultOptionMetadataExtension},
 };
 use cosmwasm_std::{
     to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, QuerierWrapper, Response,
@@ -27,9 +27,14 @@ pub fn instantiate(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    msg: Cw721InstantiateMsg,
+    msg: Cw721InstantiateMsg<DefaultOptionCollectionInfoExtensionMsg>,
 ) -> Result<Response, Cw721ContractError> {
-    let contract = Cw721Contract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
     contract.instantiate(deps, env, info, msg, "contract_name", "contract_version")
 }
 
@@ -39,16 +44,26 @@ pub fn execute(
     info: MessageInfo,
     msg: Cw721ExecuteMsg<DefaultOptionMetadataExtension, Empty>,
 ) -> Result<Response, Cw721ContractError> {
-    let contract = Cw721Contract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
     contract.execute(deps, env, info, msg)
 }
 
 pub fn query(
     deps: Deps,
     env: Env,
-    msg: Cw721QueryMsg<DefaultOptionMetadataExtension>,
+    msg: Cw721QueryMsg<DefaultOptionMetadataExtension, DefaultOptionCollectionInfoExtension>,
 ) -> StdResult<Binary> {
-    let contract = Cw721Contract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
     contract.query(deps, env, msg)
 }
 
@@ -57,7 +72,12 @@ pub fn migrate(
     env: Env,
     msg: Cw721MigrateMsg,
 ) -> Result<Response, Cw721ContractError> {
-    let contract = Cw721Contract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
     contract.migrate(deps, env, msg, "contract_name", "contract_version")
 }
 
@@ -100,7 +120,7 @@ fn query_owner(querier: QuerierWrapper, cw721: &Addr, token_id: String) -> Addr
     let resp: OwnerOfResponse = querier
         .query_wasm_smart(
             cw721,
-            &Cw721QueryMsg::<Empty>::OwnerOf {
+            &Cw721QueryMsg::<Empty, Empty>::OwnerOf {
                 token_id,
                 include_expired: None,
             },
@@ -114,7 +134,7 @@ fn mint_transfer_and_burn(app: &mut App, cw721: Addr, sender: Addr, token_id: St
         sender.clone(),
         cw721.clone(),
         &Cw721ExecuteMsg::<Empty, Empty>::Mint {
-            token_id: token_id.clone(),
+            token_id: Some(token_id.clone()),
             owner: sender.to_string(),
             token_uri: None,
             extension: Empty::default(),
@@ -160,11 +180,18 @@ fn test_operator() {
         .instantiate_contract(
             code_id,
             other.clone(),
-            &Cw721InstantiateMsg {
+            &Cw721InstantiateMsg::<DefaultOptionCollectionInfoExtensionMsg> {
                 name: "collection".to_string(),
                 symbol: "symbol".to_string(),
                 minter: Some(MINTER_ADDR.to_string()),
+                creator: None,
                 withdraw_address: None,
+                metadata_update_authority: None,
+                transfer_policy: None,
+                sequential_token_ids: None,
+                max_supply: None,
+                index_traits: None,
+                collection_info_extension: None,
             },
             &[],
             "cw721-base",
@@ -178,7 +205,7 @@ fn test_operator() {
         minter,
         cw721.clone(),
         &Cw721ExecuteMsg::<Empty, Empty>::Mint {
-            token_id: "1".to_string(),
+            token_id: Some("1".to_string()),
             owner: nft_owner.to_string(),
             token_uri: None,
             extension: Empty::default(),
@@ -216,7 +243,7 @@ fn test_operator() {
         .wrap()
         .query_wasm_smart(
             &cw721,
-            &Cw721QueryMsg::<Empty>::OwnerOf {
+            &Cw721QueryMsg::<Empty, Empty>::OwnerOf {
                 token_id: "1".to_string(),
                 include_expired: None,
             },
@@ -255,7 +282,7 @@ fn test_operator() {
         .wrap()
         .query_wasm_smart(
             &cw721,
-            &Cw721QueryMsg::<Empty>::OwnerOf {
+            &Cw721QueryMsg::<Empty, Empty>::OwnerOf {
                 token_id: "1".to_string(),
                 include_expired: None,
             },
@@ -279,7 +306,7 @@ fn test_operator() {
         .wrap()
         .query_wasm_smart(
             &cw721,
-            &Cw721QueryMsg::<Empty>::OwnerOf {
+            &Cw721QueryMsg::<Empty, Empty>::OwnerOf {
                 token_id: "1".to_string(),
                 include_expired: None,
             },
@@ -374,6 +401,7 @@ fn test_migration_legacy_to_latest() {
                 msg: to_json_binary(&Cw721MigrateMsg::WithUpdate {
                     minter: None,
                     creator: None,
+                    total_minted: None,
                 })
                 .unwrap(),
             }
@@ -388,7 +416,7 @@ fn test_migration_legacy_to_latest() {
                 other.clone(),
                 cw721.clone(),
                 &Cw721ExecuteMsg::<Empty, Empty>::Mint {
-                    token_id: "1".to_string(),
+                    token_id: Some("1".to_string()),
                     owner: other.to_string(),
                     token_uri: None,
                     extension: Empty::default(),
@@ -411,7 +439,7 @@ fn test_migration_legacy_to_latest() {
         // check new mint query response works.
         let m: MinterResponse = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Minter {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Minter {})
             .unwrap();
         assert_eq!(m.minter, Some(legacy_creator_and_minter.to_string()));
 
@@ -419,16 +447,45 @@ fn test_migration_legacy_to_latest() {
         // is not None.
         let m: v16::MinterResponse = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Minter {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Minter {})
             .unwrap();
         assert_eq!(m.minter, legacy_creator_and_minter.to_string());
 
         // check minter ownership query works
         let minter_ownership: Ownership<Addr> = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Ownership {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Ownership {})
+            .unwrap();
+        assert_eq!(
+            minter_ownership.owner,
+            Some(legacy_creator_and_minter.clone())
+        );
+
+        // check creator ownership query works
+        let creator_ownership: Ownership<Addr> = app
+            .wrap()
+            .query_wasm_smart(
+                &cw721,
+                &Cw721QueryMsg::<Empty, Empty>::GetCreatorOwnership {},
+            )
+            .unwrap();
+        assert_eq!(creator_ownership.owner, Some(legacy_creator_and_minter));
+
+        // check collection info query works without collection info extension
+        let collection_info: CollectionInfoAndExtensionResponse<
+            DefaultOptionCollectionInfoExtension,
+        > = app
+            .wrap()
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::GetCollectionInfo {})
             .unwrap();
-        assert_eq!(minter_ownership.owner, Some(legacy_creator_and_minter));
+        assert_eq!(
+            collection_info,
+            CollectionInfoAndExtensionResponse {
+                name: "collection".to_string(),
+                symbol: "symbol".to_string(),
+                extension: None,
+            }
+        );
     }
     // case 2: migrate from v0.16 to latest by providing new creator and minter addr
     {
@@ -472,6 +529,7 @@ fn test_migration_legacy_to_latest() {
                 msg: to_json_binary(&Cw721MigrateMsg::WithUpdate {
                     minter: Some(MINTER_ADDR.to_string()),
                     creator: Some(CREATOR_ADDR.to_string()),
+                    total_minted: None,
                 })
                 .unwrap(),
             }
@@ -485,7 +543,7 @@ fn test_migration_legacy_to_latest() {
                 legacy_creator_and_minter.clone(),
                 cw721.clone(),
                 &Cw721ExecuteMsg::<Empty, Empty>::Mint {
-                    token_id: "1".to_string(),
+                    token_id: Some("1".to_string()),
                     owner: legacy_creator_and_minter.to_string(),
                     token_uri: None,
                     extension: Empty::default(),
@@ -504,7 +562,7 @@ fn test_migration_legacy_to_latest() {
         // check new mint query response works.
         let m: MinterResponse = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Minter {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Minter {})
             .unwrap();
         assert_eq!(m.minter, Some(minter.to_string()));
 
@@ -512,16 +570,26 @@ fn test_migration_legacy_to_latest() {
         // is not None.
         let m: v16::MinterResponse = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Minter {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Minter {})
             .unwrap();
         assert_eq!(m.minter, minter.to_string());
 
         // check minter ownership query works
         let minter_ownership: Ownership<Addr> = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Ownership {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Ownership {})
             .unwrap();
         assert_eq!(minter_ownership.owner, Some(minter));
+
+        // check creator ownership query works
+        let creator_ownership: Ownership<Addr> = app
+            .wrap()
+            .query_wasm_smart(
+                &cw721,
+                &Cw721QueryMsg::<Empty, Empty>::GetCreatorOwnership {},
+            )
+            .unwrap();
+        assert_eq!(creator_ownership.owner, Some(Addr::unchecked(CREATOR_ADDR)));
     }
     // case 3: migrate from v0.17 to latest by using existing minter addr
     {
@@ -565,6 +633,7 @@ fn test_migration_legacy_to_latest() {
                 msg: to_json_binary(&Cw721MigrateMsg::WithUpdate {
                     minter: None,
                     creator: None,
+                    total_minted: None,
                 })
                 .unwrap(),
             }
@@ -579,7 +648,7 @@ fn test_migration_legacy_to_latest() {
                 other.clone(),
                 cw721.clone(),
                 &Cw721ExecuteMsg::<Empty, Empty>::Mint {
-                    token_id: "1".to_string(),
+                    token_id: Some("1".to_string()),
                     owner: other.to_string(),
                     token_uri: None,
                     extension: Empty::default(),
@@ -602,7 +671,7 @@ fn test_migration_legacy_to_latest() {
         // check new mint query response works.
         let m: MinterResponse = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Minter {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Minter {})
             .unwrap();
         assert_eq!(m.minter, Some(legacy_creator_and_minter.to_string()));
 
@@ -610,16 +679,29 @@ fn test_migration_legacy_to_latest() {
         // is not None.
         let m: v17::MinterResponse = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Minter {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Minter {})
             .unwrap();
         assert_eq!(m.minter, Some(legacy_creator_and_minter.to_string()));
 
         // check minter ownership query works
         let minter_ownership: Ownership<Addr> = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Ownership {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Ownership {})
             .unwrap();
-        assert_eq!(minter_ownership.owner, Some(legacy_creator_and_minter));
+        assert_eq!(
+            minter_ownership.owner,
+            Some(legacy_creator_and_minter.clone())
+        );
+
+        // check creator ownership query works
+        let creator_ownership: Ownership<Addr> = app
+            .wrap()
+            .query_wasm_smart(
+                &cw721,
+                &Cw721QueryMsg::<Empty, Empty>::GetCreatorOwnership {},
+            )
+            .unwrap();
+        assert_eq!(creator_ownership.owner, Some(legacy_creator_and_minter));
     }
     // case 4: migrate from v0.17 to latest by providing new creator and minter addr
     {
@@ -663,6 +745,7 @@ fn test_migration_legacy_to_latest() {
                 msg: to_json_binary(&Cw721MigrateMsg::WithUpdate {
                     minter: Some(MINTER_ADDR.to_string()),
                     creator: Some(CREATOR_ADDR.to_string()),
+                    total_minted: None,
                 })
                 .unwrap(),
             }
@@ -676,7 +759,7 @@ fn test_migration_legacy_to_latest() {
                 legacy_creator_and_minter.clone(),
                 cw721.clone(),
                 &Cw721ExecuteMsg::<Empty, Empty>::Mint {
-                    token_id: "1".to_string(),
+                    token_id: Some("1".to_string()),
                     owner: legacy_creator_and_minter.to_string(),
                     token_uri: None,
                     extension: Empty::default(),
@@ -695,7 +778,7 @@ fn test_migration_legacy_to_latest() {
         // check new mint query response works.
         let m: MinterResponse = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Minter {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Minter {})
             .unwrap();
         assert_eq!(m.minter, Some(minter.to_string()));
 
@@ -703,16 +786,26 @@ fn test_migration_legacy_to_latest() {
         // is not None.
         let m: v17::MinterResponse = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Minter {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Minter {})
             .unwrap();
         assert_eq!(m.minter, Some(minter.to_string()));
 
         // check minter ownership query works
         let minter_ownership: Ownership<Addr> = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Ownership {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Ownership {})
             .unwrap();
         assert_eq!(minter_ownership.owner, Some(minter));
+
+        // check creator ownership query works
+        let creator_ownership: Ownership<Addr> = app
+            .wrap()
+            .query_wasm_smart(
+                &cw721,
+                &Cw721QueryMsg::<Empty, Empty>::GetCreatorOwnership {},
+            )
+            .unwrap();
+        assert_eq!(creator_ownership.owner, Some(Addr::unchecked(CREATOR_ADDR)));
     }
     // case 5: migrate from v0.18 to latest by using existing minter addr
     {
@@ -756,6 +849,7 @@ fn test_migration_legacy_to_latest() {
                 msg: to_json_binary(&Cw721MigrateMsg::WithUpdate {
                     minter: None,
                     creator: None,
+                    total_minted: None,
                 })
                 .unwrap(),
             }
@@ -770,7 +864,7 @@ fn test_migration_legacy_to_latest() {
                 other.clone(),
                 cw721.clone(),
                 &Cw721ExecuteMsg::<Empty, Empty>::Mint {
-                    token_id: "1".to_string(),
+                    token_id: Some("1".to_string()),
                     owner: other.to_string(),
                     token_uri: None,
                     extension: Empty::default(),
@@ -793,7 +887,7 @@ fn test_migration_legacy_to_latest() {
         // check new mint query response works.
         let m: MinterResponse = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Minter {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Minter {})
             .unwrap();
         assert_eq!(m.minter, Some(legacy_creator_and_minter.to_string()));
 
@@ -801,16 +895,29 @@ fn test_migration_legacy_to_latest() {
         // is not None.
         let m: v18::MinterResponse = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Minter {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Minter {})
             .unwrap();
         assert_eq!(m.minter, Some(legacy_creator_and_minter.to_string()));
 
         // check minter ownership query works
         let minter_ownership: Ownership<Addr> = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Ownership {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Ownership {})
             .unwrap();
-        assert_eq!(minter_ownership.owner, Some(legacy_creator_and_minter));
+        assert_eq!(
+            minter_ownership.owner,
+            Some(legacy_creator_and_minter.clone())
+        );
+
+        // check creator ownership query works
+        let creator_ownership: Ownership<Addr> = app
+            .wrap()
+            .query_wasm_smart(
+                &cw721,
+                &Cw721QueryMsg::<Empty, Empty>::GetCreatorOwnership {},
+            )
+            .unwrap();
+        assert_eq!(creator_ownership.owner, Some(legacy_creator_and_minter));
     }
     // case 6: migrate from v0.18 to latest by providing new creator and minter addr
     {
@@ -854,6 +961,7 @@ fn test_migration_legacy_to_latest() {
                 msg: to_json_binary(&Cw721MigrateMsg::WithUpdate {
                     minter: Some(MINTER_ADDR.to_string()),
                     creator: Some(CREATOR_ADDR.to_string()),
+                    total_minted: None,
                 })
                 .unwrap(),
             }
@@ -867,7 +975,7 @@ fn test_migration_legacy_to_latest() {
                 legacy_creator_and_minter.clone(),
                 cw721.clone(),
                 &Cw721ExecuteMsg::<Empty, Empty>::Mint {
-                    token_id: "1".to_string(),
+                    token_id: Some("1".to_string()),
                     owner: legacy_creator_and_minter.to_string(),
                     token_uri: None,
                     extension: Empty::default(),
@@ -886,7 +994,7 @@ fn test_migration_legacy_to_latest() {
         // check new mint query response works.
         let m: MinterResponse = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Minter {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Minter {})
             .unwrap();
         assert_eq!(m.minter, Some(minter.to_string()));
 
@@ -894,16 +1002,26 @@ fn test_migration_legacy_to_latest() {
         // is not None.
         let m: v18::MinterResponse = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Minter {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Minter {})
             .unwrap();
         assert_eq!(m.minter, Some(minter.to_string()));
 
         // check minter ownership query works
         let minter_ownership: Ownership<Addr> = app
             .wrap()
-            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty>::Ownership {})
+            .query_wasm_smart(&cw721, &Cw721QueryMsg::<Empty, Empty>::Ownership {})
             .unwrap();
         assert_eq!(minter_ownership.owner, Some(minter));
+
+        // check creator ownership query works
+        let creator_ownership: Ownership<Addr> = app
+            .wrap()
+            .query_wasm_smart(
+                &cw721,
+                &Cw721QueryMsg::<Empty, Empty>::GetCreatorOwnership {},
+            )
+            .unwrap();
+        assert_eq!(creator_ownership.owner, Some(Addr::unchecked(CREATOR_ADDR)));
     }
 }
 
@@ -935,7 +1053,7 @@ fn test_instantiate_016_msg() {
     // assert withdraw address is None
     let withdraw_addr: Option<String> = app
         .wrap()
-        .query_wasm_smart(cw721, &Cw721QueryMsg::<Empty>::GetWithdrawAddress {})
+        .query_wasm_smart(cw721, &Cw721QueryMsg::<Empty, Empty>::GetWithdrawAddress {})
         .unwrap();
     assert!(withdraw_addr.is_none());
 }
@@ -1,14 +1,19 @@
 use crate::{
+    error::Cw721ContractError,
     execute::Cw721Execute,
     msg::{Cw721ExecuteMsg, Cw721InstantiateMsg},
     query::{Cw721Query, MAX_LIMIT},
-    state::{CollectionInfo, DefaultOptionMetadataExtension, Metadata, MINTER},
+    state::{
+        CollectionInfo, Cw721Config, DefaultOptionCollectionInfoExtension,
+        DefaultOptionMetadataExtension, Metadata, CREATOR, MINTER,
+    },
 };
 use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, mock_info},
-    Addr, Empty,
+    Addr, DepsMut, Empty, Response,
 };
 use cw2::ContractVersion;
+use cw_ownable::OwnershipError;
 use cw_storage_plus::Item;
 use unit_tests::{contract::Cw721Contract, multi_tests::CREATOR_ADDR};
 
@@ -19,21 +24,33 @@ use super::*;
 fn proper_cw2_initialization() {
     let mut deps = mock_dependencies();
 
-    Cw721Contract::<DefaultOptionMetadataExtension, Empty, Empty>::default()
-        .instantiate(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("larry", &[]),
-            Cw721InstantiateMsg {
-                name: "collection_name".into(),
-                symbol: "collection_symbol".into(),
-                minter: Some("minter".into()),
-                withdraw_address: None,
-            },
-            "contract_name",
-            "contract_version",
-        )
-        .unwrap();
+    Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default()
+    .instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("larry", &[]),
+        Cw721InstantiateMsg {
+            name: "collection_name".into(),
+            symbol: "collection_symbol".into(),
+            minter: Some("minter".into()),
+            creator: None,
+            withdraw_address: None,
+            metadata_update_authority: None,
+            transfer_policy: None,
+            sequential_token_ids: None,
+            max_supply: None,
+            index_traits: None,
+            collection_info_extension: None,
+        },
+        "contract_name",
+        "contract_version",
+    )
+    .unwrap();
 
     let minter = MINTER
         .get_ownership(deps.as_ref().storage)
@@ -57,37 +74,63 @@ fn proper_owner_initialization() {
     let mut deps = mock_dependencies();
 
     let info_owner = mock_info("owner", &[]);
-    Cw721Contract::<DefaultOptionMetadataExtension, Empty, Empty>::default()
-        .instantiate(
-            deps.as_mut(),
-            mock_env(),
-            info_owner.clone(),
-            Cw721InstantiateMsg {
-                name: "collection_name".into(),
-                symbol: "collection_symbol".into(),
-                minter: None,
-                withdraw_address: None,
-            },
-            "contract_name",
-            "contract_version",
-        )
-        .unwrap();
+    Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default()
+    .instantiate(
+        deps.as_mut(),
+        mock_env(),
+        info_owner.clone(),
+        Cw721InstantiateMsg {
+            name: "collection_name".into(),
+            symbol: "collection_symbol".into(),
+            minter: None,
+            creator: None,
+            withdraw_address: None,
+            metadata_update_authority: None,
+            transfer_policy: None,
+            sequential_token_ids: None,
+            max_supply: None,
+            index_traits: None,
+            collection_info_extension: None,
+        },
+        "contract_name",
+        "contract_version",
+    )
+    .unwrap();
 
     let minter = MINTER.item.load(deps.as_ref().storage).unwrap().owner;
-    assert_eq!(minter, Some(info_owner.sender));
+    assert_eq!(minter, Some(info_owner.sender.clone()));
+    let creator = CREATOR.item.load(deps.as_ref().storage).unwrap().owner;
+    assert_eq!(creator, Some(info_owner.sender));
 }
 
 #[test]
 fn use_metadata_extension() {
     let mut deps = mock_dependencies();
-    let contract = Cw721Contract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
 
     let info = mock_info(CREATOR_ADDR, &[]);
     let init_msg = Cw721InstantiateMsg {
         name: "collection_name".into(),
         symbol: "collection_symbol".into(),
         minter: None,
+        creator: None,
         withdraw_address: None,
+        metadata_update_authority: None,
+        transfer_policy: None,
+        sequential_token_ids: None,
+        max_supply: None,
+        index_traits: None,
+        collection_info_extension: None,
     };
     let env = mock_env();
     contract
@@ -109,7 +152,7 @@ fn use_metadata_extension() {
         ..Metadata::default()
     });
     let exec_msg = Cw721ExecuteMsg::Mint {
-        token_id: token_id.to_string(),
+        token_id: Some(token_id.to_string()),
         owner: "john".to_string(),
         token_uri: token_uri.clone(),
         extension: extension.clone(),
@@ -154,16 +197,44 @@ fn test_migrate() {
         });
         v16::entry::execute(deps.as_mut(), env.clone(), info, msg).unwrap();
     }
+    // approve a spender and grant an operator before migration
+    v16::entry::execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        v16::ExecuteMsg::Approve {
+            spender: "spender".into(),
+            token_id: "0".into(),
+            expires: None,
+        },
+    )
+    .unwrap();
+    v16::entry::execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        v16::ExecuteMsg::ApproveAll {
+            operator: "operator".into(),
+            expires: None,
+        },
+    )
+    .unwrap();
 
     // assert new data before migration:
     // - ownership and collection info throws NotFound Error
-    MINTER.item.load(deps.as_ref().storage).unwrap_err(); // cw_ownable in v16 is used for minter
-    let contract = Cw721Contract::<DefaultOptionMetadataExtension, Empty, Empty>::default();
+    CREATOR.item.load(deps.as_ref().storage).unwrap_err(); // cw_ownable is not used in v16
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
     contract
         .query_collection_info(deps.as_ref(), env.clone())
         .unwrap_err();
     // - query in new minter and creator ownership store throws NotFound Error (in v16 it was stored outside cw_ownable, in dedicated "minter" store)
     MINTER.get_ownership(deps.as_ref().storage).unwrap_err();
+    CREATOR.get_ownership(deps.as_ref().storage).unwrap_err();
     // assert legacy data before migration:
     // - version
     let version = cw2::get_contract_version(deps.as_ref().storage)
@@ -177,7 +248,14 @@ fn test_migrate() {
     // - legacy collection info is set
     let legacy_collection_info_store: Item<cw721_016::ContractInfoResponse> = Item::new("nft_info");
     let all_tokens = contract
-        .query_all_tokens(deps.as_ref(), env.clone(), None, Some(MAX_LIMIT))
+        .query_all_tokens(
+            deps.as_ref(),
+            env.clone(),
+            None,
+            None,
+            Some(MAX_LIMIT),
+            None,
+        )
         .unwrap();
     assert_eq!(all_tokens.tokens.len(), 200);
     for token_id in 0..200 {
@@ -187,18 +265,24 @@ fn test_migrate() {
         assert_eq!(token.owner.as_str(), "owner");
     }
 
-    Cw721Contract::<DefaultOptionMetadataExtension, Empty, Empty>::default()
-        .migrate(
-            deps.as_mut(),
-            env.clone(),
-            crate::msg::Cw721MigrateMsg::WithUpdate {
-                minter: None,
-                creator: None,
-            },
-            "contract_name",
-            "contract_version",
-        )
-        .unwrap();
+    Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default()
+    .migrate(
+        deps.as_mut(),
+        env.clone(),
+        crate::msg::Cw721MigrateMsg::WithUpdate {
+            minter: None,
+            creator: None,
+            total_minted: None,
+        },
+        "contract_name",
+        "contract_version",
+    )
+    .unwrap();
 
     // version
     let version = cw2::get_contract_version(deps.as_ref().storage)
@@ -215,6 +299,14 @@ fn test_migrate() {
         .map(|a| a.into_string());
     assert_eq!(minter_ownership, Some("legacy_minter".to_string()));
 
+    // assert creator ownership
+    let creator_ownership = CREATOR
+        .get_ownership(deps.as_ref().storage)
+        .unwrap()
+        .owner
+        .map(|a| a.into_string());
+    assert_eq!(creator_ownership, Some("legacy_minter".to_string()));
+
     // assert collection info
     let collection_info = contract
         .query_collection_info(deps.as_ref(), env.clone())
@@ -225,9 +317,49 @@ fn test_migrate() {
     };
     assert_eq!(collection_info, legacy_contract_info);
 
+    // assert operator grants are indexed by operator
+    let approved_owners = contract
+        .query_approved_owners_for_operator(
+            deps.as_ref(),
+            env.clone(),
+            "operator".to_string(),
+            false,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(approved_owners.owners.len(), 1);
+    assert_eq!(approved_owners.owners[0].owner, Addr::unchecked("owner"));
+
+    // assert token approvals are indexed by spender
+    let approved_tokens = contract
+        .query_tokens_approved_for(
+            deps.as_ref(),
+            env.clone(),
+            "spender".to_string(),
+            false,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(approved_tokens.tokens, vec!["0".to_string()]);
+
+    // assert balances are backfilled
+    let balance = contract
+        .query_balance_of(deps.as_ref(), env.clone(), "owner".to_string())
+        .unwrap();
+    assert_eq!(balance.balance, 200);
+
     // assert tokens
     let all_tokens = contract
-        .query_all_tokens(deps.as_ref(), env.clone(), None, Some(MAX_LIMIT))
+        .query_all_tokens(
+            deps.as_ref(),
+            env.clone(),
+            None,
+            None,
+            Some(MAX_LIMIT),
+            None,
+        )
         .unwrap();
     assert_eq!(all_tokens.tokens.len(), 200);
 
@@ -243,7 +375,14 @@ fn test_migrate() {
     assert_eq!(legacy_collection_info.symbol, "legacy_symbol");
     // - tokens are unchanged/still exist
     let all_tokens = contract
-        .query_all_tokens(deps.as_ref(), env.clone(), None, Some(MAX_LIMIT))
+        .query_all_tokens(
+            deps.as_ref(),
+            env.clone(),
+            None,
+            None,
+            Some(MAX_LIMIT),
+            None,
+        )
         .unwrap();
     assert_eq!(all_tokens.tokens.len(), 200);
     for token_id in 0..200 {
@@ -253,3 +392,305 @@ fn test_migrate() {
         assert_eq!(token.owner.as_str(), "owner");
     }
 }
+
+#[test]
+fn test_migrate_total_minted() {
+    let mut deps = mock_dependencies();
+
+    let env = mock_env();
+    use cw721_base_016 as v16;
+    v16::entry::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        v16::InstantiateMsg {
+            name: "legacy_name".into(),
+            symbol: "legacy_symbol".into(),
+            minter: "legacy_minter".into(),
+        },
+    )
+    .unwrap();
+    // mint 3 NFTs and burn one before migration
+    for i in 0..3 {
+        let info = mock_info("legacy_minter", &[]);
+        let msg = v16::ExecuteMsg::Mint(v16::msg::MintMsg {
+            token_id: i.to_string(),
+            owner: "owner".into(),
+            token_uri: None,
+            extension: None,
+        });
+        v16::entry::execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    }
+    v16::entry::execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        v16::ExecuteMsg::Burn {
+            token_id: "0".into(),
+        },
+    )
+    .unwrap();
+
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
+    let migrate = |deps: DepsMut, total_minted: Option<u64>| {
+        contract.migrate(
+            deps,
+            mock_env(),
+            crate::msg::Cw721MigrateMsg::WithUpdate {
+                minter: None,
+                creator: None,
+                total_minted,
+            },
+            "contract_name",
+            "contract_version",
+        )
+    };
+
+    // without total minted, burns before migration are unknown
+    migrate(deps.as_mut(), None).unwrap();
+    let supply = contract.query_supply(deps.as_ref()).unwrap();
+    assert_eq!(supply.total_minted, 2);
+    assert_eq!(supply.total_burned, 0);
+
+    // it can't be lower than the number of existing tokens
+    let err = migrate(deps.as_mut(), Some(1)).unwrap_err();
+    assert_eq!(
+        err,
+        Cw721ContractError::TotalMintedBelowTokens { num_tokens: 2 }
+    );
+
+    // total minted can be set on migration
+    migrate(deps.as_mut(), Some(3)).unwrap();
+    let supply = contract.query_supply(deps.as_ref()).unwrap();
+    assert_eq!(supply.total_minted, 3);
+    assert_eq!(supply.total_burned, 1);
+
+    // and is kept on later migrations
+    migrate(deps.as_mut(), None).unwrap();
+    let supply = contract.query_supply(deps.as_ref()).unwrap();
+    assert_eq!(supply.total_minted, 3);
+}
+
+#[test]
+fn test_migrate_indexes() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let contract = Cw721Contract::<
+        DefaultOptionMetadataExtension,
+        Empty,
+        Empty,
+        DefaultOptionCollectionInfoExtension,
+    >::default();
+    contract
+        .instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("larry", &[]),
+            Cw721InstantiateMsg {
+                name: "collection_name".into(),
+                symbol: "collection_symbol".into(),
+                minter: None,
+                creator: None,
+                withdraw_address: None,
+                metadata_update_authority: None,
+                transfer_policy: None,
+                sequential_token_ids: None,
+                max_supply: None,
+                index_traits: None,
+                collection_info_extension: None,
+            },
+            "contract_name",
+            "contract_version",
+        )
+        .unwrap();
+    let config = Cw721Config::<Empty, Empty, Empty, Empty>::default();
+    let attribute = |res: &Response, key: &str| {
+        res.attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.clone())
+            .unwrap()
+    };
+
+    // grant operators, then drop the reverse index as if created before it existed
+    for operator in ["operator1", "operator2", "operator3"] {
+        contract
+            .execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("owner", &[]),
+                Cw721ExecuteMsg::ApproveAll {
+                    operator: operator.into(),
+                    expires: None,
+                },
+            )
+            .unwrap();
+        config.operator_grants.remove(
+            deps.as_mut().storage,
+            (&Addr::unchecked(operator), &Addr::unchecked("owner")),
+        );
+    }
+    config
+        .operator_grants_backfill
+        .save(deps.as_mut().storage, &None)
+        .unwrap();
+
+    // same for approved tokens
+    for token_id in ["1", "2", "3", "4"] {
+        contract
+            .execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("larry", &[]),
+                Cw721ExecuteMsg::Mint {
+                    token_id: Some(token_id.into()),
+                    owner: "owner".into(),
+                    token_uri: None,
+                    extension: None,
+                },
+            )
+            .unwrap();
+        contract
+            .execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("owner", &[]),
+                Cw721ExecuteMsg::Approve {
+                    spender: "spender".into(),
+                    token_id: token_id.into(),
+                    expires: None,
+                },
+            )
+            .unwrap();
+        config.token_approvals.remove(
+            deps.as_mut().storage,
+            (&Addr::unchecked("spender"), token_id),
+        );
+    }
+    config
+        .token_approvals_backfill
+        .save(deps.as_mut().storage, &None)
+        .unwrap();
+
+    // and for balances
+    config
+        .balances
+        .remove(deps.as_mut().storage, &Addr::unchecked("owner"));
+    config
+        .balances_backfill
+        .save(deps.as_mut().storage, &None)
+        .unwrap();
+
+    // only the creator can continue the backfill
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            Cw721ExecuteMsg::MigrateIndexes { limit: Some(2) },
+        )
+        .unwrap_err();
+    assert_eq!(err, Cw721ContractError::Ownership(OwnershipError::NotOwner));
+
+    // backfills share the limit and run one after another
+    let migrate_indexes = |deps: DepsMut| {
+        let res = contract
+            .execute(
+                deps,
+                env.clone(),
+                mock_info("larry", &[]),
+                Cw721ExecuteMsg::MigrateIndexes { limit: Some(2) },
+            )
+            .unwrap();
+        [
+            "migrated_operator_grants",
+            "migrated_token_approvals",
+            "migrated_balances",
+            "pending",
+        ]
+        .map(|key| attribute(&res, key))
+    };
+    assert_eq!(migrate_indexes(deps.as_mut()), ["2", "0", "0", "true"]);
+    assert_eq!(migrate_indexes(deps.as_mut()), ["1", "1", "0", "true"]);
+    assert_eq!(migrate_indexes(deps.as_mut()), ["0", "2", "0", "true"]);
+    assert_eq!(migrate_indexes(deps.as_mut()), ["0", "1", "1", "true"]);
+
+    for operator in ["operator1", "operator2", "operator3"] {
+        let approved_owners = contract
+            .query_approved_owners_for_operator(
+                deps.as_ref(),
+                env.clone(),
+                operator.to_string(),
+                false,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(approved_owners.owners.len(), 1);
+    }
+    let approved_tokens = contract
+        .query_tokens_approved_for(
+            deps.as_ref(),
+            env.clone(),
+            "spender".to_string(),
+            false,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(approved_tokens.tokens, vec!["1", "2", "3", "4"]);
+
+    // while balances are backfilled, only tokens already counted change them
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            Cw721ExecuteMsg::Burn {
+                token_id: "1".into(),
+            },
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            Cw721ExecuteMsg::TransferNft {
+                recipient: "other".into(),
+                token_id: "4".into(),
+            },
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("larry", &[]),
+            Cw721ExecuteMsg::Mint {
+                token_id: Some("5".into()),
+                owner: "owner".into(),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(migrate_indexes(deps.as_mut()), ["0", "0", "2", "true"]);
+    assert_eq!(migrate_indexes(deps.as_mut()), ["0", "0", "2", "true"]);
+    // nothing left to do
+    assert_eq!(migrate_indexes(deps.as_mut()), ["0", "0", "0", "false"]);
+
+    let balance_of = |owner: &str| {
+        contract
+            .query_balance_of(deps.as_ref(), env.clone(), owner.to_string())
+            .unwrap()
+            .balance
+    };
+    assert_eq!(balance_of("owner"), 3);
+    assert_eq!(balance_of("other"), 1);
+}